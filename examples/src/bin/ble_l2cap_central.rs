@@ -5,12 +5,12 @@
 mod example_common;
 
 use core::ptr::NonNull;
-use core::{mem, slice};
+use core::mem;
 
 use defmt::{info, *};
 use embassy_executor::Spawner;
 use nrf_softdevice::ble::l2cap::Packet as _;
-use nrf_softdevice::ble::{central, l2cap, Address, TxPower};
+use nrf_softdevice::ble::{central, l2cap, TxPower};
 use nrf_softdevice::{raw, Softdevice};
 
 const PSM: u16 = 0x2349;
@@ -128,30 +128,15 @@ async fn main(spawner: Spawner) {
         tx_power: TxPower::ZerodBm,
         ..Default::default()
     };
-    let res = central::scan(sd, &config, |params| unsafe {
-        let mut data = slice::from_raw_parts(params.data.p_data, params.data.len as usize);
-        while data.len() != 0 {
-            let len = data[0] as usize;
-            if data.len() < len + 1 {
-                break;
-            }
-            if len < 1 {
-                break;
-            }
-            let key = data[1];
-            let value = &data[2..len + 1];
-
-            if key == 0x06
-                && value
-                    == &[
-                        0xeb, 0x04, 0x8b, 0xfd, 0x5b, 0x03, 0x21, 0xb5, 0xeb, 0x11, 0x65, 0x2f, 0x18, 0xce, 0x9c, 0x82,
-                    ]
-            {
-                return Some(Address::from_raw(params.peer_addr));
-            }
-            data = &data[len + 1..];
-        }
-        None
+    let res = central::scan(sd, &config, |report| {
+        let data = report.advertisement_data().ok()?;
+        data.services_128()
+            .any(|uuid| {
+                uuid == [
+                    0xeb, 0x04, 0x8b, 0xfd, 0x5b, 0x03, 0x21, 0xb5, 0xeb, 0x11, 0x65, 0x2f, 0x18, 0xce, 0x9c, 0x82,
+                ]
+            })
+            .then(|| report.peer_address())
     })
     .await;
     let address = unwrap!(res);
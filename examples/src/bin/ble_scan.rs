@@ -4,7 +4,7 @@
 #[path = "../example_common.rs"]
 mod example_common;
 
-use core::{mem, slice};
+use core::mem;
 
 use defmt::*;
 use embassy_executor::Spawner;
@@ -56,38 +56,22 @@ async fn main(spawner: Spawner) {
     unwrap!(spawner.spawn(softdevice_task(sd)));
 
     let config = central::ScanConfig::default();
-    let res = central::scan(sd, &config, |params| unsafe {
+    let res = central::scan(sd, &config, |report| {
         info!("AdvReport!");
         info!(
-            "type: connectable={} scannable={} directed={} scan_response={} extended_pdu={} status={}",
-            params.type_.connectable(),
-            params.type_.scannable(),
-            params.type_.directed(),
-            params.type_.scan_response(),
-            params.type_.extended_pdu(),
-            params.type_.status()
+            "kind: {:?} data_status: {:?} rssi: {}",
+            report.kind(),
+            report.data_status(),
+            report.rssi()
         );
-        info!(
-            "addr: resolved={} type={} addr={:x}",
-            params.peer_addr.addr_id_peer(),
-            params.peer_addr.addr_type(),
-            params.peer_addr.addr
-        );
-        let mut data = slice::from_raw_parts(params.data.p_data, params.data.len as usize);
-        while data.len() != 0 {
-            let len = data[0] as usize;
-            if data.len() < len + 1 {
-                warn!("Advertisement data truncated?");
-                break;
-            }
-            if len < 1 {
-                warn!("Advertisement data malformed?");
-                break;
+        info!("addr: {:?}", report.peer_address());
+        match report.advertisement_data() {
+            Ok(data) => {
+                for (key, value) in data.iter() {
+                    info!("value {}: {:x}", key.to_u8(), value);
+                }
             }
-            let key = data[1];
-            let value = &data[2..len + 1];
-            info!("value {}: {:x}", key, value);
-            data = &data[len + 1..];
+            Err(e) => warn!("Advertisement data malformed: {:?}", e),
         }
         None
     })
@@ -113,7 +113,7 @@ fn main() {
 
         writeln!(
             &mut res,
-            "{}\n#[inline(always)]\npub unsafe fn {}({}) -> u32 {{",
+            "{}\n#[inline(always)]\n#[cfg_attr(not(target_arch = \"arm\"), allow(unused_variables))]\npub unsafe fn {}({}) -> u32 {{",
             doc, name, args
         )
         .unwrap();
@@ -126,6 +126,7 @@ fn main() {
             .collect::<Vec<&str>>();
 
         writeln!(&mut res, "    let ret: u32;",).unwrap();
+        writeln!(&mut res, "    #[cfg(target_arch = \"arm\")]").unwrap();
         writeln!(&mut res, "    core::arch::asm!(\"svc {}\",", num).unwrap();
 
         assert!(arg_names.len() <= 4);
@@ -144,6 +145,10 @@ fn main() {
         }
         writeln!(&mut res, "        lateout(\"r12\") _,").unwrap();
         writeln!(&mut res, "    );").unwrap();
+        writeln!(&mut res, "    #[cfg(not(target_arch = \"arm\"))]").unwrap();
+        writeln!(&mut res, "    {{").unwrap();
+        writeln!(&mut res, "        ret = svc_unavailable({});", num).unwrap();
+        writeln!(&mut res, "    }}").unwrap();
         writeln!(&mut res, "    ret").unwrap();
         writeln!(&mut res, "}}",).unwrap();
 
@@ -292,4 +297,11 @@ impl<X, Y, Z, R> ToAsm for unsafe extern "C" fn(X, Y, Z) -> R {
     }
 }
 
+// SoftDevice calls can't be made off-target, but the bindings still build there so that
+// code using them can be unit tested on the host.
+#[cfg(not(target_arch = "arm"))]
+fn svc_unavailable(num: u32) -> u32 {
+    panic!("SoftDevice call svc {} is only available on ARM targets", num)
+}
+
 "#;
@@ -132,6 +132,13 @@ impl<X, Y, Z, R> ToAsm for unsafe extern "C" fn(X, Y, Z) -> R {
     }
 }
 
+// SoftDevice calls can't be made off-target, but the bindings still build there so that
+// code using them can be unit tested on the host.
+#[cfg(not(target_arch = "arm"))]
+fn svc_unavailable(num: u32) -> u32 {
+    panic!("SoftDevice call svc {} is only available on ARM targets", num)
+}
+
 /* automatically generated by rust-bindgen 0.55.1 */
 
 pub const MBR_SVC_BASE: u32 = 24;
@@ -593,8 +600,10 @@ fn bindgen_test_layout_sd_mbr_command_t() {
 #[doc = " @retval ::NRF_ERROR_NO_MEM No MBR parameter page provided"]
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM if an invalid command is given."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_mbr_command(param: *mut sd_mbr_command_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 24",
         inout("r0") to_asm(param) => ret,
         lateout("r1") _,
@@ -602,5 +611,9 @@ pub unsafe fn sd_mbr_command(param: *mut sd_mbr_command_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(24);
+    }
     ret
 }
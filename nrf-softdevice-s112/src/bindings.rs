@@ -132,6 +132,13 @@ impl<X, Y, Z, R> ToAsm for unsafe extern "C" fn(X, Y, Z) -> R {
     }
 }
 
+// SoftDevice calls can't be made off-target, but the bindings still build there so that
+// code using them can be unit tested on the host.
+#[cfg(not(target_arch = "arm"))]
+fn svc_unavailable(num: u32) -> u32 {
+    panic!("SoftDevice call svc {} is only available on ARM targets", num)
+}
+
 /* automatically generated by rust-bindgen 0.55.1 */
 
 #[repr(C)]
@@ -4595,8 +4602,10 @@ fn bindgen_test_layout_ble_gap_opt_t() {
 #[doc = " @retval ::NRF_ERROR_BUSY The stack is busy, process pending events and retry."]
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE The identity address cannot be changed while advertising."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_addr_set(p_addr: *const ble_gap_addr_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 108",
         inout("r0") to_asm(p_addr) => ret,
         lateout("r1") _,
@@ -4604,6 +4613,10 @@ pub unsafe fn sd_ble_gap_addr_set(p_addr: *const ble_gap_addr_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(108);
+    }
     ret
 }
 
@@ -4617,8 +4630,10 @@ pub unsafe fn sd_ble_gap_addr_set(p_addr: *const ble_gap_addr_t) -> u32 {
 #[doc = " @retval ::NRF_SUCCESS Address successfully retrieved."]
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR Invalid or NULL pointer supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_addr_get(p_addr: *mut ble_gap_addr_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 109",
         inout("r0") to_asm(p_addr) => ret,
         lateout("r1") _,
@@ -4626,6 +4641,10 @@ pub unsafe fn sd_ble_gap_addr_get(p_addr: *mut ble_gap_addr_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(109);
+    }
     ret
 }
 
@@ -4645,8 +4664,10 @@ pub unsafe fn sd_ble_gap_addr_get(p_addr: *mut ble_gap_addr_t) -> u32 {
 #[doc = " @retval ::BLE_ERROR_INVALID_ADV_HANDLE The provided advertising handle was not found."]
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE      The advertising set is currently not advertising."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_adv_addr_get(adv_handle: u8, p_addr: *mut ble_gap_addr_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 147",
         inout("r0") to_asm(adv_handle) => ret,
         inout("r1") to_asm(p_addr) => _,
@@ -4654,6 +4675,10 @@ pub unsafe fn sd_ble_gap_adv_addr_get(adv_handle: u8, p_addr: *mut ble_gap_addr_
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(147);
+    }
     ret
 }
 
@@ -4675,8 +4700,10 @@ pub unsafe fn sd_ble_gap_adv_addr_get(adv_handle: u8, p_addr: *mut ble_gap_addr_
 #[doc = " @retval ::NRF_ERROR_DATA_SIZE The given whitelist size is invalid (zero or too large); this can only return when"]
 #[doc = "                               pp_wl_addrs is not NULL."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_whitelist_set(pp_wl_addrs: *const *const ble_gap_addr_t, len: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 110",
         inout("r0") to_asm(pp_wl_addrs) => ret,
         inout("r1") to_asm(len) => _,
@@ -4684,6 +4711,10 @@ pub unsafe fn sd_ble_gap_whitelist_set(pp_wl_addrs: *const *const ble_gap_addr_t
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(110);
+    }
     ret
 }
 
@@ -4712,12 +4743,14 @@ pub unsafe fn sd_ble_gap_whitelist_set(pp_wl_addrs: *const *const ble_gap_addr_t
 #[doc = " @retval ::NRF_ERROR_DATA_SIZE The given device identity list size invalid (zero or too large); this can"]
 #[doc = "                               only return when pp_id_keys is not NULL."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_device_identities_set(
     pp_id_keys: *const *const ble_gap_id_key_t,
     pp_local_irks: *const *const ble_gap_irk_t,
     len: u8,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 111",
         inout("r0") to_asm(pp_id_keys) => ret,
         inout("r1") to_asm(pp_local_irks) => _,
@@ -4725,6 +4758,10 @@ pub unsafe fn sd_ble_gap_device_identities_set(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(111);
+    }
     ret
 }
 
@@ -4747,8 +4784,10 @@ pub unsafe fn sd_ble_gap_device_identities_set(
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM Out of range parameters are provided."]
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE Privacy settings cannot be changed while advertising."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_privacy_set(p_privacy_params: *const ble_gap_privacy_params_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 112",
         inout("r0") to_asm(p_privacy_params) => ret,
         lateout("r1") _,
@@ -4756,6 +4795,10 @@ pub unsafe fn sd_ble_gap_privacy_set(p_privacy_params: *const ble_gap_privacy_pa
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(112);
+    }
     ret
 }
 
@@ -4770,8 +4813,10 @@ pub unsafe fn sd_ble_gap_privacy_set(p_privacy_params: *const ble_gap_privacy_pa
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR The pointer given for returning the privacy settings may be NULL or invalid."]
 #[doc = "                                  Otherwise, the p_device_irk pointer in privacy parameter is an invalid pointer."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_privacy_get(p_privacy_params: *mut ble_gap_privacy_params_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 113",
         inout("r0") to_asm(p_privacy_params) => ret,
         lateout("r1") _,
@@ -4779,6 +4824,10 @@ pub unsafe fn sd_ble_gap_privacy_get(p_privacy_params: *mut ble_gap_privacy_para
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(113);
+    }
     ret
 }
 
@@ -4825,12 +4874,14 @@ pub unsafe fn sd_ble_gap_privacy_get(p_privacy_params: *mut ble_gap_privacy_para
 #[doc = "                                                     existing advertising handle instead."]
 #[doc = " @retval ::BLE_ERROR_GAP_UUID_LIST_MISMATCH Invalid UUID list supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_adv_set_configure(
     p_adv_handle: *mut u8,
     p_adv_data: *const ble_gap_adv_data_t,
     p_adv_params: *const ble_gap_adv_params_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 114",
         inout("r0") to_asm(p_adv_handle) => ret,
         inout("r1") to_asm(p_adv_data) => _,
@@ -4838,6 +4889,10 @@ pub unsafe fn sd_ble_gap_adv_set_configure(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(114);
+    }
     ret
 }
 
@@ -4884,8 +4939,10 @@ pub unsafe fn sd_ble_gap_adv_set_configure(
 #[doc = "                                          the selected advertising phys. Use @ref sd_ble_cfg_set to increase the event length."]
 #[doc = " @retval ::NRF_ERROR_NOT_SUPPORTED Unsupported PHYs supplied to the call."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_adv_start(adv_handle: u8, conn_cfg_tag: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 115",
         inout("r0") to_asm(adv_handle) => ret,
         inout("r1") to_asm(conn_cfg_tag) => _,
@@ -4893,6 +4950,10 @@ pub unsafe fn sd_ble_gap_adv_start(adv_handle: u8, conn_cfg_tag: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(115);
+    }
     ret
 }
 
@@ -4908,8 +4969,10 @@ pub unsafe fn sd_ble_gap_adv_start(adv_handle: u8, conn_cfg_tag: u8) -> u32 {
 #[doc = " @retval ::BLE_ERROR_INVALID_ADV_HANDLE Invalid advertising handle."]
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE The advertising handle is not advertising."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_adv_stop(adv_handle: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 116",
         inout("r0") to_asm(adv_handle) => ret,
         lateout("r1") _,
@@ -4917,6 +4980,10 @@ pub unsafe fn sd_ble_gap_adv_stop(adv_handle: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(116);
+    }
     ret
 }
 
@@ -4946,8 +5013,10 @@ pub unsafe fn sd_ble_gap_adv_stop(adv_handle: u8) -> u32 {
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[doc = " @retval ::NRF_ERROR_NO_MEM Not enough memory to complete operation."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_conn_param_update(conn_handle: u16, p_conn_params: *const ble_gap_conn_params_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 117",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_conn_params) => _,
@@ -4955,6 +5024,10 @@ pub unsafe fn sd_ble_gap_conn_param_update(conn_handle: u16, p_conn_params: *con
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(117);
+    }
     ret
 }
 
@@ -4979,8 +5052,10 @@ pub unsafe fn sd_ble_gap_conn_param_update(conn_handle: u16, p_conn_params: *con
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE Disconnection in progress or link has not been established."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_disconnect(conn_handle: u16, hci_status_code: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 118",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(hci_status_code) => _,
@@ -4988,6 +5063,10 @@ pub unsafe fn sd_ble_gap_disconnect(conn_handle: u16, hci_status_code: u8) -> u3
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(118);
+    }
     ret
 }
 
@@ -5013,8 +5092,10 @@ pub unsafe fn sd_ble_gap_disconnect(conn_handle: u16, hci_status_code: u8) -> u3
 #[doc = " @retval ::BLE_ERROR_INVALID_ADV_HANDLE Advertising handle not found."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_tx_power_set(role: u8, handle: u16, tx_power: i8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 119",
         inout("r0") to_asm(role) => ret,
         inout("r1") to_asm(handle) => _,
@@ -5022,6 +5103,10 @@ pub unsafe fn sd_ble_gap_tx_power_set(role: u8, handle: u16, tx_power: i8) -> u3
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(119);
+    }
     ret
 }
 
@@ -5032,8 +5117,10 @@ pub unsafe fn sd_ble_gap_tx_power_set(role: u8, handle: u16, tx_power: i8) -> u3
 #[doc = " @retval ::NRF_SUCCESS  Appearance value set successfully."]
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM Invalid parameter(s) supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_appearance_set(appearance: u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 120",
         inout("r0") to_asm(appearance) => ret,
         lateout("r1") _,
@@ -5041,6 +5128,10 @@ pub unsafe fn sd_ble_gap_appearance_set(appearance: u16) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(120);
+    }
     ret
 }
 
@@ -5051,8 +5142,10 @@ pub unsafe fn sd_ble_gap_appearance_set(appearance: u16) -> u32 {
 #[doc = " @retval ::NRF_SUCCESS Appearance value retrieved successfully."]
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR Invalid pointer supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_appearance_get(p_appearance: *mut u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 121",
         inout("r0") to_asm(p_appearance) => ret,
         lateout("r1") _,
@@ -5060,6 +5153,10 @@ pub unsafe fn sd_ble_gap_appearance_get(p_appearance: *mut u16) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(121);
+    }
     ret
 }
 
@@ -5073,8 +5170,10 @@ pub unsafe fn sd_ble_gap_appearance_get(p_appearance: *mut u16) -> u32 {
 #[doc = " @retval ::NRF_ERROR_NOT_SUPPORTED The characteristic is not included in the Attribute Table,"]
 #[doc = "see @ref ble_gap_cfg_ppcp_incl_cfg_t."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_ppcp_set(p_conn_params: *const ble_gap_conn_params_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 122",
         inout("r0") to_asm(p_conn_params) => ret,
         lateout("r1") _,
@@ -5082,6 +5181,10 @@ pub unsafe fn sd_ble_gap_ppcp_set(p_conn_params: *const ble_gap_conn_params_t) -
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(122);
+    }
     ret
 }
 
@@ -5094,8 +5197,10 @@ pub unsafe fn sd_ble_gap_ppcp_set(p_conn_params: *const ble_gap_conn_params_t) -
 #[doc = " @retval ::NRF_ERROR_NOT_SUPPORTED The characteristic is not included in the Attribute Table,"]
 #[doc = "see @ref ble_gap_cfg_ppcp_incl_cfg_t."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_ppcp_get(p_conn_params: *mut ble_gap_conn_params_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 123",
         inout("r0") to_asm(p_conn_params) => ret,
         lateout("r1") _,
@@ -5103,6 +5208,10 @@ pub unsafe fn sd_ble_gap_ppcp_get(p_conn_params: *mut ble_gap_conn_params_t) ->
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(123);
+    }
     ret
 }
 
@@ -5121,12 +5230,14 @@ pub unsafe fn sd_ble_gap_ppcp_get(p_conn_params: *mut ble_gap_conn_params_t) ->
 #[doc = " @retval ::NRF_ERROR_DATA_SIZE Invalid data size(s) supplied."]
 #[doc = " @retval ::NRF_ERROR_FORBIDDEN Device name is not writable."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_device_name_set(
     p_write_perm: *const ble_gap_conn_sec_mode_t,
     p_dev_name: *const u8,
     len: u16,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 124",
         inout("r0") to_asm(p_write_perm) => ret,
         inout("r1") to_asm(p_dev_name) => _,
@@ -5134,6 +5245,10 @@ pub unsafe fn sd_ble_gap_device_name_set(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(124);
+    }
     ret
 }
 
@@ -5151,8 +5266,10 @@ pub unsafe fn sd_ble_gap_device_name_set(
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR Invalid pointer supplied."]
 #[doc = " @retval ::NRF_ERROR_DATA_SIZE Invalid data size(s) supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_device_name_get(p_dev_name: *mut u8, p_len: *mut u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 125",
         inout("r0") to_asm(p_dev_name) => ret,
         inout("r1") to_asm(p_len) => _,
@@ -5160,6 +5277,10 @@ pub unsafe fn sd_ble_gap_device_name_get(p_dev_name: *mut u8, p_len: *mut u16) -
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(125);
+    }
     ret
 }
 
@@ -5197,8 +5318,10 @@ pub unsafe fn sd_ble_gap_device_name_get(p_dev_name: *mut u8, p_len: *mut u16) -
 #[doc = " @retval ::NRF_ERROR_NO_MEM The maximum number of authentication procedures that can run in parallel for the given role is reached."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_authenticate(conn_handle: u16, p_sec_params: *const ble_gap_sec_params_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 126",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_sec_params) => _,
@@ -5206,6 +5329,10 @@ pub unsafe fn sd_ble_gap_authenticate(conn_handle: u16, p_sec_params: *const ble
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(126);
+    }
     ret
 }
 
@@ -5255,6 +5382,7 @@ pub unsafe fn sd_ble_gap_authenticate(conn_handle: u16, p_sec_params: *const ble
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[doc = " @retval ::NRF_ERROR_NOT_SUPPORTED Setting of sign or link fields in @ref ble_gap_sec_kdist_t not supported."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_sec_params_reply(
     conn_handle: u16,
     sec_status: u8,
@@ -5262,6 +5390,7 @@ pub unsafe fn sd_ble_gap_sec_params_reply(
     p_sec_keyset: *const ble_gap_sec_keyset_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 127",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(sec_status) => _,
@@ -5269,6 +5398,10 @@ pub unsafe fn sd_ble_gap_sec_params_reply(
         inout("r3") to_asm(p_sec_keyset) => _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(127);
+    }
     ret
 }
 
@@ -5300,8 +5433,10 @@ pub unsafe fn sd_ble_gap_sec_params_reply(
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE Authentication key has not been requested."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_auth_key_reply(conn_handle: u16, key_type: u8, p_key: *const u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 128",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(key_type) => _,
@@ -5309,6 +5444,10 @@ pub unsafe fn sd_ble_gap_auth_key_reply(conn_handle: u16, key_type: u8, p_key: *
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(128);
+    }
     ret
 }
 
@@ -5340,8 +5479,10 @@ pub unsafe fn sd_ble_gap_auth_key_reply(conn_handle: u16, key_type: u8, p_key: *
 #[doc = "                                   - The application has not pulled a @ref BLE_GAP_EVT_LESC_DHKEY_REQUEST event."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_lesc_dhkey_reply(conn_handle: u16, p_dhkey: *const ble_gap_lesc_dhkey_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 129",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_dhkey) => _,
@@ -5349,6 +5490,10 @@ pub unsafe fn sd_ble_gap_lesc_dhkey_reply(conn_handle: u16, p_dhkey: *const ble_
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(129);
+    }
     ret
 }
 
@@ -5370,8 +5515,10 @@ pub unsafe fn sd_ble_gap_lesc_dhkey_reply(conn_handle: u16, p_dhkey: *const ble_
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[doc = " @retval ::NRF_ERROR_BUSY The BLE stack is busy. Retry at later time."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_keypress_notify(conn_handle: u16, kp_not: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 130",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(kp_not) => _,
@@ -5379,6 +5526,10 @@ pub unsafe fn sd_ble_gap_keypress_notify(conn_handle: u16, kp_not: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(130);
+    }
     ret
 }
 
@@ -5399,12 +5550,14 @@ pub unsafe fn sd_ble_gap_keypress_notify(conn_handle: u16, kp_not: u8) -> u32 {
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR Invalid pointer supplied."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_lesc_oob_data_get(
     conn_handle: u16,
     p_pk_own: *const ble_gap_lesc_p256_pk_t,
     p_oobd_own: *mut ble_gap_lesc_oob_data_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 131",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_pk_own) => _,
@@ -5412,6 +5565,10 @@ pub unsafe fn sd_ble_gap_lesc_oob_data_get(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(131);
+    }
     ret
 }
 
@@ -5443,12 +5600,14 @@ pub unsafe fn sd_ble_gap_lesc_oob_data_get(
 #[doc = "                                   - Have not actually exchanged passkeys."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_lesc_oob_data_set(
     conn_handle: u16,
     p_oobd_own: *const ble_gap_lesc_oob_data_t,
     p_oobd_peer: *const ble_gap_lesc_oob_data_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 132",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_oobd_own) => _,
@@ -5456,6 +5615,10 @@ pub unsafe fn sd_ble_gap_lesc_oob_data_set(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(132);
+    }
     ret
 }
 
@@ -5482,6 +5645,7 @@ pub unsafe fn sd_ble_gap_lesc_oob_data_set(
 #[doc = "                                   - Encryption information provided by the app without being requested. See @ref ble_gap_evt_sec_info_request_t::enc_info."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_sec_info_reply(
     conn_handle: u16,
     p_enc_info: *const ble_gap_enc_info_t,
@@ -5489,6 +5653,7 @@ pub unsafe fn sd_ble_gap_sec_info_reply(
     p_sign_info: *const ble_gap_sign_info_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 134",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_enc_info) => _,
@@ -5496,6 +5661,10 @@ pub unsafe fn sd_ble_gap_sec_info_reply(
         inout("r3") to_asm(p_sign_info) => _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(134);
+    }
     ret
 }
 
@@ -5508,8 +5677,10 @@ pub unsafe fn sd_ble_gap_sec_info_reply(
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR Invalid pointer supplied."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_conn_sec_get(conn_handle: u16, p_conn_sec: *mut ble_gap_conn_sec_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 135",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_conn_sec) => _,
@@ -5517,6 +5688,10 @@ pub unsafe fn sd_ble_gap_conn_sec_get(conn_handle: u16, p_conn_sec: *mut ble_gap
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(135);
+    }
     ret
 }
 
@@ -5543,8 +5718,10 @@ pub unsafe fn sd_ble_gap_conn_sec_get(conn_handle: u16, p_conn_sec: *mut ble_gap
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE       RSSI reporting is already ongoing."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_rssi_start(conn_handle: u16, threshold_dbm: u8, skip_count: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 136",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(threshold_dbm) => _,
@@ -5552,6 +5729,10 @@ pub unsafe fn sd_ble_gap_rssi_start(conn_handle: u16, threshold_dbm: u8, skip_co
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(136);
+    }
     ret
 }
 
@@ -5571,8 +5752,10 @@ pub unsafe fn sd_ble_gap_rssi_start(conn_handle: u16, threshold_dbm: u8, skip_co
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE       RSSI reporting is not ongoing."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_rssi_stop(conn_handle: u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 137",
         inout("r0") to_asm(conn_handle) => ret,
         lateout("r1") _,
@@ -5580,6 +5763,10 @@ pub unsafe fn sd_ble_gap_rssi_stop(conn_handle: u16) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(137);
+    }
     ret
 }
 
@@ -5601,8 +5788,10 @@ pub unsafe fn sd_ble_gap_rssi_stop(conn_handle: u16) -> u32 {
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE       RSSI reporting is not ongoing."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_rssi_get(conn_handle: u16, p_rssi: *mut i8, p_ch_index: *mut u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 142",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_rssi) => _,
@@ -5610,6 +5799,10 @@ pub unsafe fn sd_ble_gap_rssi_get(conn_handle: u16, p_rssi: *mut i8, p_ch_index:
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(142);
+    }
     ret
 }
 
@@ -5661,8 +5854,10 @@ pub unsafe fn sd_ble_gap_rssi_get(conn_handle: u16, p_rssi: *mut i8, p_ch_index:
 #[doc = " @retval ::NRF_ERROR_BUSY Procedure is already in progress or not allowed at this time. Process pending events and wait for the pending procedure to complete and retry."]
 #[doc = ""]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_phy_update(conn_handle: u16, p_gap_phys: *const ble_gap_phys_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 143",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_gap_phys) => _,
@@ -5670,6 +5865,10 @@ pub unsafe fn sd_ble_gap_phy_update(conn_handle: u16, p_gap_phys: *const ble_gap
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(143);
+    }
     ret
 }
 
@@ -5694,8 +5893,10 @@ pub unsafe fn sd_ble_gap_phy_update(conn_handle: u16, p_gap_phys: *const ble_gap
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle parameter supplied."]
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR        Invalid pointer supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_next_conn_evt_counter_get(conn_handle: u16, p_counter: *mut u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 148",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_counter) => _,
@@ -5703,6 +5904,10 @@ pub unsafe fn sd_ble_gap_next_conn_evt_counter_get(conn_handle: u16, p_counter:
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(148);
+    }
     ret
 }
 
@@ -7452,12 +7657,14 @@ fn bindgen_test_layout_ble_gattc_evt_t() {
 #[doc = " @retval ::NRF_ERROR_BUSY Client procedure already in progress."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_primary_services_discover(
     conn_handle: u16,
     start_handle: u16,
     p_srvc_uuid: *const ble_uuid_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 155",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(start_handle) => _,
@@ -7465,6 +7672,10 @@ pub unsafe fn sd_ble_gattc_primary_services_discover(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(155);
+    }
     ret
 }
 
@@ -7492,11 +7703,13 @@ pub unsafe fn sd_ble_gattc_primary_services_discover(
 #[doc = " @retval ::NRF_ERROR_BUSY Client procedure already in progress."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_relationships_discover(
     conn_handle: u16,
     p_handle_range: *const ble_gattc_handle_range_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 156",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_handle_range) => _,
@@ -7504,6 +7717,10 @@ pub unsafe fn sd_ble_gattc_relationships_discover(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(156);
+    }
     ret
 }
 
@@ -7533,11 +7750,13 @@ pub unsafe fn sd_ble_gattc_relationships_discover(
 #[doc = " @retval ::NRF_ERROR_BUSY Client procedure already in progress."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_characteristics_discover(
     conn_handle: u16,
     p_handle_range: *const ble_gattc_handle_range_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 157",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_handle_range) => _,
@@ -7545,6 +7764,10 @@ pub unsafe fn sd_ble_gattc_characteristics_discover(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(157);
+    }
     ret
 }
 
@@ -7571,11 +7794,13 @@ pub unsafe fn sd_ble_gattc_characteristics_discover(
 #[doc = " @retval ::NRF_ERROR_BUSY Client procedure already in progress."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_descriptors_discover(
     conn_handle: u16,
     p_handle_range: *const ble_gattc_handle_range_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 158",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_handle_range) => _,
@@ -7583,6 +7808,10 @@ pub unsafe fn sd_ble_gattc_descriptors_discover(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(158);
+    }
     ret
 }
 
@@ -7610,12 +7839,14 @@ pub unsafe fn sd_ble_gattc_descriptors_discover(
 #[doc = " @retval ::NRF_ERROR_BUSY Client procedure already in progress."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_char_value_by_uuid_read(
     conn_handle: u16,
     p_uuid: *const ble_uuid_t,
     p_handle_range: *const ble_gattc_handle_range_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 160",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_uuid) => _,
@@ -7623,6 +7854,10 @@ pub unsafe fn sd_ble_gattc_char_value_by_uuid_read(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(160);
+    }
     ret
 }
 
@@ -7650,8 +7885,10 @@ pub unsafe fn sd_ble_gattc_char_value_by_uuid_read(
 #[doc = " @retval ::NRF_ERROR_BUSY Client procedure already in progress."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_read(conn_handle: u16, handle: u16, offset: u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 161",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(handle) => _,
@@ -7659,6 +7896,10 @@ pub unsafe fn sd_ble_gattc_read(conn_handle: u16, handle: u16, offset: u16) -> u
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(161);
+    }
     ret
 }
 
@@ -7685,8 +7926,10 @@ pub unsafe fn sd_ble_gattc_read(conn_handle: u16, handle: u16, offset: u16) -> u
 #[doc = " @retval ::NRF_ERROR_BUSY Client procedure already in progress."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_char_values_read(conn_handle: u16, p_handles: *const u16, handle_count: u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 162",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_handles) => _,
@@ -7694,6 +7937,10 @@ pub unsafe fn sd_ble_gattc_char_values_read(conn_handle: u16, p_handles: *const
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(162);
+    }
     ret
 }
 
@@ -7741,8 +7988,10 @@ pub unsafe fn sd_ble_gattc_char_values_read(conn_handle: u16, p_handles: *const
 #[doc = "                               Wait for a @ref BLE_GATTC_EVT_WRITE_CMD_TX_COMPLETE event and retry."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_write(conn_handle: u16, p_write_params: *const ble_gattc_write_params_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 163",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_write_params) => _,
@@ -7750,6 +7999,10 @@ pub unsafe fn sd_ble_gattc_write(conn_handle: u16, p_write_params: *const ble_ga
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(163);
+    }
     ret
 }
 
@@ -7768,8 +8021,10 @@ pub unsafe fn sd_ble_gattc_write(conn_handle: u16, p_write_params: *const ble_ga
 #[doc = " @retval ::BLE_ERROR_INVALID_ATTR_HANDLE Invalid attribute handle."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_hv_confirm(conn_handle: u16, handle: u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 164",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(handle) => _,
@@ -7777,6 +8032,10 @@ pub unsafe fn sd_ble_gattc_hv_confirm(conn_handle: u16, handle: u16) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(164);
+    }
     ret
 }
 
@@ -7796,11 +8055,13 @@ pub unsafe fn sd_ble_gattc_hv_confirm(conn_handle: u16, handle: u16) -> u32 {
 #[doc = " @retval ::NRF_ERROR_BUSY Client procedure already in progress."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_attr_info_discover(
     conn_handle: u16,
     p_handle_range: *const ble_gattc_handle_range_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 159",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_handle_range) => _,
@@ -7808,6 +8069,10 @@ pub unsafe fn sd_ble_gattc_attr_info_discover(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(159);
+    }
     ret
 }
 
@@ -7842,8 +8107,10 @@ pub unsafe fn sd_ble_gattc_attr_info_discover(
 #[doc = " @retval ::NRF_ERROR_BUSY Client procedure already in progress."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_exchange_mtu_request(conn_handle: u16, client_rx_mtu: u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 165",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(client_rx_mtu) => _,
@@ -7851,6 +8118,10 @@ pub unsafe fn sd_ble_gattc_exchange_mtu_request(conn_handle: u16, client_rx_mtu:
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(165);
+    }
     ret
 }
 
@@ -9410,8 +9681,10 @@ fn bindgen_test_layout_ble_gatts_evt_t() {
 #[doc = " @retval ::NRF_ERROR_FORBIDDEN Forbidden value supplied, certain UUIDs are reserved for the stack."]
 #[doc = " @retval ::NRF_ERROR_NO_MEM Not enough memory to complete operation."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_service_add(type_: u8, p_uuid: *const ble_uuid_t, p_handle: *mut u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 168",
         inout("r0") to_asm(type_) => ret,
         inout("r1") to_asm(p_uuid) => _,
@@ -9419,6 +9692,10 @@ pub unsafe fn sd_ble_gatts_service_add(type_: u8, p_uuid: *const ble_uuid_t, p_h
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(168);
+    }
     ret
 }
 
@@ -9445,8 +9722,10 @@ pub unsafe fn sd_ble_gatts_service_add(type_: u8, p_uuid: *const ble_uuid_t, p_h
 #[doc = " @retval ::NRF_ERROR_NO_MEM Not enough memory to complete operation."]
 #[doc = " @retval ::NRF_ERROR_NOT_FOUND Attribute not found."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_include_add(service_handle: u16, inc_srvc_handle: u16, p_include_handle: *mut u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 169",
         inout("r0") to_asm(service_handle) => ret,
         inout("r1") to_asm(inc_srvc_handle) => _,
@@ -9454,6 +9733,10 @@ pub unsafe fn sd_ble_gatts_include_add(service_handle: u16, inc_srvc_handle: u16
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(169);
+    }
     ret
 }
 
@@ -9483,6 +9766,7 @@ pub unsafe fn sd_ble_gatts_include_add(service_handle: u16, inc_srvc_handle: u16
 #[doc = " @retval ::NRF_ERROR_NO_MEM Not enough memory to complete operation."]
 #[doc = " @retval ::NRF_ERROR_DATA_SIZE Invalid data size(s) supplied, attribute lengths are restricted by @ref BLE_GATTS_ATTR_LENS_MAX."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_characteristic_add(
     service_handle: u16,
     p_char_md: *const ble_gatts_char_md_t,
@@ -9490,6 +9774,7 @@ pub unsafe fn sd_ble_gatts_characteristic_add(
     p_handles: *mut ble_gatts_char_handles_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 170",
         inout("r0") to_asm(service_handle) => ret,
         inout("r1") to_asm(p_char_md) => _,
@@ -9497,6 +9782,10 @@ pub unsafe fn sd_ble_gatts_characteristic_add(
         inout("r3") to_asm(p_handles) => _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(170);
+    }
     ret
 }
 
@@ -9520,12 +9809,14 @@ pub unsafe fn sd_ble_gatts_characteristic_add(
 #[doc = " @retval ::NRF_ERROR_NO_MEM Not enough memory to complete operation."]
 #[doc = " @retval ::NRF_ERROR_DATA_SIZE Invalid data size(s) supplied, attribute lengths are restricted by @ref BLE_GATTS_ATTR_LENS_MAX."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_descriptor_add(
     char_handle: u16,
     p_attr: *const ble_gatts_attr_t,
     p_handle: *mut u16,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 171",
         inout("r0") to_asm(char_handle) => ret,
         inout("r1") to_asm(p_attr) => _,
@@ -9533,6 +9824,10 @@ pub unsafe fn sd_ble_gatts_descriptor_add(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(171);
+    }
     ret
 }
 
@@ -9557,8 +9852,10 @@ pub unsafe fn sd_ble_gatts_descriptor_add(
 #[doc = " @retval ::NRF_ERROR_DATA_SIZE Invalid data size(s) supplied, attribute lengths are restricted by @ref BLE_GATTS_ATTR_LENS_MAX."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied on a system attribute."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_value_set(conn_handle: u16, handle: u16, p_value: *mut ble_gatts_value_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 172",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(handle) => _,
@@ -9566,6 +9863,10 @@ pub unsafe fn sd_ble_gatts_value_set(conn_handle: u16, handle: u16, p_value: *mu
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(172);
+    }
     ret
 }
 
@@ -9591,8 +9892,10 @@ pub unsafe fn sd_ble_gatts_value_set(conn_handle: u16, handle: u16, p_value: *mu
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied on a system attribute."]
 #[doc = " @retval ::BLE_ERROR_GATTS_SYS_ATTR_MISSING System attributes missing, use @ref sd_ble_gatts_sys_attr_set to set them to a known value."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_value_get(conn_handle: u16, handle: u16, p_value: *mut ble_gatts_value_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 173",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(handle) => _,
@@ -9600,6 +9903,10 @@ pub unsafe fn sd_ble_gatts_value_get(conn_handle: u16, handle: u16, p_value: *mu
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(173);
+    }
     ret
 }
 
@@ -9666,8 +9973,10 @@ pub unsafe fn sd_ble_gatts_value_get(conn_handle: u16, handle: u16, p_value: *mu
 #[doc = "                               Wait for a @ref BLE_GATTS_EVT_HVN_TX_COMPLETE event and retry."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_hvx(conn_handle: u16, p_hvx_params: *const ble_gatts_hvx_params_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 174",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_hvx_params) => _,
@@ -9675,6 +9984,10 @@ pub unsafe fn sd_ble_gatts_hvx(conn_handle: u16, p_hvx_params: *const ble_gatts_
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(174);
+    }
     ret
 }
 
@@ -9712,8 +10025,10 @@ pub unsafe fn sd_ble_gatts_hvx(conn_handle: u16, p_hvx_params: *const ble_gatts_
 #[doc = " @retval ::BLE_ERROR_GATTS_SYS_ATTR_MISSING System attributes missing, use @ref sd_ble_gatts_sys_attr_set to set them to a known value."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_service_changed(conn_handle: u16, start_handle: u16, end_handle: u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 175",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(start_handle) => _,
@@ -9721,6 +10036,10 @@ pub unsafe fn sd_ble_gatts_service_changed(conn_handle: u16, start_handle: u16,
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(175);
+    }
     ret
 }
 
@@ -9755,11 +10074,13 @@ pub unsafe fn sd_ble_gatts_service_changed(conn_handle: u16, start_handle: u16,
 #[doc = "                                         or invalid data to be written provided by the application."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_rw_authorize_reply(
     conn_handle: u16,
     p_rw_authorize_reply_params: *const ble_gatts_rw_authorize_reply_params_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 176",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_rw_authorize_reply_params) => _,
@@ -9767,6 +10088,10 @@ pub unsafe fn sd_ble_gatts_rw_authorize_reply(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(176);
+    }
     ret
 }
 
@@ -9811,8 +10136,10 @@ pub unsafe fn sd_ble_gatts_rw_authorize_reply(
 #[doc = " @retval ::NRF_ERROR_INVALID_DATA Invalid data supplied, the data should be exactly the same as retrieved with @ref sd_ble_gatts_sys_attr_get."]
 #[doc = " @retval ::NRF_ERROR_NO_MEM Not enough memory to complete operation."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_sys_attr_set(conn_handle: u16, p_sys_attr_data: *const u8, len: u16, flags: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 177",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_sys_attr_data) => _,
@@ -9820,6 +10147,10 @@ pub unsafe fn sd_ble_gatts_sys_attr_set(conn_handle: u16, p_sys_attr_data: *cons
         inout("r3") to_asm(flags) => _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(177);
+    }
     ret
 }
 
@@ -9853,6 +10184,7 @@ pub unsafe fn sd_ble_gatts_sys_attr_set(conn_handle: u16, p_sys_attr_data: *cons
 #[doc = " @retval ::NRF_ERROR_DATA_SIZE The system attribute information did not fit into the provided buffer."]
 #[doc = " @retval ::NRF_ERROR_NOT_FOUND No system attributes found."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_sys_attr_get(
     conn_handle: u16,
     p_sys_attr_data: *mut u8,
@@ -9860,6 +10192,7 @@ pub unsafe fn sd_ble_gatts_sys_attr_get(
     flags: u32,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 178",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_sys_attr_data) => _,
@@ -9867,6 +10200,10 @@ pub unsafe fn sd_ble_gatts_sys_attr_get(
         inout("r3") to_asm(flags) => _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(178);
+    }
     ret
 }
 
@@ -9877,8 +10214,10 @@ pub unsafe fn sd_ble_gatts_sys_attr_get(
 #[doc = " @retval ::NRF_SUCCESS Successfully retrieved the handle."]
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR Invalid pointer supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_initial_user_handle_get(p_handle: *mut u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 179",
         inout("r0") to_asm(p_handle) => ret,
         lateout("r1") _,
@@ -9886,6 +10225,10 @@ pub unsafe fn sd_ble_gatts_initial_user_handle_get(p_handle: *mut u16) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(179);
+    }
     ret
 }
 
@@ -9900,8 +10243,10 @@ pub unsafe fn sd_ble_gatts_initial_user_handle_get(p_handle: *mut u16) -> u32 {
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM Invalid parameters supplied. Returned when both @c p_uuid and @c p_md are NULL."]
 #[doc = " @retval ::NRF_ERROR_NOT_FOUND Attribute was not found."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_attr_get(handle: u16, p_uuid: *mut ble_uuid_t, p_md: *mut ble_gatts_attr_md_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 180",
         inout("r0") to_asm(handle) => ret,
         inout("r1") to_asm(p_uuid) => _,
@@ -9909,6 +10254,10 @@ pub unsafe fn sd_ble_gatts_attr_get(handle: u16, p_uuid: *mut ble_uuid_t, p_md:
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(180);
+    }
     ret
 }
 
@@ -9940,8 +10289,10 @@ pub unsafe fn sd_ble_gatts_attr_get(handle: u16, p_uuid: *mut ble_uuid_t, p_md:
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM Invalid Server RX MTU size supplied."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_exchange_mtu_reply(conn_handle: u16, server_rx_mtu: u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 181",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(server_rx_mtu) => _,
@@ -9949,6 +10300,10 @@ pub unsafe fn sd_ble_gatts_exchange_mtu_reply(conn_handle: u16, server_rx_mtu: u
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(181);
+    }
     ret
 }
 
@@ -11013,8 +11368,10 @@ fn bindgen_test_layout_ble_cfg_t() {
 #[doc = "                                      is currently not supported."]
 #[doc = " @retval ::NRF_ERROR_RESOURCES      The total number of L2CAP Channels configured using @ref sd_ble_cfg_set is too large."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_enable(p_app_ram_base: *mut u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 96",
         inout("r0") to_asm(p_app_ram_base) => ret,
         lateout("r1") _,
@@ -11022,6 +11379,10 @@ pub unsafe fn sd_ble_enable(p_app_ram_base: *mut u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(96);
+    }
     ret
 }
 
@@ -11059,8 +11420,10 @@ pub unsafe fn sd_ble_enable(p_app_ram_base: *mut u32) -> u32 {
 #[doc = " @retval ::NRF_ERROR_NO_MEM         The amount of memory assigned to the SoftDevice by app_ram_base is not"]
 #[doc = "                                    large enough to fit this configuration's memory requirement."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_cfg_set(cfg_id: u32, p_cfg: *const ble_cfg_t, app_ram_base: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 105",
         inout("r0") to_asm(cfg_id) => ret,
         inout("r1") to_asm(p_cfg) => _,
@@ -11068,6 +11431,10 @@ pub unsafe fn sd_ble_cfg_set(cfg_id: u32, p_cfg: *const ble_cfg_t, app_ram_base:
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(105);
+    }
     ret
 }
 
@@ -11107,8 +11474,10 @@ pub unsafe fn sd_ble_cfg_set(cfg_id: u32, p_cfg: *const ble_cfg_t, app_ram_base:
 #[doc = " @retval ::NRF_ERROR_NOT_FOUND No events ready to be pulled."]
 #[doc = " @retval ::NRF_ERROR_DATA_SIZE Event ready but could not fit into the supplied buffer."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_evt_get(p_dest: *mut u8, p_len: *mut u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 97",
         inout("r0") to_asm(p_dest) => ret,
         inout("r1") to_asm(p_len) => _,
@@ -11116,6 +11485,10 @@ pub unsafe fn sd_ble_evt_get(p_dest: *mut u8, p_len: *mut u16) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(97);
+    }
     ret
 }
 
@@ -11145,8 +11518,10 @@ pub unsafe fn sd_ble_evt_get(p_dest: *mut u8, p_len: *mut u16) -> u32 {
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR If p_vs_uuid or p_uuid_type is NULL or invalid."]
 #[doc = " @retval ::NRF_ERROR_NO_MEM If there are no more free slots for VS UUIDs."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_uuid_vs_add(p_vs_uuid: *const ble_uuid128_t, p_uuid_type: *mut u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 98",
         inout("r0") to_asm(p_vs_uuid) => ret,
         inout("r1") to_asm(p_uuid_type) => _,
@@ -11154,6 +11529,10 @@ pub unsafe fn sd_ble_uuid_vs_add(p_vs_uuid: *const ble_uuid128_t, p_uuid_type: *
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(98);
+    }
     ret
 }
 
@@ -11175,8 +11554,10 @@ pub unsafe fn sd_ble_uuid_vs_add(p_vs_uuid: *const ble_uuid128_t, p_uuid_type: *
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM If p_uuid_type points to a non-valid UUID type."]
 #[doc = " @retval ::NRF_ERROR_FORBIDDEN If the Vendor Specific base UUID is in use by the ATT Server."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_uuid_vs_remove(p_uuid_type: *mut u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 106",
         inout("r0") to_asm(p_uuid_type) => ret,
         lateout("r1") _,
@@ -11184,6 +11565,10 @@ pub unsafe fn sd_ble_uuid_vs_remove(p_uuid_type: *mut u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(106);
+    }
     ret
 }
 
@@ -11205,8 +11590,10 @@ pub unsafe fn sd_ble_uuid_vs_remove(p_uuid_type: *mut u8) -> u32 {
 #[doc = " @retval ::NRF_ERROR_INVALID_LENGTH Invalid UUID length."]
 #[doc = " @retval ::NRF_ERROR_NOT_FOUND For a 128-bit UUID, no match in the populated table of UUIDs."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_uuid_decode(uuid_le_len: u8, p_uuid_le: *const u8, p_uuid: *mut ble_uuid_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 99",
         inout("r0") to_asm(uuid_le_len) => ret,
         inout("r1") to_asm(p_uuid_le) => _,
@@ -11214,6 +11601,10 @@ pub unsafe fn sd_ble_uuid_decode(uuid_le_len: u8, p_uuid_le: *const u8, p_uuid:
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(99);
+    }
     ret
 }
 
@@ -11229,8 +11620,10 @@ pub unsafe fn sd_ble_uuid_decode(uuid_le_len: u8, p_uuid_le: *const u8, p_uuid:
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR Invalid pointer supplied."]
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM Invalid UUID type."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_uuid_encode(p_uuid: *const ble_uuid_t, p_uuid_le_len: *mut u8, p_uuid_le: *mut u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 100",
         inout("r0") to_asm(p_uuid) => ret,
         inout("r1") to_asm(p_uuid_le_len) => _,
@@ -11238,6 +11631,10 @@ pub unsafe fn sd_ble_uuid_encode(p_uuid: *const ble_uuid_t, p_uuid_le_len: *mut
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(100);
+    }
     ret
 }
 
@@ -11251,8 +11648,10 @@ pub unsafe fn sd_ble_uuid_encode(p_uuid: *const ble_uuid_t, p_uuid_le_len: *mut
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR Invalid pointer supplied."]
 #[doc = " @retval ::NRF_ERROR_BUSY The BLE stack is busy (typically doing a locally-initiated disconnection procedure)."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_version_get(p_version: *mut ble_version_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 101",
         inout("r0") to_asm(p_version) => ret,
         lateout("r1") _,
@@ -11260,6 +11659,10 @@ pub unsafe fn sd_ble_version_get(p_version: *mut ble_version_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(101);
+    }
     ret
 }
 
@@ -11286,8 +11689,10 @@ pub unsafe fn sd_ble_version_get(p_version: *mut ble_version_t) -> u32 {
 #[doc = " @retval ::NRF_ERROR_INVALID_LENGTH Invalid user memory block length supplied."]
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE Invalid Connection state or no user memory request pending."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_user_mem_reply(conn_handle: u16, p_block: *const ble_user_mem_block_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 102",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_block) => _,
@@ -11295,6 +11700,10 @@ pub unsafe fn sd_ble_user_mem_reply(conn_handle: u16, p_block: *const ble_user_m
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(102);
+    }
     ret
 }
 
@@ -11312,8 +11721,10 @@ pub unsafe fn sd_ble_user_mem_reply(conn_handle: u16, p_block: *const ble_user_m
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE Unable to set the parameter at this time."]
 #[doc = " @retval ::NRF_ERROR_BUSY The BLE stack is busy or the previous procedure has not completed."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_opt_set(opt_id: u32, p_opt: *const ble_opt_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 103",
         inout("r0") to_asm(opt_id) => ret,
         inout("r1") to_asm(p_opt) => _,
@@ -11321,6 +11732,10 @@ pub unsafe fn sd_ble_opt_set(opt_id: u32, p_opt: *const ble_opt_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(103);
+    }
     ret
 }
 
@@ -11340,8 +11755,10 @@ pub unsafe fn sd_ble_opt_set(opt_id: u32, p_opt: *const ble_opt_t) -> u32 {
 #[doc = " @retval ::NRF_ERROR_NOT_SUPPORTED This option is not supported."]
 #[doc = ""]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_opt_get(opt_id: u32, p_opt: *mut ble_opt_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 104",
         inout("r0") to_asm(opt_id) => ret,
         inout("r1") to_asm(p_opt) => _,
@@ -11349,6 +11766,10 @@ pub unsafe fn sd_ble_opt_get(opt_id: u32, p_opt: *mut ble_opt_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(104);
+    }
     ret
 }
 
@@ -12075,8 +12496,10 @@ fn bindgen_test_layout_nrf_ecb_hal_data_block_t() {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_mutex_new(p_mutex: *mut nrf_mutex_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 44",
         inout("r0") to_asm(p_mutex) => ret,
         lateout("r1") _,
@@ -12084,6 +12507,10 @@ pub unsafe fn sd_mutex_new(p_mutex: *mut nrf_mutex_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(44);
+    }
     ret
 }
 
@@ -12094,8 +12521,10 @@ pub unsafe fn sd_mutex_new(p_mutex: *mut nrf_mutex_t) -> u32 {
 #[doc = " @retval ::NRF_SUCCESS The mutex was successfully acquired."]
 #[doc = " @retval ::NRF_ERROR_SOC_MUTEX_ALREADY_TAKEN The mutex could not be acquired."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_mutex_acquire(p_mutex: *mut nrf_mutex_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 45",
         inout("r0") to_asm(p_mutex) => ret,
         lateout("r1") _,
@@ -12103,6 +12532,10 @@ pub unsafe fn sd_mutex_acquire(p_mutex: *mut nrf_mutex_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(45);
+    }
     ret
 }
 
@@ -12112,8 +12545,10 @@ pub unsafe fn sd_mutex_acquire(p_mutex: *mut nrf_mutex_t) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_mutex_release(p_mutex: *mut nrf_mutex_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 46",
         inout("r0") to_asm(p_mutex) => ret,
         lateout("r1") _,
@@ -12121,6 +12556,10 @@ pub unsafe fn sd_mutex_release(p_mutex: *mut nrf_mutex_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(46);
+    }
     ret
 }
 
@@ -12130,8 +12569,10 @@ pub unsafe fn sd_mutex_release(p_mutex: *mut nrf_mutex_t) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_rand_application_pool_capacity_get(p_pool_capacity: *mut u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 47",
         inout("r0") to_asm(p_pool_capacity) => ret,
         lateout("r1") _,
@@ -12139,6 +12580,10 @@ pub unsafe fn sd_rand_application_pool_capacity_get(p_pool_capacity: *mut u8) ->
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(47);
+    }
     ret
 }
 
@@ -12148,8 +12593,10 @@ pub unsafe fn sd_rand_application_pool_capacity_get(p_pool_capacity: *mut u8) ->
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_rand_application_bytes_available_get(p_bytes_available: *mut u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 48",
         inout("r0") to_asm(p_bytes_available) => ret,
         lateout("r1") _,
@@ -12157,6 +12604,10 @@ pub unsafe fn sd_rand_application_bytes_available_get(p_bytes_available: *mut u8
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(48);
+    }
     ret
 }
 
@@ -12168,8 +12619,10 @@ pub unsafe fn sd_rand_application_bytes_available_get(p_bytes_available: *mut u8
 #[doc = " @retval ::NRF_SUCCESS The requested bytes were written to p_buff."]
 #[doc = " @retval ::NRF_ERROR_SOC_RAND_NOT_ENOUGH_VALUES No bytes were written to the buffer, because there were not enough bytes available."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_rand_application_vector_get(p_buff: *mut u8, length: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 49",
         inout("r0") to_asm(p_buff) => ret,
         inout("r1") to_asm(length) => _,
@@ -12177,6 +12630,10 @@ pub unsafe fn sd_rand_application_vector_get(p_buff: *mut u8, length: u8) -> u32
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(49);
+    }
     ret
 }
 
@@ -12186,8 +12643,10 @@ pub unsafe fn sd_rand_application_vector_get(p_buff: *mut u8, length: u8) -> u32
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_reset_reason_get(p_reset_reason: *mut u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 52",
         inout("r0") to_asm(p_reset_reason) => ret,
         lateout("r1") _,
@@ -12195,6 +12654,10 @@ pub unsafe fn sd_power_reset_reason_get(p_reset_reason: *mut u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(52);
+    }
     ret
 }
 
@@ -12204,8 +12667,10 @@ pub unsafe fn sd_power_reset_reason_get(p_reset_reason: *mut u32) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_reset_reason_clr(reset_reason_clr_msk: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 53",
         inout("r0") to_asm(reset_reason_clr_msk) => ret,
         lateout("r1") _,
@@ -12213,6 +12678,10 @@ pub unsafe fn sd_power_reset_reason_clr(reset_reason_clr_msk: u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(53);
+    }
     ret
 }
 
@@ -12223,8 +12692,10 @@ pub unsafe fn sd_power_reset_reason_clr(reset_reason_clr_msk: u32) -> u32 {
 #[doc = " @retval ::NRF_SUCCESS The power mode was set."]
 #[doc = " @retval ::NRF_ERROR_SOC_POWER_MODE_UNKNOWN The power mode was unknown."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_mode_set(power_mode: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 50",
         inout("r0") to_asm(power_mode) => ret,
         lateout("r1") _,
@@ -12232,6 +12703,10 @@ pub unsafe fn sd_power_mode_set(power_mode: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(50);
+    }
     ret
 }
 
@@ -12239,8 +12714,10 @@ pub unsafe fn sd_power_mode_set(power_mode: u8) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_ERROR_SOC_POWER_OFF_SHOULD_NOT_RETURN"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_system_off() -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 51",
         lateout("r0") ret,
         lateout("r1") _,
@@ -12248,6 +12725,10 @@ pub unsafe fn sd_power_system_off() -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(51);
+    }
     ret
 }
 
@@ -12260,8 +12741,10 @@ pub unsafe fn sd_power_system_off() -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_pof_enable(pof_enable: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 54",
         inout("r0") to_asm(pof_enable) => ret,
         lateout("r1") _,
@@ -12269,6 +12752,10 @@ pub unsafe fn sd_power_pof_enable(pof_enable: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(54);
+    }
     ret
 }
 
@@ -12280,8 +12767,10 @@ pub unsafe fn sd_power_pof_enable(pof_enable: u8) -> u32 {
 #[doc = " @retval ::NRF_SUCCESS The power failure threshold was set."]
 #[doc = " @retval ::NRF_ERROR_SOC_POWER_POF_THRESHOLD_UNKNOWN The power failure threshold is unknown."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_pof_threshold_set(threshold: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 55",
         inout("r0") to_asm(threshold) => ret,
         lateout("r1") _,
@@ -12289,6 +12778,10 @@ pub unsafe fn sd_power_pof_threshold_set(threshold: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(55);
+    }
     ret
 }
 
@@ -12299,8 +12792,10 @@ pub unsafe fn sd_power_pof_threshold_set(threshold: u8) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_ram_power_set(index: u8, ram_powerset: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 57",
         inout("r0") to_asm(index) => ret,
         inout("r1") to_asm(ram_powerset) => _,
@@ -12308,6 +12803,10 @@ pub unsafe fn sd_power_ram_power_set(index: u8, ram_powerset: u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(57);
+    }
     ret
 }
 
@@ -12318,8 +12817,10 @@ pub unsafe fn sd_power_ram_power_set(index: u8, ram_powerset: u32) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_ram_power_clr(index: u8, ram_powerclr: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 58",
         inout("r0") to_asm(index) => ret,
         inout("r1") to_asm(ram_powerclr) => _,
@@ -12327,6 +12828,10 @@ pub unsafe fn sd_power_ram_power_clr(index: u8, ram_powerclr: u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(58);
+    }
     ret
 }
 
@@ -12337,8 +12842,10 @@ pub unsafe fn sd_power_ram_power_clr(index: u8, ram_powerclr: u32) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_ram_power_get(index: u8, p_ram_power: *mut u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 59",
         inout("r0") to_asm(index) => ret,
         inout("r1") to_asm(p_ram_power) => _,
@@ -12346,6 +12853,10 @@ pub unsafe fn sd_power_ram_power_get(index: u8, p_ram_power: *mut u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(59);
+    }
     ret
 }
 
@@ -12356,8 +12867,10 @@ pub unsafe fn sd_power_ram_power_get(index: u8, p_ram_power: *mut u32) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_gpregret_set(gpregret_id: u32, gpregret_msk: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 60",
         inout("r0") to_asm(gpregret_id) => ret,
         inout("r1") to_asm(gpregret_msk) => _,
@@ -12365,6 +12878,10 @@ pub unsafe fn sd_power_gpregret_set(gpregret_id: u32, gpregret_msk: u32) -> u32
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(60);
+    }
     ret
 }
 
@@ -12375,8 +12892,10 @@ pub unsafe fn sd_power_gpregret_set(gpregret_id: u32, gpregret_msk: u32) -> u32
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_gpregret_clr(gpregret_id: u32, gpregret_msk: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 61",
         inout("r0") to_asm(gpregret_id) => ret,
         inout("r1") to_asm(gpregret_msk) => _,
@@ -12384,6 +12903,10 @@ pub unsafe fn sd_power_gpregret_clr(gpregret_id: u32, gpregret_msk: u32) -> u32
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(61);
+    }
     ret
 }
 
@@ -12394,8 +12917,10 @@ pub unsafe fn sd_power_gpregret_clr(gpregret_id: u32, gpregret_msk: u32) -> u32
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_gpregret_get(gpregret_id: u32, p_gpregret: *mut u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 62",
         inout("r0") to_asm(gpregret_id) => ret,
         inout("r1") to_asm(p_gpregret) => _,
@@ -12403,6 +12928,10 @@ pub unsafe fn sd_power_gpregret_get(gpregret_id: u32, p_gpregret: *mut u32) -> u
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(62);
+    }
     ret
 }
 
@@ -12413,8 +12942,10 @@ pub unsafe fn sd_power_gpregret_get(gpregret_id: u32, p_gpregret: *mut u32) -> u
 #[doc = " @retval ::NRF_SUCCESS"]
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM The DCDC mode is invalid."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_dcdc_mode_set(dcdc_mode: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 63",
         inout("r0") to_asm(dcdc_mode) => ret,
         lateout("r1") _,
@@ -12422,6 +12953,10 @@ pub unsafe fn sd_power_dcdc_mode_set(dcdc_mode: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(63);
+    }
     ret
 }
 
@@ -12435,8 +12970,10 @@ pub unsafe fn sd_power_dcdc_mode_set(dcdc_mode: u8) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_clock_hfclk_request() -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 66",
         lateout("r0") ret,
         lateout("r1") _,
@@ -12444,6 +12981,10 @@ pub unsafe fn sd_clock_hfclk_request() -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(66);
+    }
     ret
 }
 
@@ -12456,8 +12997,10 @@ pub unsafe fn sd_clock_hfclk_request() -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_clock_hfclk_release() -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 67",
         lateout("r0") ret,
         lateout("r1") _,
@@ -12465,6 +13008,10 @@ pub unsafe fn sd_clock_hfclk_release() -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(67);
+    }
     ret
 }
 
@@ -12477,8 +13024,10 @@ pub unsafe fn sd_clock_hfclk_release() -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_clock_hfclk_is_running(p_is_running: *mut u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 68",
         inout("r0") to_asm(p_is_running) => ret,
         lateout("r1") _,
@@ -12486,6 +13035,10 @@ pub unsafe fn sd_clock_hfclk_is_running(p_is_running: *mut u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(68);
+    }
     ret
 }
 
@@ -12515,8 +13068,10 @@ pub unsafe fn sd_clock_hfclk_is_running(p_is_running: *mut u32) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_app_evt_wait() -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 65",
         lateout("r0") ret,
         lateout("r1") _,
@@ -12524,6 +13079,10 @@ pub unsafe fn sd_app_evt_wait() -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(65);
+    }
     ret
 }
 
@@ -12533,8 +13092,10 @@ pub unsafe fn sd_app_evt_wait() -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ppi_channel_enable_get(p_channel_enable: *mut u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 32",
         inout("r0") to_asm(p_channel_enable) => ret,
         lateout("r1") _,
@@ -12542,6 +13103,10 @@ pub unsafe fn sd_ppi_channel_enable_get(p_channel_enable: *mut u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(32);
+    }
     ret
 }
 
@@ -12551,8 +13116,10 @@ pub unsafe fn sd_ppi_channel_enable_get(p_channel_enable: *mut u32) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ppi_channel_enable_set(channel_enable_set_msk: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 33",
         inout("r0") to_asm(channel_enable_set_msk) => ret,
         lateout("r1") _,
@@ -12560,6 +13127,10 @@ pub unsafe fn sd_ppi_channel_enable_set(channel_enable_set_msk: u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(33);
+    }
     ret
 }
 
@@ -12569,8 +13140,10 @@ pub unsafe fn sd_ppi_channel_enable_set(channel_enable_set_msk: u32) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ppi_channel_enable_clr(channel_enable_clr_msk: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 34",
         inout("r0") to_asm(channel_enable_clr_msk) => ret,
         lateout("r1") _,
@@ -12578,6 +13151,10 @@ pub unsafe fn sd_ppi_channel_enable_clr(channel_enable_clr_msk: u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(34);
+    }
     ret
 }
 
@@ -12590,12 +13167,14 @@ pub unsafe fn sd_ppi_channel_enable_clr(channel_enable_clr_msk: u32) -> u32 {
 #[doc = " @retval ::NRF_ERROR_SOC_PPI_INVALID_CHANNEL The channel number is invalid."]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ppi_channel_assign(
     channel_num: u8,
     evt_endpoint: *const self::c_void,
     task_endpoint: *const self::c_void,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 35",
         inout("r0") to_asm(channel_num) => ret,
         inout("r1") to_asm(evt_endpoint) => _,
@@ -12603,6 +13182,10 @@ pub unsafe fn sd_ppi_channel_assign(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(35);
+    }
     ret
 }
 
@@ -12613,8 +13196,10 @@ pub unsafe fn sd_ppi_channel_assign(
 #[doc = " @retval ::NRF_ERROR_SOC_PPI_INVALID_GROUP The group number is invalid"]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ppi_group_task_enable(group_num: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 36",
         inout("r0") to_asm(group_num) => ret,
         lateout("r1") _,
@@ -12622,6 +13207,10 @@ pub unsafe fn sd_ppi_group_task_enable(group_num: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(36);
+    }
     ret
 }
 
@@ -12632,8 +13221,10 @@ pub unsafe fn sd_ppi_group_task_enable(group_num: u8) -> u32 {
 #[doc = " @retval ::NRF_ERROR_SOC_PPI_INVALID_GROUP The group number is invalid."]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ppi_group_task_disable(group_num: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 37",
         inout("r0") to_asm(group_num) => ret,
         lateout("r1") _,
@@ -12641,6 +13232,10 @@ pub unsafe fn sd_ppi_group_task_disable(group_num: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(37);
+    }
     ret
 }
 
@@ -12652,8 +13247,10 @@ pub unsafe fn sd_ppi_group_task_disable(group_num: u8) -> u32 {
 #[doc = " @retval ::NRF_ERROR_SOC_PPI_INVALID_GROUP The group number is invalid."]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ppi_group_assign(group_num: u8, channel_msk: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 38",
         inout("r0") to_asm(group_num) => ret,
         inout("r1") to_asm(channel_msk) => _,
@@ -12661,6 +13258,10 @@ pub unsafe fn sd_ppi_group_assign(group_num: u8, channel_msk: u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(38);
+    }
     ret
 }
 
@@ -12672,8 +13273,10 @@ pub unsafe fn sd_ppi_group_assign(group_num: u8, channel_msk: u32) -> u32 {
 #[doc = " @retval ::NRF_ERROR_SOC_PPI_INVALID_GROUP The group number is invalid."]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ppi_group_get(group_num: u8, p_channel_msk: *mut u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 39",
         inout("r0") to_asm(group_num) => ret,
         inout("r1") to_asm(p_channel_msk) => _,
@@ -12681,6 +13284,10 @@ pub unsafe fn sd_ppi_group_get(group_num: u8, p_channel_msk: *mut u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(39);
+    }
     ret
 }
 
@@ -12713,8 +13320,10 @@ pub unsafe fn sd_ppi_group_get(group_num: u8, p_channel_msk: *mut u32) -> u32 {
 #[doc = "                                   running activities and retry."]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_radio_notification_cfg_set(type_: u8, distance: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 69",
         inout("r0") to_asm(type_) => ret,
         inout("r1") to_asm(distance) => _,
@@ -12722,6 +13331,10 @@ pub unsafe fn sd_radio_notification_cfg_set(type_: u8, distance: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(69);
+    }
     ret
 }
 
@@ -12739,8 +13352,10 @@ pub unsafe fn sd_radio_notification_cfg_set(type_: u8, distance: u8) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ecb_block_encrypt(p_ecb_data: *mut nrf_ecb_hal_data_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 70",
         inout("r0") to_asm(p_ecb_data) => ret,
         lateout("r1") _,
@@ -12748,6 +13363,10 @@ pub unsafe fn sd_ecb_block_encrypt(p_ecb_data: *mut nrf_ecb_hal_data_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(70);
+    }
     ret
 }
 
@@ -12766,8 +13385,10 @@ pub unsafe fn sd_ecb_block_encrypt(p_ecb_data: *mut nrf_ecb_hal_data_t) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ecb_blocks_encrypt(block_count: u8, p_data_blocks: *mut nrf_ecb_hal_data_block_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 71",
         inout("r0") to_asm(block_count) => ret,
         inout("r1") to_asm(p_data_blocks) => _,
@@ -12775,6 +13396,10 @@ pub unsafe fn sd_ecb_blocks_encrypt(block_count: u8, p_data_blocks: *mut nrf_ecb
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(71);
+    }
     ret
 }
 
@@ -12787,8 +13412,10 @@ pub unsafe fn sd_ecb_blocks_encrypt(block_count: u8, p_data_blocks: *mut nrf_ecb
 #[doc = " @retval ::NRF_SUCCESS An event was pending. The event id is written in the p_evt_id parameter."]
 #[doc = " @retval ::NRF_ERROR_NOT_FOUND No pending events."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_evt_get(p_evt_id: *mut u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 75",
         inout("r0") to_asm(p_evt_id) => ret,
         lateout("r1") _,
@@ -12796,6 +13423,10 @@ pub unsafe fn sd_evt_get(p_evt_id: *mut u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(75);
+    }
     ret
 }
 
@@ -12808,8 +13439,10 @@ pub unsafe fn sd_evt_get(p_evt_id: *mut u32) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS A temperature measurement was done, and the temperature was written to temp"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_temp_get(p_temp: *mut i32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 76",
         inout("r0") to_asm(p_temp) => ret,
         lateout("r1") _,
@@ -12817,6 +13450,10 @@ pub unsafe fn sd_temp_get(p_temp: *mut i32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(76);
+    }
     ret
 }
 
@@ -12855,8 +13492,10 @@ pub unsafe fn sd_temp_get(p_temp: *mut i32) -> u32 {
 #[doc = " @retval ::NRF_ERROR_FORBIDDEN      Tried to write to an address outside the application flash area."]
 #[doc = " @retval ::NRF_SUCCESS              The command was accepted."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_flash_write(p_dst: *mut u32, p_src: *const u32, size: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 41",
         inout("r0") to_asm(p_dst) => ret,
         inout("r1") to_asm(p_src) => _,
@@ -12864,6 +13503,10 @@ pub unsafe fn sd_flash_write(p_dst: *mut u32, p_src: *const u32, size: u32) -> u
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(41);
+    }
     ret
 }
 
@@ -12896,8 +13539,10 @@ pub unsafe fn sd_flash_write(p_dst: *mut u32, p_src: *const u32, size: u32) -> u
 #[doc = " @retval ::NRF_ERROR_FORBIDDEN     Tried to erase a page outside the application flash area."]
 #[doc = " @retval ::NRF_SUCCESS             The command was accepted."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_flash_page_erase(page_number: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 40",
         inout("r0") to_asm(page_number) => ret,
         lateout("r1") _,
@@ -12905,6 +13550,10 @@ pub unsafe fn sd_flash_page_erase(page_number: u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(40);
+    }
     ret
 }
 
@@ -12928,8 +13577,10 @@ pub unsafe fn sd_flash_page_erase(page_number: u32) -> u32 {
 #[doc = " @retval ::NRF_ERROR_NOT_SUPPORTED Non-zero value supplied to one or more of the unsupported parameters."]
 #[doc = " @retval ::NRF_SUCCESS Values successfully written to configuration registers."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_flash_protect(block_cfg0: u32, block_cfg1: u32, block_cfg2: u32, block_cfg3: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 42",
         inout("r0") to_asm(block_cfg0) => ret,
         inout("r1") to_asm(block_cfg1) => _,
@@ -12937,6 +13588,10 @@ pub unsafe fn sd_flash_protect(block_cfg0: u32, block_cfg1: u32, block_cfg2: u32
         inout("r3") to_asm(block_cfg3) => _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(42);
+    }
     ret
 }
 
@@ -12960,8 +13615,10 @@ pub unsafe fn sd_flash_protect(block_cfg0: u32, block_cfg1: u32, block_cfg2: u32
 #[doc = " @retval ::NRF_ERROR_INTERNAL If a new session could not be opened due to an internal error."]
 #[doc = " @retval ::NRF_SUCCESS Otherwise."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_radio_session_open(p_radio_signal_callback: nrf_radio_signal_callback_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 72",
         inout("r0") to_asm(p_radio_signal_callback) => ret,
         lateout("r1") _,
@@ -12969,6 +13626,10 @@ pub unsafe fn sd_radio_session_open(p_radio_signal_callback: nrf_radio_signal_ca
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(72);
+    }
     ret
 }
 
@@ -12983,8 +13644,10 @@ pub unsafe fn sd_radio_session_open(p_radio_signal_callback: nrf_radio_signal_ca
 #[doc = " @retval ::NRF_ERROR_BUSY If session is currently being closed."]
 #[doc = " @retval ::NRF_SUCCESS Otherwise."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_radio_session_close() -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 73",
         lateout("r0") ret,
         lateout("r1") _,
@@ -12992,6 +13655,10 @@ pub unsafe fn sd_radio_session_close() -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(73);
+    }
     ret
 }
 
@@ -13030,8 +13697,10 @@ pub unsafe fn sd_radio_session_close() -> u32 {
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM If the parameters of p_request are not valid."]
 #[doc = " @retval ::NRF_SUCCESS Otherwise."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_radio_request(p_request: *const nrf_radio_request_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 74",
         inout("r0") to_asm(p_request) => ret,
         lateout("r1") _,
@@ -13039,6 +13708,10 @@ pub unsafe fn sd_radio_request(p_request: *const nrf_radio_request_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(74);
+    }
     ret
 }
 
@@ -13061,8 +13734,10 @@ pub unsafe fn sd_radio_request(p_request: *const nrf_radio_request_t) -> u32 {
 #[doc = " @retval ::NRF_SUCCESS Value successfully written to register."]
 #[doc = ""]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_protected_register_write(p_register: *mut u32, value: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 43",
         inout("r0") to_asm(p_register) => ret,
         inout("r1") to_asm(value) => _,
@@ -13070,6 +13745,10 @@ pub unsafe fn sd_protected_register_write(p_register: *mut u32, value: u32) -> u
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(43);
+    }
     ret
 }
 
@@ -13509,8 +14188,10 @@ fn bindgen_test_layout_sd_mbr_command_t() {
 #[doc = " @retval ::NRF_ERROR_NO_MEM No MBR parameter page provided"]
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM if an invalid command is given."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_mbr_command(param: *mut sd_mbr_command_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 24",
         inout("r0") to_asm(param) => ret,
         lateout("r1") _,
@@ -13518,6 +14199,10 @@ pub unsafe fn sd_mbr_command(param: *mut sd_mbr_command_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(24);
+    }
     ret
 }
 
@@ -13673,11 +14358,13 @@ pub type nrf_fault_handler_t = ::core::option::Option<unsafe extern "C" fn(id: u
 #[doc = " @retval ::NRF_ERROR_SDM_LFCLK_SOURCE_UNKNOWN Unknown low frequency clock source selected."]
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM Invalid clock source configuration supplied in p_clock_lf_cfg."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_softdevice_enable(
     p_clock_lf_cfg: *const nrf_clock_lf_cfg_t,
     fault_handler: nrf_fault_handler_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 16",
         inout("r0") to_asm(p_clock_lf_cfg) => ret,
         inout("r1") to_asm(fault_handler) => _,
@@ -13685,6 +14372,10 @@ pub unsafe fn sd_softdevice_enable(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(16);
+    }
     ret
 }
 
@@ -13701,8 +14392,10 @@ pub unsafe fn sd_softdevice_enable(
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_softdevice_disable() -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 17",
         lateout("r0") ret,
         lateout("r1") _,
@@ -13710,6 +14403,10 @@ pub unsafe fn sd_softdevice_disable() -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(17);
+    }
     ret
 }
 
@@ -13719,8 +14416,10 @@ pub unsafe fn sd_softdevice_disable() -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_softdevice_is_enabled(p_softdevice_enabled: *mut u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 18",
         inout("r0") to_asm(p_softdevice_enabled) => ret,
         lateout("r1") _,
@@ -13728,6 +14427,10 @@ pub unsafe fn sd_softdevice_is_enabled(p_softdevice_enabled: *mut u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(18);
+    }
     ret
 }
 
@@ -13739,8 +14442,10 @@ pub unsafe fn sd_softdevice_is_enabled(p_softdevice_enabled: *mut u8) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_softdevice_vector_table_base_set(address: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 19",
         inout("r0") to_asm(address) => ret,
         lateout("r1") _,
@@ -13748,5 +14453,9 @@ pub unsafe fn sd_softdevice_vector_table_base_set(address: u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(19);
+    }
     ret
 }
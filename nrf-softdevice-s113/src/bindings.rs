@@ -132,6 +132,13 @@ impl<X, Y, Z, R> ToAsm for unsafe extern "C" fn(X, Y, Z) -> R {
     }
 }
 
+// SoftDevice calls can't be made off-target, but the bindings still build there so that
+// code using them can be unit tested on the host.
+#[cfg(not(target_arch = "arm"))]
+fn svc_unavailable(num: u32) -> u32 {
+    panic!("SoftDevice call svc {} is only available on ARM targets", num)
+}
+
 /* automatically generated by rust-bindgen 0.55.1 */
 
 #[repr(C)]
@@ -4920,8 +4927,10 @@ fn bindgen_test_layout_ble_gap_conn_event_trigger_t() {
 #[doc = " @retval ::NRF_ERROR_BUSY The stack is busy, process pending events and retry."]
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE The identity address cannot be changed while advertising."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_addr_set(p_addr: *const ble_gap_addr_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 108",
         inout("r0") to_asm(p_addr) => ret,
         lateout("r1") _,
@@ -4929,6 +4938,10 @@ pub unsafe fn sd_ble_gap_addr_set(p_addr: *const ble_gap_addr_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(108);
+    }
     ret
 }
 
@@ -4942,8 +4955,10 @@ pub unsafe fn sd_ble_gap_addr_set(p_addr: *const ble_gap_addr_t) -> u32 {
 #[doc = " @retval ::NRF_SUCCESS Address successfully retrieved."]
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR Invalid or NULL pointer supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_addr_get(p_addr: *mut ble_gap_addr_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 109",
         inout("r0") to_asm(p_addr) => ret,
         lateout("r1") _,
@@ -4951,6 +4966,10 @@ pub unsafe fn sd_ble_gap_addr_get(p_addr: *mut ble_gap_addr_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(109);
+    }
     ret
 }
 
@@ -4970,8 +4989,10 @@ pub unsafe fn sd_ble_gap_addr_get(p_addr: *mut ble_gap_addr_t) -> u32 {
 #[doc = " @retval ::BLE_ERROR_INVALID_ADV_HANDLE The provided advertising handle was not found."]
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE      The advertising set is currently not advertising."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_adv_addr_get(adv_handle: u8, p_addr: *mut ble_gap_addr_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 147",
         inout("r0") to_asm(adv_handle) => ret,
         inout("r1") to_asm(p_addr) => _,
@@ -4979,6 +5000,10 @@ pub unsafe fn sd_ble_gap_adv_addr_get(adv_handle: u8, p_addr: *mut ble_gap_addr_
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(147);
+    }
     ret
 }
 
@@ -5000,8 +5025,10 @@ pub unsafe fn sd_ble_gap_adv_addr_get(adv_handle: u8, p_addr: *mut ble_gap_addr_
 #[doc = " @retval ::NRF_ERROR_DATA_SIZE The given whitelist size is invalid (zero or too large); this can only return when"]
 #[doc = "                               pp_wl_addrs is not NULL."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_whitelist_set(pp_wl_addrs: *const *const ble_gap_addr_t, len: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 110",
         inout("r0") to_asm(pp_wl_addrs) => ret,
         inout("r1") to_asm(len) => _,
@@ -5009,6 +5036,10 @@ pub unsafe fn sd_ble_gap_whitelist_set(pp_wl_addrs: *const *const ble_gap_addr_t
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(110);
+    }
     ret
 }
 
@@ -5037,12 +5068,14 @@ pub unsafe fn sd_ble_gap_whitelist_set(pp_wl_addrs: *const *const ble_gap_addr_t
 #[doc = " @retval ::NRF_ERROR_DATA_SIZE The given device identity list size invalid (zero or too large); this can"]
 #[doc = "                               only return when pp_id_keys is not NULL."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_device_identities_set(
     pp_id_keys: *const *const ble_gap_id_key_t,
     pp_local_irks: *const *const ble_gap_irk_t,
     len: u8,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 111",
         inout("r0") to_asm(pp_id_keys) => ret,
         inout("r1") to_asm(pp_local_irks) => _,
@@ -5050,6 +5083,10 @@ pub unsafe fn sd_ble_gap_device_identities_set(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(111);
+    }
     ret
 }
 
@@ -5072,8 +5109,10 @@ pub unsafe fn sd_ble_gap_device_identities_set(
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM Out of range parameters are provided."]
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE Privacy settings cannot be changed while advertising."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_privacy_set(p_privacy_params: *const ble_gap_privacy_params_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 112",
         inout("r0") to_asm(p_privacy_params) => ret,
         lateout("r1") _,
@@ -5081,6 +5120,10 @@ pub unsafe fn sd_ble_gap_privacy_set(p_privacy_params: *const ble_gap_privacy_pa
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(112);
+    }
     ret
 }
 
@@ -5095,8 +5138,10 @@ pub unsafe fn sd_ble_gap_privacy_set(p_privacy_params: *const ble_gap_privacy_pa
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR The pointer given for returning the privacy settings may be NULL or invalid."]
 #[doc = "                                  Otherwise, the p_device_irk pointer in privacy parameter is an invalid pointer."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_privacy_get(p_privacy_params: *mut ble_gap_privacy_params_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 113",
         inout("r0") to_asm(p_privacy_params) => ret,
         lateout("r1") _,
@@ -5104,6 +5149,10 @@ pub unsafe fn sd_ble_gap_privacy_get(p_privacy_params: *mut ble_gap_privacy_para
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(113);
+    }
     ret
 }
 
@@ -5150,12 +5199,14 @@ pub unsafe fn sd_ble_gap_privacy_get(p_privacy_params: *mut ble_gap_privacy_para
 #[doc = "                                                     existing advertising handle instead."]
 #[doc = " @retval ::BLE_ERROR_GAP_UUID_LIST_MISMATCH Invalid UUID list supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_adv_set_configure(
     p_adv_handle: *mut u8,
     p_adv_data: *const ble_gap_adv_data_t,
     p_adv_params: *const ble_gap_adv_params_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 114",
         inout("r0") to_asm(p_adv_handle) => ret,
         inout("r1") to_asm(p_adv_data) => _,
@@ -5163,6 +5214,10 @@ pub unsafe fn sd_ble_gap_adv_set_configure(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(114);
+    }
     ret
 }
 
@@ -5209,8 +5264,10 @@ pub unsafe fn sd_ble_gap_adv_set_configure(
 #[doc = "                                          the selected advertising phys. Use @ref sd_ble_cfg_set to increase the event length."]
 #[doc = " @retval ::NRF_ERROR_NOT_SUPPORTED Unsupported PHYs supplied to the call."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_adv_start(adv_handle: u8, conn_cfg_tag: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 115",
         inout("r0") to_asm(adv_handle) => ret,
         inout("r1") to_asm(conn_cfg_tag) => _,
@@ -5218,6 +5275,10 @@ pub unsafe fn sd_ble_gap_adv_start(adv_handle: u8, conn_cfg_tag: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(115);
+    }
     ret
 }
 
@@ -5233,8 +5294,10 @@ pub unsafe fn sd_ble_gap_adv_start(adv_handle: u8, conn_cfg_tag: u8) -> u32 {
 #[doc = " @retval ::BLE_ERROR_INVALID_ADV_HANDLE Invalid advertising handle."]
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE The advertising handle is not advertising."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_adv_stop(adv_handle: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 116",
         inout("r0") to_asm(adv_handle) => ret,
         lateout("r1") _,
@@ -5242,6 +5305,10 @@ pub unsafe fn sd_ble_gap_adv_stop(adv_handle: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(116);
+    }
     ret
 }
 
@@ -5271,8 +5338,10 @@ pub unsafe fn sd_ble_gap_adv_stop(adv_handle: u8) -> u32 {
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[doc = " @retval ::NRF_ERROR_NO_MEM Not enough memory to complete operation."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_conn_param_update(conn_handle: u16, p_conn_params: *const ble_gap_conn_params_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 117",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_conn_params) => _,
@@ -5280,6 +5349,10 @@ pub unsafe fn sd_ble_gap_conn_param_update(conn_handle: u16, p_conn_params: *con
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(117);
+    }
     ret
 }
 
@@ -5304,8 +5377,10 @@ pub unsafe fn sd_ble_gap_conn_param_update(conn_handle: u16, p_conn_params: *con
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE Disconnection in progress or link has not been established."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_disconnect(conn_handle: u16, hci_status_code: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 118",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(hci_status_code) => _,
@@ -5313,6 +5388,10 @@ pub unsafe fn sd_ble_gap_disconnect(conn_handle: u16, hci_status_code: u8) -> u3
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(118);
+    }
     ret
 }
 
@@ -5338,8 +5417,10 @@ pub unsafe fn sd_ble_gap_disconnect(conn_handle: u16, hci_status_code: u8) -> u3
 #[doc = " @retval ::BLE_ERROR_INVALID_ADV_HANDLE Advertising handle not found."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_tx_power_set(role: u8, handle: u16, tx_power: i8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 119",
         inout("r0") to_asm(role) => ret,
         inout("r1") to_asm(handle) => _,
@@ -5347,6 +5428,10 @@ pub unsafe fn sd_ble_gap_tx_power_set(role: u8, handle: u16, tx_power: i8) -> u3
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(119);
+    }
     ret
 }
 
@@ -5357,8 +5442,10 @@ pub unsafe fn sd_ble_gap_tx_power_set(role: u8, handle: u16, tx_power: i8) -> u3
 #[doc = " @retval ::NRF_SUCCESS  Appearance value set successfully."]
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM Invalid parameter(s) supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_appearance_set(appearance: u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 120",
         inout("r0") to_asm(appearance) => ret,
         lateout("r1") _,
@@ -5366,6 +5453,10 @@ pub unsafe fn sd_ble_gap_appearance_set(appearance: u16) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(120);
+    }
     ret
 }
 
@@ -5376,8 +5467,10 @@ pub unsafe fn sd_ble_gap_appearance_set(appearance: u16) -> u32 {
 #[doc = " @retval ::NRF_SUCCESS Appearance value retrieved successfully."]
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR Invalid pointer supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_appearance_get(p_appearance: *mut u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 121",
         inout("r0") to_asm(p_appearance) => ret,
         lateout("r1") _,
@@ -5385,6 +5478,10 @@ pub unsafe fn sd_ble_gap_appearance_get(p_appearance: *mut u16) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(121);
+    }
     ret
 }
 
@@ -5398,8 +5495,10 @@ pub unsafe fn sd_ble_gap_appearance_get(p_appearance: *mut u16) -> u32 {
 #[doc = " @retval ::NRF_ERROR_NOT_SUPPORTED The characteristic is not included in the Attribute Table,"]
 #[doc = "see @ref ble_gap_cfg_ppcp_incl_cfg_t."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_ppcp_set(p_conn_params: *const ble_gap_conn_params_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 122",
         inout("r0") to_asm(p_conn_params) => ret,
         lateout("r1") _,
@@ -5407,6 +5506,10 @@ pub unsafe fn sd_ble_gap_ppcp_set(p_conn_params: *const ble_gap_conn_params_t) -
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(122);
+    }
     ret
 }
 
@@ -5419,8 +5522,10 @@ pub unsafe fn sd_ble_gap_ppcp_set(p_conn_params: *const ble_gap_conn_params_t) -
 #[doc = " @retval ::NRF_ERROR_NOT_SUPPORTED The characteristic is not included in the Attribute Table,"]
 #[doc = "see @ref ble_gap_cfg_ppcp_incl_cfg_t."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_ppcp_get(p_conn_params: *mut ble_gap_conn_params_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 123",
         inout("r0") to_asm(p_conn_params) => ret,
         lateout("r1") _,
@@ -5428,6 +5533,10 @@ pub unsafe fn sd_ble_gap_ppcp_get(p_conn_params: *mut ble_gap_conn_params_t) ->
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(123);
+    }
     ret
 }
 
@@ -5446,12 +5555,14 @@ pub unsafe fn sd_ble_gap_ppcp_get(p_conn_params: *mut ble_gap_conn_params_t) ->
 #[doc = " @retval ::NRF_ERROR_DATA_SIZE Invalid data size(s) supplied."]
 #[doc = " @retval ::NRF_ERROR_FORBIDDEN Device name is not writable."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_device_name_set(
     p_write_perm: *const ble_gap_conn_sec_mode_t,
     p_dev_name: *const u8,
     len: u16,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 124",
         inout("r0") to_asm(p_write_perm) => ret,
         inout("r1") to_asm(p_dev_name) => _,
@@ -5459,6 +5570,10 @@ pub unsafe fn sd_ble_gap_device_name_set(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(124);
+    }
     ret
 }
 
@@ -5476,8 +5591,10 @@ pub unsafe fn sd_ble_gap_device_name_set(
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR Invalid pointer supplied."]
 #[doc = " @retval ::NRF_ERROR_DATA_SIZE Invalid data size(s) supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_device_name_get(p_dev_name: *mut u8, p_len: *mut u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 125",
         inout("r0") to_asm(p_dev_name) => ret,
         inout("r1") to_asm(p_len) => _,
@@ -5485,6 +5602,10 @@ pub unsafe fn sd_ble_gap_device_name_get(p_dev_name: *mut u8, p_len: *mut u16) -
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(125);
+    }
     ret
 }
 
@@ -5522,8 +5643,10 @@ pub unsafe fn sd_ble_gap_device_name_get(p_dev_name: *mut u8, p_len: *mut u16) -
 #[doc = " @retval ::NRF_ERROR_NO_MEM The maximum number of authentication procedures that can run in parallel for the given role is reached."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_authenticate(conn_handle: u16, p_sec_params: *const ble_gap_sec_params_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 126",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_sec_params) => _,
@@ -5531,6 +5654,10 @@ pub unsafe fn sd_ble_gap_authenticate(conn_handle: u16, p_sec_params: *const ble
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(126);
+    }
     ret
 }
 
@@ -5580,6 +5707,7 @@ pub unsafe fn sd_ble_gap_authenticate(conn_handle: u16, p_sec_params: *const ble
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[doc = " @retval ::NRF_ERROR_NOT_SUPPORTED Setting of sign or link fields in @ref ble_gap_sec_kdist_t not supported."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_sec_params_reply(
     conn_handle: u16,
     sec_status: u8,
@@ -5587,6 +5715,7 @@ pub unsafe fn sd_ble_gap_sec_params_reply(
     p_sec_keyset: *const ble_gap_sec_keyset_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 127",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(sec_status) => _,
@@ -5594,6 +5723,10 @@ pub unsafe fn sd_ble_gap_sec_params_reply(
         inout("r3") to_asm(p_sec_keyset) => _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(127);
+    }
     ret
 }
 
@@ -5625,8 +5758,10 @@ pub unsafe fn sd_ble_gap_sec_params_reply(
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE Authentication key has not been requested."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_auth_key_reply(conn_handle: u16, key_type: u8, p_key: *const u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 128",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(key_type) => _,
@@ -5634,6 +5769,10 @@ pub unsafe fn sd_ble_gap_auth_key_reply(conn_handle: u16, key_type: u8, p_key: *
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(128);
+    }
     ret
 }
 
@@ -5665,8 +5804,10 @@ pub unsafe fn sd_ble_gap_auth_key_reply(conn_handle: u16, key_type: u8, p_key: *
 #[doc = "                                   - The application has not pulled a @ref BLE_GAP_EVT_LESC_DHKEY_REQUEST event."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_lesc_dhkey_reply(conn_handle: u16, p_dhkey: *const ble_gap_lesc_dhkey_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 129",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_dhkey) => _,
@@ -5674,6 +5815,10 @@ pub unsafe fn sd_ble_gap_lesc_dhkey_reply(conn_handle: u16, p_dhkey: *const ble_
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(129);
+    }
     ret
 }
 
@@ -5695,8 +5840,10 @@ pub unsafe fn sd_ble_gap_lesc_dhkey_reply(conn_handle: u16, p_dhkey: *const ble_
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[doc = " @retval ::NRF_ERROR_BUSY The BLE stack is busy. Retry at later time."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_keypress_notify(conn_handle: u16, kp_not: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 130",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(kp_not) => _,
@@ -5704,6 +5851,10 @@ pub unsafe fn sd_ble_gap_keypress_notify(conn_handle: u16, kp_not: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(130);
+    }
     ret
 }
 
@@ -5724,12 +5875,14 @@ pub unsafe fn sd_ble_gap_keypress_notify(conn_handle: u16, kp_not: u8) -> u32 {
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR Invalid pointer supplied."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_lesc_oob_data_get(
     conn_handle: u16,
     p_pk_own: *const ble_gap_lesc_p256_pk_t,
     p_oobd_own: *mut ble_gap_lesc_oob_data_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 131",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_pk_own) => _,
@@ -5737,6 +5890,10 @@ pub unsafe fn sd_ble_gap_lesc_oob_data_get(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(131);
+    }
     ret
 }
 
@@ -5768,12 +5925,14 @@ pub unsafe fn sd_ble_gap_lesc_oob_data_get(
 #[doc = "                                   - Have not actually exchanged passkeys."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_lesc_oob_data_set(
     conn_handle: u16,
     p_oobd_own: *const ble_gap_lesc_oob_data_t,
     p_oobd_peer: *const ble_gap_lesc_oob_data_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 132",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_oobd_own) => _,
@@ -5781,6 +5940,10 @@ pub unsafe fn sd_ble_gap_lesc_oob_data_set(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(132);
+    }
     ret
 }
 
@@ -5807,6 +5970,7 @@ pub unsafe fn sd_ble_gap_lesc_oob_data_set(
 #[doc = "                                   - Encryption information provided by the app without being requested. See @ref ble_gap_evt_sec_info_request_t::enc_info."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_sec_info_reply(
     conn_handle: u16,
     p_enc_info: *const ble_gap_enc_info_t,
@@ -5814,6 +5978,7 @@ pub unsafe fn sd_ble_gap_sec_info_reply(
     p_sign_info: *const ble_gap_sign_info_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 134",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_enc_info) => _,
@@ -5821,6 +5986,10 @@ pub unsafe fn sd_ble_gap_sec_info_reply(
         inout("r3") to_asm(p_sign_info) => _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(134);
+    }
     ret
 }
 
@@ -5833,8 +6002,10 @@ pub unsafe fn sd_ble_gap_sec_info_reply(
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR Invalid pointer supplied."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_conn_sec_get(conn_handle: u16, p_conn_sec: *mut ble_gap_conn_sec_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 135",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_conn_sec) => _,
@@ -5842,6 +6013,10 @@ pub unsafe fn sd_ble_gap_conn_sec_get(conn_handle: u16, p_conn_sec: *mut ble_gap
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(135);
+    }
     ret
 }
 
@@ -5868,8 +6043,10 @@ pub unsafe fn sd_ble_gap_conn_sec_get(conn_handle: u16, p_conn_sec: *mut ble_gap
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE       RSSI reporting is already ongoing."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_rssi_start(conn_handle: u16, threshold_dbm: u8, skip_count: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 136",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(threshold_dbm) => _,
@@ -5877,6 +6054,10 @@ pub unsafe fn sd_ble_gap_rssi_start(conn_handle: u16, threshold_dbm: u8, skip_co
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(136);
+    }
     ret
 }
 
@@ -5896,8 +6077,10 @@ pub unsafe fn sd_ble_gap_rssi_start(conn_handle: u16, threshold_dbm: u8, skip_co
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE       RSSI reporting is not ongoing."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_rssi_stop(conn_handle: u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 137",
         inout("r0") to_asm(conn_handle) => ret,
         lateout("r1") _,
@@ -5905,6 +6088,10 @@ pub unsafe fn sd_ble_gap_rssi_stop(conn_handle: u16) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(137);
+    }
     ret
 }
 
@@ -5926,8 +6113,10 @@ pub unsafe fn sd_ble_gap_rssi_stop(conn_handle: u16) -> u32 {
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE       RSSI reporting is not ongoing."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_rssi_get(conn_handle: u16, p_rssi: *mut i8, p_ch_index: *mut u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 142",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_rssi) => _,
@@ -5935,6 +6124,10 @@ pub unsafe fn sd_ble_gap_rssi_get(conn_handle: u16, p_rssi: *mut i8, p_ch_index:
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(142);
+    }
     ret
 }
 
@@ -5986,8 +6179,10 @@ pub unsafe fn sd_ble_gap_rssi_get(conn_handle: u16, p_rssi: *mut i8, p_ch_index:
 #[doc = " @retval ::NRF_ERROR_BUSY Procedure is already in progress or not allowed at this time. Process pending events and wait for the pending procedure to complete and retry."]
 #[doc = ""]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_phy_update(conn_handle: u16, p_gap_phys: *const ble_gap_phys_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 143",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_gap_phys) => _,
@@ -5995,6 +6190,10 @@ pub unsafe fn sd_ble_gap_phy_update(conn_handle: u16, p_gap_phys: *const ble_gap
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(143);
+    }
     ret
 }
 
@@ -6030,12 +6229,14 @@ pub unsafe fn sd_ble_gap_phy_update(conn_handle: u16, p_gap_phys: *const ble_gap
 #[doc = " @retval ::NRF_ERROR_BUSY Peer has already initiated a Data Length Update Procedure. Process the"]
 #[doc = "                          pending @ref BLE_GAP_EVT_DATA_LENGTH_UPDATE_REQUEST event to respond."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_data_length_update(
     conn_handle: u16,
     p_dl_params: *const ble_gap_data_length_params_t,
     p_dl_limitation: *mut ble_gap_data_length_limitation_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 144",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_dl_params) => _,
@@ -6043,6 +6244,10 @@ pub unsafe fn sd_ble_gap_data_length_update(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(144);
+    }
     ret
 }
 
@@ -6067,8 +6272,10 @@ pub unsafe fn sd_ble_gap_data_length_update(
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle parameter supplied."]
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR        Invalid pointer supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_next_conn_evt_counter_get(conn_handle: u16, p_counter: *mut u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 148",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_counter) => _,
@@ -6076,6 +6283,10 @@ pub unsafe fn sd_ble_gap_next_conn_evt_counter_get(conn_handle: u16, p_counter:
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(148);
+    }
     ret
 }
 
@@ -6098,11 +6309,13 @@ pub unsafe fn sd_ble_gap_next_conn_evt_counter_get(conn_handle: u16, p_counter:
 #[doc = "                                           Use @ref sd_ble_gap_next_conn_evt_counter_get to find a new value"]
 #[doc = "to be used as ble_gap_conn_event_trigger_t::conn_evt_counter_start."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_conn_evt_trigger_start(
     conn_handle: u16,
     p_params: *const ble_gap_conn_event_trigger_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 149",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_params) => _,
@@ -6110,6 +6323,10 @@ pub unsafe fn sd_ble_gap_conn_evt_trigger_start(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(149);
+    }
     ret
 }
 
@@ -6121,8 +6338,10 @@ pub unsafe fn sd_ble_gap_conn_evt_trigger_start(
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE       Trying to stop connection event triggering when it is not enabled."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_conn_evt_trigger_stop(conn_handle: u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 150",
         inout("r0") to_asm(conn_handle) => ret,
         lateout("r1") _,
@@ -6130,6 +6349,10 @@ pub unsafe fn sd_ble_gap_conn_evt_trigger_stop(conn_handle: u16) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(150);
+    }
     ret
 }
 
@@ -6909,12 +7132,14 @@ fn bindgen_test_layout_ble_l2cap_evt_t() {
 #[doc = " @retval ::NRF_ERROR_RESOURCES            The limit has been reached for available L2CAP channels,"]
 #[doc = "                                          see @ref ble_l2cap_conn_cfg_t::ch_count."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_l2cap_ch_setup(
     conn_handle: u16,
     p_local_cid: *mut u16,
     p_params: *const ble_l2cap_ch_setup_params_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 184",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_local_cid) => _,
@@ -6922,6 +7147,10 @@ pub unsafe fn sd_ble_l2cap_ch_setup(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(184);
+    }
     ret
 }
 
@@ -6946,8 +7175,10 @@ pub unsafe fn sd_ble_l2cap_ch_setup(
 #[doc = "                                          in progress for the L2CAP channel)."]
 #[doc = " @retval ::NRF_ERROR_NOT_FOUND            CID not found."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_l2cap_ch_release(conn_handle: u16, local_cid: u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 185",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(local_cid) => _,
@@ -6955,6 +7186,10 @@ pub unsafe fn sd_ble_l2cap_ch_release(conn_handle: u16, local_cid: u16) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(185);
+    }
     ret
 }
 
@@ -6990,8 +7225,10 @@ pub unsafe fn sd_ble_l2cap_ch_release(conn_handle: u16, local_cid: u16) -> u32 {
 #[doc = " @retval ::NRF_ERROR_RESOURCES            Too many SDU data buffers supplied. Wait for a"]
 #[doc = "                                          @ref BLE_L2CAP_EVT_CH_RX event and retry."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_l2cap_ch_rx(conn_handle: u16, local_cid: u16, p_sdu_buf: *const ble_data_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 186",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(local_cid) => _,
@@ -6999,6 +7236,10 @@ pub unsafe fn sd_ble_l2cap_ch_rx(conn_handle: u16, local_cid: u16, p_sdu_buf: *c
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(186);
+    }
     ret
 }
 
@@ -7045,8 +7286,10 @@ pub unsafe fn sd_ble_l2cap_ch_rx(conn_handle: u16, local_cid: u16, p_sdu_buf: *c
 #[doc = " @retval ::NRF_ERROR_RESOURCES            Too many SDUs queued for transmission. Wait for a"]
 #[doc = "                                          @ref BLE_L2CAP_EVT_CH_TX event and retry."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_l2cap_ch_tx(conn_handle: u16, local_cid: u16, p_sdu_buf: *const ble_data_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 187",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(local_cid) => _,
@@ -7054,6 +7297,10 @@ pub unsafe fn sd_ble_l2cap_ch_tx(conn_handle: u16, local_cid: u16, p_sdu_buf: *c
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(187);
+    }
     ret
 }
 
@@ -7099,8 +7346,10 @@ pub unsafe fn sd_ble_l2cap_ch_tx(conn_handle: u16, local_cid: u16, p_sdu_buf: *c
 #[doc = "                                          in progress for an L2CAP channel)."]
 #[doc = " @retval ::NRF_ERROR_NOT_FOUND            CID not found."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_l2cap_ch_flow_control(conn_handle: u16, local_cid: u16, credits: u16, p_credits: *mut u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 188",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(local_cid) => _,
@@ -7108,6 +7357,10 @@ pub unsafe fn sd_ble_l2cap_ch_flow_control(conn_handle: u16, local_cid: u16, cre
         inout("r3") to_asm(p_credits) => _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(188);
+    }
     ret
 }
 
@@ -8857,12 +9110,14 @@ fn bindgen_test_layout_ble_gattc_evt_t() {
 #[doc = " @retval ::NRF_ERROR_BUSY Client procedure already in progress."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_primary_services_discover(
     conn_handle: u16,
     start_handle: u16,
     p_srvc_uuid: *const ble_uuid_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 155",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(start_handle) => _,
@@ -8870,6 +9125,10 @@ pub unsafe fn sd_ble_gattc_primary_services_discover(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(155);
+    }
     ret
 }
 
@@ -8897,11 +9156,13 @@ pub unsafe fn sd_ble_gattc_primary_services_discover(
 #[doc = " @retval ::NRF_ERROR_BUSY Client procedure already in progress."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_relationships_discover(
     conn_handle: u16,
     p_handle_range: *const ble_gattc_handle_range_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 156",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_handle_range) => _,
@@ -8909,6 +9170,10 @@ pub unsafe fn sd_ble_gattc_relationships_discover(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(156);
+    }
     ret
 }
 
@@ -8938,11 +9203,13 @@ pub unsafe fn sd_ble_gattc_relationships_discover(
 #[doc = " @retval ::NRF_ERROR_BUSY Client procedure already in progress."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_characteristics_discover(
     conn_handle: u16,
     p_handle_range: *const ble_gattc_handle_range_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 157",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_handle_range) => _,
@@ -8950,6 +9217,10 @@ pub unsafe fn sd_ble_gattc_characteristics_discover(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(157);
+    }
     ret
 }
 
@@ -8976,11 +9247,13 @@ pub unsafe fn sd_ble_gattc_characteristics_discover(
 #[doc = " @retval ::NRF_ERROR_BUSY Client procedure already in progress."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_descriptors_discover(
     conn_handle: u16,
     p_handle_range: *const ble_gattc_handle_range_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 158",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_handle_range) => _,
@@ -8988,6 +9261,10 @@ pub unsafe fn sd_ble_gattc_descriptors_discover(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(158);
+    }
     ret
 }
 
@@ -9015,12 +9292,14 @@ pub unsafe fn sd_ble_gattc_descriptors_discover(
 #[doc = " @retval ::NRF_ERROR_BUSY Client procedure already in progress."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_char_value_by_uuid_read(
     conn_handle: u16,
     p_uuid: *const ble_uuid_t,
     p_handle_range: *const ble_gattc_handle_range_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 160",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_uuid) => _,
@@ -9028,6 +9307,10 @@ pub unsafe fn sd_ble_gattc_char_value_by_uuid_read(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(160);
+    }
     ret
 }
 
@@ -9055,8 +9338,10 @@ pub unsafe fn sd_ble_gattc_char_value_by_uuid_read(
 #[doc = " @retval ::NRF_ERROR_BUSY Client procedure already in progress."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_read(conn_handle: u16, handle: u16, offset: u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 161",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(handle) => _,
@@ -9064,6 +9349,10 @@ pub unsafe fn sd_ble_gattc_read(conn_handle: u16, handle: u16, offset: u16) -> u
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(161);
+    }
     ret
 }
 
@@ -9090,8 +9379,10 @@ pub unsafe fn sd_ble_gattc_read(conn_handle: u16, handle: u16, offset: u16) -> u
 #[doc = " @retval ::NRF_ERROR_BUSY Client procedure already in progress."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_char_values_read(conn_handle: u16, p_handles: *const u16, handle_count: u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 162",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_handles) => _,
@@ -9099,6 +9390,10 @@ pub unsafe fn sd_ble_gattc_char_values_read(conn_handle: u16, p_handles: *const
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(162);
+    }
     ret
 }
 
@@ -9146,8 +9441,10 @@ pub unsafe fn sd_ble_gattc_char_values_read(conn_handle: u16, p_handles: *const
 #[doc = "                               Wait for a @ref BLE_GATTC_EVT_WRITE_CMD_TX_COMPLETE event and retry."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_write(conn_handle: u16, p_write_params: *const ble_gattc_write_params_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 163",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_write_params) => _,
@@ -9155,6 +9452,10 @@ pub unsafe fn sd_ble_gattc_write(conn_handle: u16, p_write_params: *const ble_ga
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(163);
+    }
     ret
 }
 
@@ -9173,8 +9474,10 @@ pub unsafe fn sd_ble_gattc_write(conn_handle: u16, p_write_params: *const ble_ga
 #[doc = " @retval ::BLE_ERROR_INVALID_ATTR_HANDLE Invalid attribute handle."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_hv_confirm(conn_handle: u16, handle: u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 164",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(handle) => _,
@@ -9182,6 +9485,10 @@ pub unsafe fn sd_ble_gattc_hv_confirm(conn_handle: u16, handle: u16) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(164);
+    }
     ret
 }
 
@@ -9201,11 +9508,13 @@ pub unsafe fn sd_ble_gattc_hv_confirm(conn_handle: u16, handle: u16) -> u32 {
 #[doc = " @retval ::NRF_ERROR_BUSY Client procedure already in progress."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_attr_info_discover(
     conn_handle: u16,
     p_handle_range: *const ble_gattc_handle_range_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 159",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_handle_range) => _,
@@ -9213,6 +9522,10 @@ pub unsafe fn sd_ble_gattc_attr_info_discover(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(159);
+    }
     ret
 }
 
@@ -9247,8 +9560,10 @@ pub unsafe fn sd_ble_gattc_attr_info_discover(
 #[doc = " @retval ::NRF_ERROR_BUSY Client procedure already in progress."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_exchange_mtu_request(conn_handle: u16, client_rx_mtu: u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 165",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(client_rx_mtu) => _,
@@ -9256,6 +9571,10 @@ pub unsafe fn sd_ble_gattc_exchange_mtu_request(conn_handle: u16, client_rx_mtu:
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(165);
+    }
     ret
 }
 
@@ -10815,8 +11134,10 @@ fn bindgen_test_layout_ble_gatts_evt_t() {
 #[doc = " @retval ::NRF_ERROR_FORBIDDEN Forbidden value supplied, certain UUIDs are reserved for the stack."]
 #[doc = " @retval ::NRF_ERROR_NO_MEM Not enough memory to complete operation."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_service_add(type_: u8, p_uuid: *const ble_uuid_t, p_handle: *mut u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 168",
         inout("r0") to_asm(type_) => ret,
         inout("r1") to_asm(p_uuid) => _,
@@ -10824,6 +11145,10 @@ pub unsafe fn sd_ble_gatts_service_add(type_: u8, p_uuid: *const ble_uuid_t, p_h
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(168);
+    }
     ret
 }
 
@@ -10850,8 +11175,10 @@ pub unsafe fn sd_ble_gatts_service_add(type_: u8, p_uuid: *const ble_uuid_t, p_h
 #[doc = " @retval ::NRF_ERROR_NO_MEM Not enough memory to complete operation."]
 #[doc = " @retval ::NRF_ERROR_NOT_FOUND Attribute not found."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_include_add(service_handle: u16, inc_srvc_handle: u16, p_include_handle: *mut u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 169",
         inout("r0") to_asm(service_handle) => ret,
         inout("r1") to_asm(inc_srvc_handle) => _,
@@ -10859,6 +11186,10 @@ pub unsafe fn sd_ble_gatts_include_add(service_handle: u16, inc_srvc_handle: u16
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(169);
+    }
     ret
 }
 
@@ -10888,6 +11219,7 @@ pub unsafe fn sd_ble_gatts_include_add(service_handle: u16, inc_srvc_handle: u16
 #[doc = " @retval ::NRF_ERROR_NO_MEM Not enough memory to complete operation."]
 #[doc = " @retval ::NRF_ERROR_DATA_SIZE Invalid data size(s) supplied, attribute lengths are restricted by @ref BLE_GATTS_ATTR_LENS_MAX."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_characteristic_add(
     service_handle: u16,
     p_char_md: *const ble_gatts_char_md_t,
@@ -10895,6 +11227,7 @@ pub unsafe fn sd_ble_gatts_characteristic_add(
     p_handles: *mut ble_gatts_char_handles_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 170",
         inout("r0") to_asm(service_handle) => ret,
         inout("r1") to_asm(p_char_md) => _,
@@ -10902,6 +11235,10 @@ pub unsafe fn sd_ble_gatts_characteristic_add(
         inout("r3") to_asm(p_handles) => _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(170);
+    }
     ret
 }
 
@@ -10925,12 +11262,14 @@ pub unsafe fn sd_ble_gatts_characteristic_add(
 #[doc = " @retval ::NRF_ERROR_NO_MEM Not enough memory to complete operation."]
 #[doc = " @retval ::NRF_ERROR_DATA_SIZE Invalid data size(s) supplied, attribute lengths are restricted by @ref BLE_GATTS_ATTR_LENS_MAX."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_descriptor_add(
     char_handle: u16,
     p_attr: *const ble_gatts_attr_t,
     p_handle: *mut u16,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 171",
         inout("r0") to_asm(char_handle) => ret,
         inout("r1") to_asm(p_attr) => _,
@@ -10938,6 +11277,10 @@ pub unsafe fn sd_ble_gatts_descriptor_add(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(171);
+    }
     ret
 }
 
@@ -10962,8 +11305,10 @@ pub unsafe fn sd_ble_gatts_descriptor_add(
 #[doc = " @retval ::NRF_ERROR_DATA_SIZE Invalid data size(s) supplied, attribute lengths are restricted by @ref BLE_GATTS_ATTR_LENS_MAX."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied on a system attribute."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_value_set(conn_handle: u16, handle: u16, p_value: *mut ble_gatts_value_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 172",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(handle) => _,
@@ -10971,6 +11316,10 @@ pub unsafe fn sd_ble_gatts_value_set(conn_handle: u16, handle: u16, p_value: *mu
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(172);
+    }
     ret
 }
 
@@ -10996,8 +11345,10 @@ pub unsafe fn sd_ble_gatts_value_set(conn_handle: u16, handle: u16, p_value: *mu
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied on a system attribute."]
 #[doc = " @retval ::BLE_ERROR_GATTS_SYS_ATTR_MISSING System attributes missing, use @ref sd_ble_gatts_sys_attr_set to set them to a known value."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_value_get(conn_handle: u16, handle: u16, p_value: *mut ble_gatts_value_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 173",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(handle) => _,
@@ -11005,6 +11356,10 @@ pub unsafe fn sd_ble_gatts_value_get(conn_handle: u16, handle: u16, p_value: *mu
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(173);
+    }
     ret
 }
 
@@ -11071,8 +11426,10 @@ pub unsafe fn sd_ble_gatts_value_get(conn_handle: u16, handle: u16, p_value: *mu
 #[doc = "                               Wait for a @ref BLE_GATTS_EVT_HVN_TX_COMPLETE event and retry."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_hvx(conn_handle: u16, p_hvx_params: *const ble_gatts_hvx_params_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 174",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_hvx_params) => _,
@@ -11080,6 +11437,10 @@ pub unsafe fn sd_ble_gatts_hvx(conn_handle: u16, p_hvx_params: *const ble_gatts_
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(174);
+    }
     ret
 }
 
@@ -11117,8 +11478,10 @@ pub unsafe fn sd_ble_gatts_hvx(conn_handle: u16, p_hvx_params: *const ble_gatts_
 #[doc = " @retval ::BLE_ERROR_GATTS_SYS_ATTR_MISSING System attributes missing, use @ref sd_ble_gatts_sys_attr_set to set them to a known value."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_service_changed(conn_handle: u16, start_handle: u16, end_handle: u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 175",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(start_handle) => _,
@@ -11126,6 +11489,10 @@ pub unsafe fn sd_ble_gatts_service_changed(conn_handle: u16, start_handle: u16,
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(175);
+    }
     ret
 }
 
@@ -11160,11 +11527,13 @@ pub unsafe fn sd_ble_gatts_service_changed(conn_handle: u16, start_handle: u16,
 #[doc = "                                         or invalid data to be written provided by the application."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_rw_authorize_reply(
     conn_handle: u16,
     p_rw_authorize_reply_params: *const ble_gatts_rw_authorize_reply_params_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 176",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_rw_authorize_reply_params) => _,
@@ -11172,6 +11541,10 @@ pub unsafe fn sd_ble_gatts_rw_authorize_reply(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(176);
+    }
     ret
 }
 
@@ -11216,8 +11589,10 @@ pub unsafe fn sd_ble_gatts_rw_authorize_reply(
 #[doc = " @retval ::NRF_ERROR_INVALID_DATA Invalid data supplied, the data should be exactly the same as retrieved with @ref sd_ble_gatts_sys_attr_get."]
 #[doc = " @retval ::NRF_ERROR_NO_MEM Not enough memory to complete operation."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_sys_attr_set(conn_handle: u16, p_sys_attr_data: *const u8, len: u16, flags: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 177",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_sys_attr_data) => _,
@@ -11225,6 +11600,10 @@ pub unsafe fn sd_ble_gatts_sys_attr_set(conn_handle: u16, p_sys_attr_data: *cons
         inout("r3") to_asm(flags) => _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(177);
+    }
     ret
 }
 
@@ -11258,6 +11637,7 @@ pub unsafe fn sd_ble_gatts_sys_attr_set(conn_handle: u16, p_sys_attr_data: *cons
 #[doc = " @retval ::NRF_ERROR_DATA_SIZE The system attribute information did not fit into the provided buffer."]
 #[doc = " @retval ::NRF_ERROR_NOT_FOUND No system attributes found."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_sys_attr_get(
     conn_handle: u16,
     p_sys_attr_data: *mut u8,
@@ -11265,6 +11645,7 @@ pub unsafe fn sd_ble_gatts_sys_attr_get(
     flags: u32,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 178",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_sys_attr_data) => _,
@@ -11272,6 +11653,10 @@ pub unsafe fn sd_ble_gatts_sys_attr_get(
         inout("r3") to_asm(flags) => _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(178);
+    }
     ret
 }
 
@@ -11282,8 +11667,10 @@ pub unsafe fn sd_ble_gatts_sys_attr_get(
 #[doc = " @retval ::NRF_SUCCESS Successfully retrieved the handle."]
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR Invalid pointer supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_initial_user_handle_get(p_handle: *mut u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 179",
         inout("r0") to_asm(p_handle) => ret,
         lateout("r1") _,
@@ -11291,6 +11678,10 @@ pub unsafe fn sd_ble_gatts_initial_user_handle_get(p_handle: *mut u16) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(179);
+    }
     ret
 }
 
@@ -11305,8 +11696,10 @@ pub unsafe fn sd_ble_gatts_initial_user_handle_get(p_handle: *mut u16) -> u32 {
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM Invalid parameters supplied. Returned when both @c p_uuid and @c p_md are NULL."]
 #[doc = " @retval ::NRF_ERROR_NOT_FOUND Attribute was not found."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_attr_get(handle: u16, p_uuid: *mut ble_uuid_t, p_md: *mut ble_gatts_attr_md_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 180",
         inout("r0") to_asm(handle) => ret,
         inout("r1") to_asm(p_uuid) => _,
@@ -11314,6 +11707,10 @@ pub unsafe fn sd_ble_gatts_attr_get(handle: u16, p_uuid: *mut ble_uuid_t, p_md:
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(180);
+    }
     ret
 }
 
@@ -11345,8 +11742,10 @@ pub unsafe fn sd_ble_gatts_attr_get(handle: u16, p_uuid: *mut ble_uuid_t, p_md:
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM Invalid Server RX MTU size supplied."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_exchange_mtu_reply(conn_handle: u16, server_rx_mtu: u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 181",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(server_rx_mtu) => _,
@@ -11354,6 +11753,10 @@ pub unsafe fn sd_ble_gatts_exchange_mtu_reply(conn_handle: u16, server_rx_mtu: u
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(181);
+    }
     ret
 }
 
@@ -12444,8 +12847,10 @@ fn bindgen_test_layout_ble_cfg_t() {
 #[doc = "                                      is currently not supported."]
 #[doc = " @retval ::NRF_ERROR_RESOURCES      The total number of L2CAP Channels configured using @ref sd_ble_cfg_set is too large."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_enable(p_app_ram_base: *mut u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 96",
         inout("r0") to_asm(p_app_ram_base) => ret,
         lateout("r1") _,
@@ -12453,6 +12858,10 @@ pub unsafe fn sd_ble_enable(p_app_ram_base: *mut u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(96);
+    }
     ret
 }
 
@@ -12490,8 +12899,10 @@ pub unsafe fn sd_ble_enable(p_app_ram_base: *mut u32) -> u32 {
 #[doc = " @retval ::NRF_ERROR_NO_MEM         The amount of memory assigned to the SoftDevice by app_ram_base is not"]
 #[doc = "                                    large enough to fit this configuration's memory requirement."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_cfg_set(cfg_id: u32, p_cfg: *const ble_cfg_t, app_ram_base: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 105",
         inout("r0") to_asm(cfg_id) => ret,
         inout("r1") to_asm(p_cfg) => _,
@@ -12499,6 +12910,10 @@ pub unsafe fn sd_ble_cfg_set(cfg_id: u32, p_cfg: *const ble_cfg_t, app_ram_base:
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(105);
+    }
     ret
 }
 
@@ -12538,8 +12953,10 @@ pub unsafe fn sd_ble_cfg_set(cfg_id: u32, p_cfg: *const ble_cfg_t, app_ram_base:
 #[doc = " @retval ::NRF_ERROR_NOT_FOUND No events ready to be pulled."]
 #[doc = " @retval ::NRF_ERROR_DATA_SIZE Event ready but could not fit into the supplied buffer."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_evt_get(p_dest: *mut u8, p_len: *mut u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 97",
         inout("r0") to_asm(p_dest) => ret,
         inout("r1") to_asm(p_len) => _,
@@ -12547,6 +12964,10 @@ pub unsafe fn sd_ble_evt_get(p_dest: *mut u8, p_len: *mut u16) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(97);
+    }
     ret
 }
 
@@ -12576,8 +12997,10 @@ pub unsafe fn sd_ble_evt_get(p_dest: *mut u8, p_len: *mut u16) -> u32 {
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR If p_vs_uuid or p_uuid_type is NULL or invalid."]
 #[doc = " @retval ::NRF_ERROR_NO_MEM If there are no more free slots for VS UUIDs."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_uuid_vs_add(p_vs_uuid: *const ble_uuid128_t, p_uuid_type: *mut u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 98",
         inout("r0") to_asm(p_vs_uuid) => ret,
         inout("r1") to_asm(p_uuid_type) => _,
@@ -12585,6 +13008,10 @@ pub unsafe fn sd_ble_uuid_vs_add(p_vs_uuid: *const ble_uuid128_t, p_uuid_type: *
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(98);
+    }
     ret
 }
 
@@ -12606,8 +13033,10 @@ pub unsafe fn sd_ble_uuid_vs_add(p_vs_uuid: *const ble_uuid128_t, p_uuid_type: *
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM If p_uuid_type points to a non-valid UUID type."]
 #[doc = " @retval ::NRF_ERROR_FORBIDDEN If the Vendor Specific base UUID is in use by the ATT Server."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_uuid_vs_remove(p_uuid_type: *mut u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 106",
         inout("r0") to_asm(p_uuid_type) => ret,
         lateout("r1") _,
@@ -12615,6 +13044,10 @@ pub unsafe fn sd_ble_uuid_vs_remove(p_uuid_type: *mut u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(106);
+    }
     ret
 }
 
@@ -12636,8 +13069,10 @@ pub unsafe fn sd_ble_uuid_vs_remove(p_uuid_type: *mut u8) -> u32 {
 #[doc = " @retval ::NRF_ERROR_INVALID_LENGTH Invalid UUID length."]
 #[doc = " @retval ::NRF_ERROR_NOT_FOUND For a 128-bit UUID, no match in the populated table of UUIDs."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_uuid_decode(uuid_le_len: u8, p_uuid_le: *const u8, p_uuid: *mut ble_uuid_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 99",
         inout("r0") to_asm(uuid_le_len) => ret,
         inout("r1") to_asm(p_uuid_le) => _,
@@ -12645,6 +13080,10 @@ pub unsafe fn sd_ble_uuid_decode(uuid_le_len: u8, p_uuid_le: *const u8, p_uuid:
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(99);
+    }
     ret
 }
 
@@ -12660,8 +13099,10 @@ pub unsafe fn sd_ble_uuid_decode(uuid_le_len: u8, p_uuid_le: *const u8, p_uuid:
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR Invalid pointer supplied."]
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM Invalid UUID type."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_uuid_encode(p_uuid: *const ble_uuid_t, p_uuid_le_len: *mut u8, p_uuid_le: *mut u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 100",
         inout("r0") to_asm(p_uuid) => ret,
         inout("r1") to_asm(p_uuid_le_len) => _,
@@ -12669,6 +13110,10 @@ pub unsafe fn sd_ble_uuid_encode(p_uuid: *const ble_uuid_t, p_uuid_le_len: *mut
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(100);
+    }
     ret
 }
 
@@ -12682,8 +13127,10 @@ pub unsafe fn sd_ble_uuid_encode(p_uuid: *const ble_uuid_t, p_uuid_le_len: *mut
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR Invalid pointer supplied."]
 #[doc = " @retval ::NRF_ERROR_BUSY The BLE stack is busy (typically doing a locally-initiated disconnection procedure)."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_version_get(p_version: *mut ble_version_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 101",
         inout("r0") to_asm(p_version) => ret,
         lateout("r1") _,
@@ -12691,6 +13138,10 @@ pub unsafe fn sd_ble_version_get(p_version: *mut ble_version_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(101);
+    }
     ret
 }
 
@@ -12717,8 +13168,10 @@ pub unsafe fn sd_ble_version_get(p_version: *mut ble_version_t) -> u32 {
 #[doc = " @retval ::NRF_ERROR_INVALID_LENGTH Invalid user memory block length supplied."]
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE Invalid Connection state or no user memory request pending."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_user_mem_reply(conn_handle: u16, p_block: *const ble_user_mem_block_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 102",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_block) => _,
@@ -12726,6 +13179,10 @@ pub unsafe fn sd_ble_user_mem_reply(conn_handle: u16, p_block: *const ble_user_m
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(102);
+    }
     ret
 }
 
@@ -12743,8 +13200,10 @@ pub unsafe fn sd_ble_user_mem_reply(conn_handle: u16, p_block: *const ble_user_m
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE Unable to set the parameter at this time."]
 #[doc = " @retval ::NRF_ERROR_BUSY The BLE stack is busy or the previous procedure has not completed."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_opt_set(opt_id: u32, p_opt: *const ble_opt_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 103",
         inout("r0") to_asm(opt_id) => ret,
         inout("r1") to_asm(p_opt) => _,
@@ -12752,6 +13211,10 @@ pub unsafe fn sd_ble_opt_set(opt_id: u32, p_opt: *const ble_opt_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(103);
+    }
     ret
 }
 
@@ -12771,8 +13234,10 @@ pub unsafe fn sd_ble_opt_set(opt_id: u32, p_opt: *const ble_opt_t) -> u32 {
 #[doc = " @retval ::NRF_ERROR_NOT_SUPPORTED This option is not supported."]
 #[doc = ""]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_opt_get(opt_id: u32, p_opt: *mut ble_opt_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 104",
         inout("r0") to_asm(opt_id) => ret,
         inout("r1") to_asm(p_opt) => _,
@@ -12780,6 +13245,10 @@ pub unsafe fn sd_ble_opt_get(opt_id: u32, p_opt: *mut ble_opt_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(104);
+    }
     ret
 }
 
@@ -13516,8 +13985,10 @@ fn bindgen_test_layout_nrf_ecb_hal_data_block_t() {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_mutex_new(p_mutex: *mut nrf_mutex_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 44",
         inout("r0") to_asm(p_mutex) => ret,
         lateout("r1") _,
@@ -13525,6 +13996,10 @@ pub unsafe fn sd_mutex_new(p_mutex: *mut nrf_mutex_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(44);
+    }
     ret
 }
 
@@ -13535,8 +14010,10 @@ pub unsafe fn sd_mutex_new(p_mutex: *mut nrf_mutex_t) -> u32 {
 #[doc = " @retval ::NRF_SUCCESS The mutex was successfully acquired."]
 #[doc = " @retval ::NRF_ERROR_SOC_MUTEX_ALREADY_TAKEN The mutex could not be acquired."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_mutex_acquire(p_mutex: *mut nrf_mutex_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 45",
         inout("r0") to_asm(p_mutex) => ret,
         lateout("r1") _,
@@ -13544,6 +14021,10 @@ pub unsafe fn sd_mutex_acquire(p_mutex: *mut nrf_mutex_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(45);
+    }
     ret
 }
 
@@ -13553,8 +14034,10 @@ pub unsafe fn sd_mutex_acquire(p_mutex: *mut nrf_mutex_t) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_mutex_release(p_mutex: *mut nrf_mutex_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 46",
         inout("r0") to_asm(p_mutex) => ret,
         lateout("r1") _,
@@ -13562,6 +14045,10 @@ pub unsafe fn sd_mutex_release(p_mutex: *mut nrf_mutex_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(46);
+    }
     ret
 }
 
@@ -13571,8 +14058,10 @@ pub unsafe fn sd_mutex_release(p_mutex: *mut nrf_mutex_t) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_rand_application_pool_capacity_get(p_pool_capacity: *mut u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 47",
         inout("r0") to_asm(p_pool_capacity) => ret,
         lateout("r1") _,
@@ -13580,6 +14069,10 @@ pub unsafe fn sd_rand_application_pool_capacity_get(p_pool_capacity: *mut u8) ->
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(47);
+    }
     ret
 }
 
@@ -13589,8 +14082,10 @@ pub unsafe fn sd_rand_application_pool_capacity_get(p_pool_capacity: *mut u8) ->
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_rand_application_bytes_available_get(p_bytes_available: *mut u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 48",
         inout("r0") to_asm(p_bytes_available) => ret,
         lateout("r1") _,
@@ -13598,6 +14093,10 @@ pub unsafe fn sd_rand_application_bytes_available_get(p_bytes_available: *mut u8
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(48);
+    }
     ret
 }
 
@@ -13609,8 +14108,10 @@ pub unsafe fn sd_rand_application_bytes_available_get(p_bytes_available: *mut u8
 #[doc = " @retval ::NRF_SUCCESS The requested bytes were written to p_buff."]
 #[doc = " @retval ::NRF_ERROR_SOC_RAND_NOT_ENOUGH_VALUES No bytes were written to the buffer, because there were not enough bytes available."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_rand_application_vector_get(p_buff: *mut u8, length: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 49",
         inout("r0") to_asm(p_buff) => ret,
         inout("r1") to_asm(length) => _,
@@ -13618,6 +14119,10 @@ pub unsafe fn sd_rand_application_vector_get(p_buff: *mut u8, length: u8) -> u32
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(49);
+    }
     ret
 }
 
@@ -13627,8 +14132,10 @@ pub unsafe fn sd_rand_application_vector_get(p_buff: *mut u8, length: u8) -> u32
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_reset_reason_get(p_reset_reason: *mut u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 52",
         inout("r0") to_asm(p_reset_reason) => ret,
         lateout("r1") _,
@@ -13636,6 +14143,10 @@ pub unsafe fn sd_power_reset_reason_get(p_reset_reason: *mut u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(52);
+    }
     ret
 }
 
@@ -13645,8 +14156,10 @@ pub unsafe fn sd_power_reset_reason_get(p_reset_reason: *mut u32) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_reset_reason_clr(reset_reason_clr_msk: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 53",
         inout("r0") to_asm(reset_reason_clr_msk) => ret,
         lateout("r1") _,
@@ -13654,6 +14167,10 @@ pub unsafe fn sd_power_reset_reason_clr(reset_reason_clr_msk: u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(53);
+    }
     ret
 }
 
@@ -13664,8 +14181,10 @@ pub unsafe fn sd_power_reset_reason_clr(reset_reason_clr_msk: u32) -> u32 {
 #[doc = " @retval ::NRF_SUCCESS The power mode was set."]
 #[doc = " @retval ::NRF_ERROR_SOC_POWER_MODE_UNKNOWN The power mode was unknown."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_mode_set(power_mode: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 50",
         inout("r0") to_asm(power_mode) => ret,
         lateout("r1") _,
@@ -13673,6 +14192,10 @@ pub unsafe fn sd_power_mode_set(power_mode: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(50);
+    }
     ret
 }
 
@@ -13680,8 +14203,10 @@ pub unsafe fn sd_power_mode_set(power_mode: u8) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_ERROR_SOC_POWER_OFF_SHOULD_NOT_RETURN"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_system_off() -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 51",
         lateout("r0") ret,
         lateout("r1") _,
@@ -13689,6 +14214,10 @@ pub unsafe fn sd_power_system_off() -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(51);
+    }
     ret
 }
 
@@ -13701,8 +14230,10 @@ pub unsafe fn sd_power_system_off() -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_pof_enable(pof_enable: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 54",
         inout("r0") to_asm(pof_enable) => ret,
         lateout("r1") _,
@@ -13710,6 +14241,10 @@ pub unsafe fn sd_power_pof_enable(pof_enable: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(54);
+    }
     ret
 }
 
@@ -13724,8 +14259,10 @@ pub unsafe fn sd_power_pof_enable(pof_enable: u8) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_usbpwrrdy_enable(usbpwrrdy_enable: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 77",
         inout("r0") to_asm(usbpwrrdy_enable) => ret,
         lateout("r1") _,
@@ -13733,6 +14270,10 @@ pub unsafe fn sd_power_usbpwrrdy_enable(usbpwrrdy_enable: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(77);
+    }
     ret
 }
 
@@ -13747,8 +14288,10 @@ pub unsafe fn sd_power_usbpwrrdy_enable(usbpwrrdy_enable: u8) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_usbdetected_enable(usbdetected_enable: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 78",
         inout("r0") to_asm(usbdetected_enable) => ret,
         lateout("r1") _,
@@ -13756,6 +14299,10 @@ pub unsafe fn sd_power_usbdetected_enable(usbdetected_enable: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(78);
+    }
     ret
 }
 
@@ -13770,8 +14317,10 @@ pub unsafe fn sd_power_usbdetected_enable(usbdetected_enable: u8) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_usbremoved_enable(usbremoved_enable: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 79",
         inout("r0") to_asm(usbremoved_enable) => ret,
         lateout("r1") _,
@@ -13779,6 +14328,10 @@ pub unsafe fn sd_power_usbremoved_enable(usbremoved_enable: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(79);
+    }
     ret
 }
 
@@ -13790,8 +14343,10 @@ pub unsafe fn sd_power_usbremoved_enable(usbremoved_enable: u8) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_usbregstatus_get(usbregstatus: *mut u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 80",
         inout("r0") to_asm(usbregstatus) => ret,
         lateout("r1") _,
@@ -13799,6 +14354,10 @@ pub unsafe fn sd_power_usbregstatus_get(usbregstatus: *mut u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(80);
+    }
     ret
 }
 
@@ -13810,8 +14369,10 @@ pub unsafe fn sd_power_usbregstatus_get(usbregstatus: *mut u32) -> u32 {
 #[doc = " @retval ::NRF_SUCCESS The power failure threshold was set."]
 #[doc = " @retval ::NRF_ERROR_SOC_POWER_POF_THRESHOLD_UNKNOWN The power failure threshold is unknown."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_pof_threshold_set(threshold: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 55",
         inout("r0") to_asm(threshold) => ret,
         lateout("r1") _,
@@ -13819,6 +14380,10 @@ pub unsafe fn sd_power_pof_threshold_set(threshold: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(55);
+    }
     ret
 }
 
@@ -13829,8 +14394,10 @@ pub unsafe fn sd_power_pof_threshold_set(threshold: u8) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_ram_power_set(index: u8, ram_powerset: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 57",
         inout("r0") to_asm(index) => ret,
         inout("r1") to_asm(ram_powerset) => _,
@@ -13838,6 +14405,10 @@ pub unsafe fn sd_power_ram_power_set(index: u8, ram_powerset: u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(57);
+    }
     ret
 }
 
@@ -13848,8 +14419,10 @@ pub unsafe fn sd_power_ram_power_set(index: u8, ram_powerset: u32) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_ram_power_clr(index: u8, ram_powerclr: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 58",
         inout("r0") to_asm(index) => ret,
         inout("r1") to_asm(ram_powerclr) => _,
@@ -13857,6 +14430,10 @@ pub unsafe fn sd_power_ram_power_clr(index: u8, ram_powerclr: u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(58);
+    }
     ret
 }
 
@@ -13867,8 +14444,10 @@ pub unsafe fn sd_power_ram_power_clr(index: u8, ram_powerclr: u32) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_ram_power_get(index: u8, p_ram_power: *mut u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 59",
         inout("r0") to_asm(index) => ret,
         inout("r1") to_asm(p_ram_power) => _,
@@ -13876,6 +14455,10 @@ pub unsafe fn sd_power_ram_power_get(index: u8, p_ram_power: *mut u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(59);
+    }
     ret
 }
 
@@ -13886,8 +14469,10 @@ pub unsafe fn sd_power_ram_power_get(index: u8, p_ram_power: *mut u32) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_gpregret_set(gpregret_id: u32, gpregret_msk: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 60",
         inout("r0") to_asm(gpregret_id) => ret,
         inout("r1") to_asm(gpregret_msk) => _,
@@ -13895,6 +14480,10 @@ pub unsafe fn sd_power_gpregret_set(gpregret_id: u32, gpregret_msk: u32) -> u32
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(60);
+    }
     ret
 }
 
@@ -13905,8 +14494,10 @@ pub unsafe fn sd_power_gpregret_set(gpregret_id: u32, gpregret_msk: u32) -> u32
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_gpregret_clr(gpregret_id: u32, gpregret_msk: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 61",
         inout("r0") to_asm(gpregret_id) => ret,
         inout("r1") to_asm(gpregret_msk) => _,
@@ -13914,6 +14505,10 @@ pub unsafe fn sd_power_gpregret_clr(gpregret_id: u32, gpregret_msk: u32) -> u32
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(61);
+    }
     ret
 }
 
@@ -13924,8 +14519,10 @@ pub unsafe fn sd_power_gpregret_clr(gpregret_id: u32, gpregret_msk: u32) -> u32
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_gpregret_get(gpregret_id: u32, p_gpregret: *mut u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 62",
         inout("r0") to_asm(gpregret_id) => ret,
         inout("r1") to_asm(p_gpregret) => _,
@@ -13933,6 +14530,10 @@ pub unsafe fn sd_power_gpregret_get(gpregret_id: u32, p_gpregret: *mut u32) -> u
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(62);
+    }
     ret
 }
 
@@ -13943,8 +14544,10 @@ pub unsafe fn sd_power_gpregret_get(gpregret_id: u32, p_gpregret: *mut u32) -> u
 #[doc = " @retval ::NRF_SUCCESS"]
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM The DCDC mode is invalid."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_dcdc_mode_set(dcdc_mode: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 63",
         inout("r0") to_asm(dcdc_mode) => ret,
         lateout("r1") _,
@@ -13952,6 +14555,10 @@ pub unsafe fn sd_power_dcdc_mode_set(dcdc_mode: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(63);
+    }
     ret
 }
 
@@ -13965,8 +14572,10 @@ pub unsafe fn sd_power_dcdc_mode_set(dcdc_mode: u8) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_clock_hfclk_request() -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 66",
         lateout("r0") ret,
         lateout("r1") _,
@@ -13974,6 +14583,10 @@ pub unsafe fn sd_clock_hfclk_request() -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(66);
+    }
     ret
 }
 
@@ -13986,8 +14599,10 @@ pub unsafe fn sd_clock_hfclk_request() -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_clock_hfclk_release() -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 67",
         lateout("r0") ret,
         lateout("r1") _,
@@ -13995,6 +14610,10 @@ pub unsafe fn sd_clock_hfclk_release() -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(67);
+    }
     ret
 }
 
@@ -14007,8 +14626,10 @@ pub unsafe fn sd_clock_hfclk_release() -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_clock_hfclk_is_running(p_is_running: *mut u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 68",
         inout("r0") to_asm(p_is_running) => ret,
         lateout("r1") _,
@@ -14016,6 +14637,10 @@ pub unsafe fn sd_clock_hfclk_is_running(p_is_running: *mut u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(68);
+    }
     ret
 }
 
@@ -14045,8 +14670,10 @@ pub unsafe fn sd_clock_hfclk_is_running(p_is_running: *mut u32) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_app_evt_wait() -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 65",
         lateout("r0") ret,
         lateout("r1") _,
@@ -14054,6 +14681,10 @@ pub unsafe fn sd_app_evt_wait() -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(65);
+    }
     ret
 }
 
@@ -14063,8 +14694,10 @@ pub unsafe fn sd_app_evt_wait() -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ppi_channel_enable_get(p_channel_enable: *mut u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 32",
         inout("r0") to_asm(p_channel_enable) => ret,
         lateout("r1") _,
@@ -14072,6 +14705,10 @@ pub unsafe fn sd_ppi_channel_enable_get(p_channel_enable: *mut u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(32);
+    }
     ret
 }
 
@@ -14081,8 +14718,10 @@ pub unsafe fn sd_ppi_channel_enable_get(p_channel_enable: *mut u32) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ppi_channel_enable_set(channel_enable_set_msk: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 33",
         inout("r0") to_asm(channel_enable_set_msk) => ret,
         lateout("r1") _,
@@ -14090,6 +14729,10 @@ pub unsafe fn sd_ppi_channel_enable_set(channel_enable_set_msk: u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(33);
+    }
     ret
 }
 
@@ -14099,8 +14742,10 @@ pub unsafe fn sd_ppi_channel_enable_set(channel_enable_set_msk: u32) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ppi_channel_enable_clr(channel_enable_clr_msk: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 34",
         inout("r0") to_asm(channel_enable_clr_msk) => ret,
         lateout("r1") _,
@@ -14108,6 +14753,10 @@ pub unsafe fn sd_ppi_channel_enable_clr(channel_enable_clr_msk: u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(34);
+    }
     ret
 }
 
@@ -14120,12 +14769,14 @@ pub unsafe fn sd_ppi_channel_enable_clr(channel_enable_clr_msk: u32) -> u32 {
 #[doc = " @retval ::NRF_ERROR_SOC_PPI_INVALID_CHANNEL The channel number is invalid."]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ppi_channel_assign(
     channel_num: u8,
     evt_endpoint: *const self::c_void,
     task_endpoint: *const self::c_void,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 35",
         inout("r0") to_asm(channel_num) => ret,
         inout("r1") to_asm(evt_endpoint) => _,
@@ -14133,6 +14784,10 @@ pub unsafe fn sd_ppi_channel_assign(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(35);
+    }
     ret
 }
 
@@ -14143,8 +14798,10 @@ pub unsafe fn sd_ppi_channel_assign(
 #[doc = " @retval ::NRF_ERROR_SOC_PPI_INVALID_GROUP The group number is invalid"]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ppi_group_task_enable(group_num: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 36",
         inout("r0") to_asm(group_num) => ret,
         lateout("r1") _,
@@ -14152,6 +14809,10 @@ pub unsafe fn sd_ppi_group_task_enable(group_num: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(36);
+    }
     ret
 }
 
@@ -14162,8 +14823,10 @@ pub unsafe fn sd_ppi_group_task_enable(group_num: u8) -> u32 {
 #[doc = " @retval ::NRF_ERROR_SOC_PPI_INVALID_GROUP The group number is invalid."]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ppi_group_task_disable(group_num: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 37",
         inout("r0") to_asm(group_num) => ret,
         lateout("r1") _,
@@ -14171,6 +14834,10 @@ pub unsafe fn sd_ppi_group_task_disable(group_num: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(37);
+    }
     ret
 }
 
@@ -14182,8 +14849,10 @@ pub unsafe fn sd_ppi_group_task_disable(group_num: u8) -> u32 {
 #[doc = " @retval ::NRF_ERROR_SOC_PPI_INVALID_GROUP The group number is invalid."]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ppi_group_assign(group_num: u8, channel_msk: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 38",
         inout("r0") to_asm(group_num) => ret,
         inout("r1") to_asm(channel_msk) => _,
@@ -14191,6 +14860,10 @@ pub unsafe fn sd_ppi_group_assign(group_num: u8, channel_msk: u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(38);
+    }
     ret
 }
 
@@ -14202,8 +14875,10 @@ pub unsafe fn sd_ppi_group_assign(group_num: u8, channel_msk: u32) -> u32 {
 #[doc = " @retval ::NRF_ERROR_SOC_PPI_INVALID_GROUP The group number is invalid."]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ppi_group_get(group_num: u8, p_channel_msk: *mut u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 39",
         inout("r0") to_asm(group_num) => ret,
         inout("r1") to_asm(p_channel_msk) => _,
@@ -14211,6 +14886,10 @@ pub unsafe fn sd_ppi_group_get(group_num: u8, p_channel_msk: *mut u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(39);
+    }
     ret
 }
 
@@ -14243,8 +14922,10 @@ pub unsafe fn sd_ppi_group_get(group_num: u8, p_channel_msk: *mut u32) -> u32 {
 #[doc = "                                   running activities and retry."]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_radio_notification_cfg_set(type_: u8, distance: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 69",
         inout("r0") to_asm(type_) => ret,
         inout("r1") to_asm(distance) => _,
@@ -14252,6 +14933,10 @@ pub unsafe fn sd_radio_notification_cfg_set(type_: u8, distance: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(69);
+    }
     ret
 }
 
@@ -14269,8 +14954,10 @@ pub unsafe fn sd_radio_notification_cfg_set(type_: u8, distance: u8) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ecb_block_encrypt(p_ecb_data: *mut nrf_ecb_hal_data_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 70",
         inout("r0") to_asm(p_ecb_data) => ret,
         lateout("r1") _,
@@ -14278,6 +14965,10 @@ pub unsafe fn sd_ecb_block_encrypt(p_ecb_data: *mut nrf_ecb_hal_data_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(70);
+    }
     ret
 }
 
@@ -14296,8 +14987,10 @@ pub unsafe fn sd_ecb_block_encrypt(p_ecb_data: *mut nrf_ecb_hal_data_t) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ecb_blocks_encrypt(block_count: u8, p_data_blocks: *mut nrf_ecb_hal_data_block_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 71",
         inout("r0") to_asm(block_count) => ret,
         inout("r1") to_asm(p_data_blocks) => _,
@@ -14305,6 +14998,10 @@ pub unsafe fn sd_ecb_blocks_encrypt(block_count: u8, p_data_blocks: *mut nrf_ecb
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(71);
+    }
     ret
 }
 
@@ -14317,8 +15014,10 @@ pub unsafe fn sd_ecb_blocks_encrypt(block_count: u8, p_data_blocks: *mut nrf_ecb
 #[doc = " @retval ::NRF_SUCCESS An event was pending. The event id is written in the p_evt_id parameter."]
 #[doc = " @retval ::NRF_ERROR_NOT_FOUND No pending events."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_evt_get(p_evt_id: *mut u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 75",
         inout("r0") to_asm(p_evt_id) => ret,
         lateout("r1") _,
@@ -14326,6 +15025,10 @@ pub unsafe fn sd_evt_get(p_evt_id: *mut u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(75);
+    }
     ret
 }
 
@@ -14338,8 +15041,10 @@ pub unsafe fn sd_evt_get(p_evt_id: *mut u32) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS A temperature measurement was done, and the temperature was written to temp"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_temp_get(p_temp: *mut i32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 76",
         inout("r0") to_asm(p_temp) => ret,
         lateout("r1") _,
@@ -14347,6 +15052,10 @@ pub unsafe fn sd_temp_get(p_temp: *mut i32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(76);
+    }
     ret
 }
 
@@ -14385,8 +15094,10 @@ pub unsafe fn sd_temp_get(p_temp: *mut i32) -> u32 {
 #[doc = " @retval ::NRF_ERROR_FORBIDDEN      Tried to write to an address outside the application flash area."]
 #[doc = " @retval ::NRF_SUCCESS              The command was accepted."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_flash_write(p_dst: *mut u32, p_src: *const u32, size: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 41",
         inout("r0") to_asm(p_dst) => ret,
         inout("r1") to_asm(p_src) => _,
@@ -14394,6 +15105,10 @@ pub unsafe fn sd_flash_write(p_dst: *mut u32, p_src: *const u32, size: u32) -> u
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(41);
+    }
     ret
 }
 
@@ -14426,8 +15141,10 @@ pub unsafe fn sd_flash_write(p_dst: *mut u32, p_src: *const u32, size: u32) -> u
 #[doc = " @retval ::NRF_ERROR_FORBIDDEN     Tried to erase a page outside the application flash area."]
 #[doc = " @retval ::NRF_SUCCESS             The command was accepted."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_flash_page_erase(page_number: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 40",
         inout("r0") to_asm(page_number) => ret,
         lateout("r1") _,
@@ -14435,6 +15152,10 @@ pub unsafe fn sd_flash_page_erase(page_number: u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(40);
+    }
     ret
 }
 
@@ -14458,8 +15179,10 @@ pub unsafe fn sd_flash_page_erase(page_number: u32) -> u32 {
 #[doc = " @retval ::NRF_ERROR_NOT_SUPPORTED Non-zero value supplied to one or more of the unsupported parameters."]
 #[doc = " @retval ::NRF_SUCCESS Values successfully written to configuration registers."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_flash_protect(block_cfg0: u32, block_cfg1: u32, block_cfg2: u32, block_cfg3: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 42",
         inout("r0") to_asm(block_cfg0) => ret,
         inout("r1") to_asm(block_cfg1) => _,
@@ -14467,6 +15190,10 @@ pub unsafe fn sd_flash_protect(block_cfg0: u32, block_cfg1: u32, block_cfg2: u32
         inout("r3") to_asm(block_cfg3) => _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(42);
+    }
     ret
 }
 
@@ -14490,8 +15217,10 @@ pub unsafe fn sd_flash_protect(block_cfg0: u32, block_cfg1: u32, block_cfg2: u32
 #[doc = " @retval ::NRF_ERROR_INTERNAL If a new session could not be opened due to an internal error."]
 #[doc = " @retval ::NRF_SUCCESS Otherwise."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_radio_session_open(p_radio_signal_callback: nrf_radio_signal_callback_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 72",
         inout("r0") to_asm(p_radio_signal_callback) => ret,
         lateout("r1") _,
@@ -14499,6 +15228,10 @@ pub unsafe fn sd_radio_session_open(p_radio_signal_callback: nrf_radio_signal_ca
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(72);
+    }
     ret
 }
 
@@ -14513,8 +15246,10 @@ pub unsafe fn sd_radio_session_open(p_radio_signal_callback: nrf_radio_signal_ca
 #[doc = " @retval ::NRF_ERROR_BUSY If session is currently being closed."]
 #[doc = " @retval ::NRF_SUCCESS Otherwise."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_radio_session_close() -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 73",
         lateout("r0") ret,
         lateout("r1") _,
@@ -14522,6 +15257,10 @@ pub unsafe fn sd_radio_session_close() -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(73);
+    }
     ret
 }
 
@@ -14560,8 +15299,10 @@ pub unsafe fn sd_radio_session_close() -> u32 {
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM If the parameters of p_request are not valid."]
 #[doc = " @retval ::NRF_SUCCESS Otherwise."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_radio_request(p_request: *const nrf_radio_request_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 74",
         inout("r0") to_asm(p_request) => ret,
         lateout("r1") _,
@@ -14569,6 +15310,10 @@ pub unsafe fn sd_radio_request(p_request: *const nrf_radio_request_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(74);
+    }
     ret
 }
 
@@ -14591,8 +15336,10 @@ pub unsafe fn sd_radio_request(p_request: *const nrf_radio_request_t) -> u32 {
 #[doc = " @retval ::NRF_SUCCESS Value successfully written to register."]
 #[doc = ""]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_protected_register_write(p_register: *mut u32, value: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 43",
         inout("r0") to_asm(p_register) => ret,
         inout("r1") to_asm(value) => _,
@@ -14600,6 +15347,10 @@ pub unsafe fn sd_protected_register_write(p_register: *mut u32, value: u32) -> u
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(43);
+    }
     ret
 }
 
@@ -15039,8 +15790,10 @@ fn bindgen_test_layout_sd_mbr_command_t() {
 #[doc = " @retval ::NRF_ERROR_NO_MEM No MBR parameter page provided"]
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM if an invalid command is given."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_mbr_command(param: *mut sd_mbr_command_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 24",
         inout("r0") to_asm(param) => ret,
         lateout("r1") _,
@@ -15048,6 +15801,10 @@ pub unsafe fn sd_mbr_command(param: *mut sd_mbr_command_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(24);
+    }
     ret
 }
 
@@ -15203,11 +15960,13 @@ pub type nrf_fault_handler_t = ::core::option::Option<unsafe extern "C" fn(id: u
 #[doc = " @retval ::NRF_ERROR_SDM_LFCLK_SOURCE_UNKNOWN Unknown low frequency clock source selected."]
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM Invalid clock source configuration supplied in p_clock_lf_cfg."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_softdevice_enable(
     p_clock_lf_cfg: *const nrf_clock_lf_cfg_t,
     fault_handler: nrf_fault_handler_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 16",
         inout("r0") to_asm(p_clock_lf_cfg) => ret,
         inout("r1") to_asm(fault_handler) => _,
@@ -15215,6 +15974,10 @@ pub unsafe fn sd_softdevice_enable(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(16);
+    }
     ret
 }
 
@@ -15231,8 +15994,10 @@ pub unsafe fn sd_softdevice_enable(
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_softdevice_disable() -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 17",
         lateout("r0") ret,
         lateout("r1") _,
@@ -15240,6 +16005,10 @@ pub unsafe fn sd_softdevice_disable() -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(17);
+    }
     ret
 }
 
@@ -15249,8 +16018,10 @@ pub unsafe fn sd_softdevice_disable() -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_softdevice_is_enabled(p_softdevice_enabled: *mut u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 18",
         inout("r0") to_asm(p_softdevice_enabled) => ret,
         lateout("r1") _,
@@ -15258,6 +16029,10 @@ pub unsafe fn sd_softdevice_is_enabled(p_softdevice_enabled: *mut u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(18);
+    }
     ret
 }
 
@@ -15269,8 +16044,10 @@ pub unsafe fn sd_softdevice_is_enabled(p_softdevice_enabled: *mut u8) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_softdevice_vector_table_base_set(address: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 19",
         inout("r0") to_asm(address) => ret,
         lateout("r1") _,
@@ -15278,5 +16055,9 @@ pub unsafe fn sd_softdevice_vector_table_base_set(address: u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(19);
+    }
     ret
 }
@@ -132,6 +132,13 @@ impl<X, Y, Z, R> ToAsm for unsafe extern "C" fn(X, Y, Z) -> R {
     }
 }
 
+// SoftDevice calls can't be made off-target, but the bindings still build there so that
+// code using them can be unit tested on the host.
+#[cfg(not(target_arch = "arm"))]
+fn svc_unavailable(num: u32) -> u32 {
+    panic!("SoftDevice call svc {} is only available on ARM targets", num)
+}
+
 /* automatically generated by rust-bindgen 0.55.1 */
 
 #[repr(C)]
@@ -5053,8 +5060,10 @@ fn bindgen_test_layout_ble_gap_qos_params_t() {
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE The identity address cannot be changed while advertising,"]
 #[doc = "                                   scanning or creating a connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_addr_set(p_addr: *const ble_gap_addr_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 108",
         inout("r0") to_asm(p_addr) => ret,
         lateout("r1") _,
@@ -5062,6 +5071,10 @@ pub unsafe fn sd_ble_gap_addr_set(p_addr: *const ble_gap_addr_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(108);
+    }
     ret
 }
 
@@ -5075,8 +5088,10 @@ pub unsafe fn sd_ble_gap_addr_set(p_addr: *const ble_gap_addr_t) -> u32 {
 #[doc = " @retval ::NRF_SUCCESS Address successfully retrieved."]
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR Invalid or NULL pointer supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_addr_get(p_addr: *mut ble_gap_addr_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 109",
         inout("r0") to_asm(p_addr) => ret,
         lateout("r1") _,
@@ -5084,6 +5099,10 @@ pub unsafe fn sd_ble_gap_addr_get(p_addr: *mut ble_gap_addr_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(109);
+    }
     ret
 }
 
@@ -5109,8 +5128,10 @@ pub unsafe fn sd_ble_gap_addr_get(p_addr: *mut ble_gap_addr_t) -> u32 {
 #[doc = " @retval ::NRF_ERROR_DATA_SIZE The given whitelist size is invalid (zero or too large); this can only return when"]
 #[doc = "                               pp_wl_addrs is not NULL."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_whitelist_set(pp_wl_addrs: *const *const ble_gap_addr_t, len: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 111",
         inout("r0") to_asm(pp_wl_addrs) => ret,
         inout("r1") to_asm(len) => _,
@@ -5118,6 +5139,10 @@ pub unsafe fn sd_ble_gap_whitelist_set(pp_wl_addrs: *const *const ble_gap_addr_t
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(111);
+    }
     ret
 }
 
@@ -5146,12 +5171,14 @@ pub unsafe fn sd_ble_gap_whitelist_set(pp_wl_addrs: *const *const ble_gap_addr_t
 #[doc = " @retval ::NRF_ERROR_DATA_SIZE The given device identity list size invalid (zero or too large); this can"]
 #[doc = "                               only return when pp_id_keys is not NULL."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_device_identities_set(
     pp_id_keys: *const *const ble_gap_id_key_t,
     pp_local_irks: *const *const ble_gap_irk_t,
     len: u8,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 112",
         inout("r0") to_asm(pp_id_keys) => ret,
         inout("r1") to_asm(pp_local_irks) => _,
@@ -5159,6 +5186,10 @@ pub unsafe fn sd_ble_gap_device_identities_set(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(112);
+    }
     ret
 }
 
@@ -5185,8 +5216,10 @@ pub unsafe fn sd_ble_gap_device_identities_set(
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE Privacy settings cannot be changed while advertising, scanning"]
 #[doc = "                                   or creating a connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_privacy_set(p_privacy_params: *const ble_gap_privacy_params_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 113",
         inout("r0") to_asm(p_privacy_params) => ret,
         lateout("r1") _,
@@ -5194,6 +5227,10 @@ pub unsafe fn sd_ble_gap_privacy_set(p_privacy_params: *const ble_gap_privacy_pa
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(113);
+    }
     ret
 }
 
@@ -5208,8 +5245,10 @@ pub unsafe fn sd_ble_gap_privacy_set(p_privacy_params: *const ble_gap_privacy_pa
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR The pointer given for returning the privacy settings may be NULL or invalid."]
 #[doc = "                                  Otherwise, the p_device_irk pointer in privacy parameter is an invalid pointer."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_privacy_get(p_privacy_params: *mut ble_gap_privacy_params_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 114",
         inout("r0") to_asm(p_privacy_params) => ret,
         lateout("r1") _,
@@ -5217,6 +5256,10 @@ pub unsafe fn sd_ble_gap_privacy_get(p_privacy_params: *mut ble_gap_privacy_para
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(114);
+    }
     ret
 }
 
@@ -5252,8 +5295,10 @@ pub unsafe fn sd_ble_gap_privacy_get(p_privacy_params: *mut ble_gap_privacy_para
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[doc = " @retval ::NRF_ERROR_NO_MEM Not enough memory to complete operation."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_conn_param_update(conn_handle: u16, p_conn_params: *const ble_gap_conn_params_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 118",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_conn_params) => _,
@@ -5261,6 +5306,10 @@ pub unsafe fn sd_ble_gap_conn_param_update(conn_handle: u16, p_conn_params: *con
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(118);
+    }
     ret
 }
 
@@ -5285,8 +5334,10 @@ pub unsafe fn sd_ble_gap_conn_param_update(conn_handle: u16, p_conn_params: *con
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE Disconnection in progress or link has not been established."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_disconnect(conn_handle: u16, hci_status_code: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 119",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(hci_status_code) => _,
@@ -5294,6 +5345,10 @@ pub unsafe fn sd_ble_gap_disconnect(conn_handle: u16, hci_status_code: u8) -> u3
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(119);
+    }
     ret
 }
 
@@ -5316,8 +5371,10 @@ pub unsafe fn sd_ble_gap_disconnect(conn_handle: u16, hci_status_code: u8) -> u3
 #[doc = " @retval ::BLE_ERROR_INVALID_ADV_HANDLE Advertising handle not found."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_tx_power_set(role: u8, handle: u16, tx_power: i8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 120",
         inout("r0") to_asm(role) => ret,
         inout("r1") to_asm(handle) => _,
@@ -5325,6 +5382,10 @@ pub unsafe fn sd_ble_gap_tx_power_set(role: u8, handle: u16, tx_power: i8) -> u3
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(120);
+    }
     ret
 }
 
@@ -5335,8 +5396,10 @@ pub unsafe fn sd_ble_gap_tx_power_set(role: u8, handle: u16, tx_power: i8) -> u3
 #[doc = " @retval ::NRF_SUCCESS  Appearance value set successfully."]
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM Invalid parameter(s) supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_appearance_set(appearance: u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 121",
         inout("r0") to_asm(appearance) => ret,
         lateout("r1") _,
@@ -5344,6 +5407,10 @@ pub unsafe fn sd_ble_gap_appearance_set(appearance: u16) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(121);
+    }
     ret
 }
 
@@ -5354,8 +5421,10 @@ pub unsafe fn sd_ble_gap_appearance_set(appearance: u16) -> u32 {
 #[doc = " @retval ::NRF_SUCCESS Appearance value retrieved successfully."]
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR Invalid pointer supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_appearance_get(p_appearance: *mut u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 122",
         inout("r0") to_asm(p_appearance) => ret,
         lateout("r1") _,
@@ -5363,6 +5432,10 @@ pub unsafe fn sd_ble_gap_appearance_get(p_appearance: *mut u16) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(122);
+    }
     ret
 }
 
@@ -5381,12 +5454,14 @@ pub unsafe fn sd_ble_gap_appearance_get(p_appearance: *mut u16) -> u32 {
 #[doc = " @retval ::NRF_ERROR_DATA_SIZE Invalid data size(s) supplied."]
 #[doc = " @retval ::NRF_ERROR_FORBIDDEN Device name is not writable."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_device_name_set(
     p_write_perm: *const ble_gap_conn_sec_mode_t,
     p_dev_name: *const u8,
     len: u16,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 125",
         inout("r0") to_asm(p_write_perm) => ret,
         inout("r1") to_asm(p_dev_name) => _,
@@ -5394,6 +5469,10 @@ pub unsafe fn sd_ble_gap_device_name_set(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(125);
+    }
     ret
 }
 
@@ -5411,8 +5490,10 @@ pub unsafe fn sd_ble_gap_device_name_set(
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR Invalid pointer supplied."]
 #[doc = " @retval ::NRF_ERROR_DATA_SIZE Invalid data size(s) supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_device_name_get(p_dev_name: *mut u8, p_len: *mut u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 126",
         inout("r0") to_asm(p_dev_name) => ret,
         inout("r1") to_asm(p_len) => _,
@@ -5420,6 +5501,10 @@ pub unsafe fn sd_ble_gap_device_name_get(p_dev_name: *mut u8, p_len: *mut u16) -
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(126);
+    }
     ret
 }
 
@@ -5472,8 +5557,10 @@ pub unsafe fn sd_ble_gap_device_name_get(p_dev_name: *mut u8, p_len: *mut u16) -
 #[doc = "                                   See @ref ble_gap_cfg_car_incl_cfg_t and @ref ble_gap_cfg_role_count_t."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT A SMP timeout has occurred, and further SMP operations on this link is prohibited."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_authenticate(conn_handle: u16, p_sec_params: *const ble_gap_sec_params_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 127",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_sec_params) => _,
@@ -5481,6 +5568,10 @@ pub unsafe fn sd_ble_gap_authenticate(conn_handle: u16, p_sec_params: *const ble
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(127);
+    }
     ret
 }
 
@@ -5529,6 +5620,7 @@ pub unsafe fn sd_ble_gap_authenticate(conn_handle: u16, p_sec_params: *const ble
 #[doc = "                                   the Softdevice is configured to support peripheral roles only."]
 #[doc = "                                   See @ref ble_gap_cfg_car_incl_cfg_t and @ref ble_gap_cfg_role_count_t."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_sec_params_reply(
     conn_handle: u16,
     sec_status: u8,
@@ -5536,6 +5628,7 @@ pub unsafe fn sd_ble_gap_sec_params_reply(
     p_sec_keyset: *const ble_gap_sec_keyset_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 128",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(sec_status) => _,
@@ -5543,6 +5636,10 @@ pub unsafe fn sd_ble_gap_sec_params_reply(
         inout("r3") to_asm(p_sec_keyset) => _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(128);
+    }
     ret
 }
 
@@ -5574,8 +5671,10 @@ pub unsafe fn sd_ble_gap_sec_params_reply(
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE Authentication key has not been requested."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_auth_key_reply(conn_handle: u16, key_type: u8, p_key: *const u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 129",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(key_type) => _,
@@ -5583,6 +5682,10 @@ pub unsafe fn sd_ble_gap_auth_key_reply(conn_handle: u16, key_type: u8, p_key: *
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(129);
+    }
     ret
 }
 
@@ -5614,8 +5717,10 @@ pub unsafe fn sd_ble_gap_auth_key_reply(conn_handle: u16, key_type: u8, p_key: *
 #[doc = "                                   - The application has not pulled a @ref BLE_GAP_EVT_LESC_DHKEY_REQUEST event."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_lesc_dhkey_reply(conn_handle: u16, p_dhkey: *const ble_gap_lesc_dhkey_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 130",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_dhkey) => _,
@@ -5623,6 +5728,10 @@ pub unsafe fn sd_ble_gap_lesc_dhkey_reply(conn_handle: u16, p_dhkey: *const ble_
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(130);
+    }
     ret
 }
 
@@ -5644,8 +5753,10 @@ pub unsafe fn sd_ble_gap_lesc_dhkey_reply(conn_handle: u16, p_dhkey: *const ble_
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[doc = " @retval ::NRF_ERROR_BUSY The BLE stack is busy. Retry at later time."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_keypress_notify(conn_handle: u16, kp_not: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 131",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(kp_not) => _,
@@ -5653,6 +5764,10 @@ pub unsafe fn sd_ble_gap_keypress_notify(conn_handle: u16, kp_not: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(131);
+    }
     ret
 }
 
@@ -5673,12 +5788,14 @@ pub unsafe fn sd_ble_gap_keypress_notify(conn_handle: u16, kp_not: u8) -> u32 {
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR Invalid pointer supplied."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_lesc_oob_data_get(
     conn_handle: u16,
     p_pk_own: *const ble_gap_lesc_p256_pk_t,
     p_oobd_own: *mut ble_gap_lesc_oob_data_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 132",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_pk_own) => _,
@@ -5686,6 +5803,10 @@ pub unsafe fn sd_ble_gap_lesc_oob_data_get(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(132);
+    }
     ret
 }
 
@@ -5718,12 +5839,14 @@ pub unsafe fn sd_ble_gap_lesc_oob_data_get(
 #[doc = "                                   - Have not actually exchanged passkeys."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_lesc_oob_data_set(
     conn_handle: u16,
     p_oobd_own: *const ble_gap_lesc_oob_data_t,
     p_oobd_peer: *const ble_gap_lesc_oob_data_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 133",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_oobd_own) => _,
@@ -5731,6 +5854,10 @@ pub unsafe fn sd_ble_gap_lesc_oob_data_set(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(133);
+    }
     ret
 }
 
@@ -5761,12 +5888,14 @@ pub unsafe fn sd_ble_gap_lesc_oob_data_set(
 #[doc = " @retval ::NRF_ERROR_BUSY Procedure already in progress or not allowed at this time, wait for pending procedures to complete and retry."]
 #[doc = " @retval ::BLE_ERROR_UNSUPPORTED_REMOTE_FEATURE Peer has indicated that it does not support Encryption procedure."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_encrypt(
     conn_handle: u16,
     p_master_id: *const ble_gap_master_id_t,
     p_enc_info: *const ble_gap_enc_info_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 134",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_master_id) => _,
@@ -5774,6 +5903,10 @@ pub unsafe fn sd_ble_gap_encrypt(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(134);
+    }
     ret
 }
 
@@ -5786,8 +5919,10 @@ pub unsafe fn sd_ble_gap_encrypt(
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR Invalid pointer supplied."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_conn_sec_get(conn_handle: u16, p_conn_sec: *mut ble_gap_conn_sec_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 136",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_conn_sec) => _,
@@ -5795,6 +5930,10 @@ pub unsafe fn sd_ble_gap_conn_sec_get(conn_handle: u16, p_conn_sec: *mut ble_gap
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(136);
+    }
     ret
 }
 
@@ -5817,8 +5956,10 @@ pub unsafe fn sd_ble_gap_conn_sec_get(conn_handle: u16, p_conn_sec: *mut ble_gap
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE       RSSI reporting is not ongoing."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_rssi_get(conn_handle: u16, p_rssi: *mut i8, p_ch_index: *mut u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 143",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_rssi) => _,
@@ -5826,6 +5967,10 @@ pub unsafe fn sd_ble_gap_rssi_get(conn_handle: u16, p_rssi: *mut i8, p_ch_index:
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(143);
+    }
     ret
 }
 
@@ -5875,11 +6020,13 @@ pub unsafe fn sd_ble_gap_rssi_get(conn_handle: u16, p_rssi: *mut i8, p_ch_index:
 #[doc = "                               Stop one or more currently active roles (Central, Peripheral or Broadcaster) and try again"]
 #[doc = " @retval ::NRF_ERROR_NOT_SUPPORTED Unsupported PHYs supplied to the call."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_scan_start(
     p_scan_params: *const ble_gap_scan_params_t,
     p_adv_report_buffer: *const ble_data_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 137",
         inout("r0") to_asm(p_scan_params) => ret,
         inout("r1") to_asm(p_adv_report_buffer) => _,
@@ -5887,6 +6034,10 @@ pub unsafe fn sd_ble_gap_scan_start(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(137);
+    }
     ret
 }
 
@@ -5901,8 +6052,10 @@ pub unsafe fn sd_ble_gap_scan_start(
 #[doc = " @retval ::NRF_SUCCESS Successfully stopped scanning procedure."]
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE Not in the scanning state."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_scan_stop() -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 138",
         lateout("r0") ret,
         lateout("r1") _,
@@ -5910,6 +6063,10 @@ pub unsafe fn sd_ble_gap_scan_stop() -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(138);
+    }
     ret
 }
 
@@ -5956,6 +6113,7 @@ pub unsafe fn sd_ble_gap_scan_stop() -> u32 {
 #[doc = "                                   Use @ref sd_ble_cfg_set to increase the event length."]
 #[doc = " @retval ::NRF_ERROR_NOT_SUPPORTED Unsupported PHYs supplied to the call."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_connect(
     p_peer_addr: *const ble_gap_addr_t,
     p_scan_params: *const ble_gap_scan_params_t,
@@ -5963,6 +6121,7 @@ pub unsafe fn sd_ble_gap_connect(
     conn_cfg_tag: u8,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 139",
         inout("r0") to_asm(p_peer_addr) => ret,
         inout("r1") to_asm(p_scan_params) => _,
@@ -5970,6 +6129,10 @@ pub unsafe fn sd_ble_gap_connect(
         inout("r3") to_asm(conn_cfg_tag) => _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(139);
+    }
     ret
 }
 
@@ -5983,8 +6146,10 @@ pub unsafe fn sd_ble_gap_connect(
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE No locally initiated connect procedure started or connection"]
 #[doc = "                                   completed occurred."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_connect_cancel() -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 140",
         lateout("r0") ret,
         lateout("r1") _,
@@ -5992,6 +6157,10 @@ pub unsafe fn sd_ble_gap_connect_cancel() -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(140);
+    }
     ret
 }
 
@@ -6047,8 +6216,10 @@ pub unsafe fn sd_ble_gap_connect_cancel() -> u32 {
 #[doc = " @retval ::NRF_ERROR_BUSY Procedure is already in progress or not allowed at this time. Process pending events and wait for the pending procedure to complete and retry."]
 #[doc = ""]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_phy_update(conn_handle: u16, p_gap_phys: *const ble_gap_phys_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 141",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_gap_phys) => _,
@@ -6056,6 +6227,10 @@ pub unsafe fn sd_ble_gap_phy_update(conn_handle: u16, p_gap_phys: *const ble_gap
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(141);
+    }
     ret
 }
 
@@ -6072,8 +6247,10 @@ pub unsafe fn sd_ble_gap_phy_update(conn_handle: u16, p_gap_phys: *const ble_gap
 #[doc = ""]
 #[doc = " @note For a complete set of return values see @ref ble_gap_qos_params_t."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_qos_start(qos_id: u32, p_qos_params: *const ble_gap_qos_params_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 144",
         inout("r0") to_asm(qos_id) => ret,
         inout("r1") to_asm(p_qos_params) => _,
@@ -6081,6 +6258,10 @@ pub unsafe fn sd_ble_gap_qos_start(qos_id: u32, p_qos_params: *const ble_gap_qos
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(144);
+    }
     ret
 }
 
@@ -6097,8 +6278,10 @@ pub unsafe fn sd_ble_gap_qos_start(qos_id: u32, p_qos_params: *const ble_gap_qos
 #[doc = ""]
 #[doc = " @note For a complete set of return values see @ref ble_gap_qos_params_t."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_qos_stop(qos_id: u32, p_qos_params: *const ble_gap_qos_params_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 145",
         inout("r0") to_asm(qos_id) => ret,
         inout("r1") to_asm(p_qos_params) => _,
@@ -6106,6 +6289,10 @@ pub unsafe fn sd_ble_gap_qos_stop(qos_id: u32, p_qos_params: *const ble_gap_qos_
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(145);
+    }
     ret
 }
 
@@ -6130,8 +6317,10 @@ pub unsafe fn sd_ble_gap_qos_stop(qos_id: u32, p_qos_params: *const ble_gap_qos_
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle parameter supplied."]
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR        Invalid pointer supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_next_conn_evt_counter_get(conn_handle: u16, p_counter: *mut u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 147",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_counter) => _,
@@ -6139,6 +6328,10 @@ pub unsafe fn sd_ble_gap_next_conn_evt_counter_get(conn_handle: u16, p_counter:
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(147);
+    }
     ret
 }
 
@@ -6168,8 +6361,10 @@ pub unsafe fn sd_ble_gap_next_conn_evt_counter_get(conn_handle: u16, p_counter:
 #[doc = "                                         - Trying to start the event triggering for @ref BLE_GAP_LL_ROLE_SCAN or"]
 #[doc = "                                           @ref BLE_GAP_LL_ROLE_INIT when the corresponding LL role is not active."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_evt_trigger_start(role: u8, handle: u16, p_params: *const ble_gap_event_trigger_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 148",
         inout("r0") to_asm(role) => ret,
         inout("r1") to_asm(handle) => _,
@@ -6177,6 +6372,10 @@ pub unsafe fn sd_ble_gap_evt_trigger_start(role: u8, handle: u16, p_params: *con
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(148);
+    }
     ret
 }
 
@@ -6195,8 +6394,10 @@ pub unsafe fn sd_ble_gap_evt_trigger_start(role: u8, handle: u16, p_params: *con
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE       Trying to stop event triggering when it is not started."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_evt_trigger_stop(role: u8, handle: u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 149",
         inout("r0") to_asm(role) => ret,
         inout("r1") to_asm(handle) => _,
@@ -6204,6 +6405,10 @@ pub unsafe fn sd_ble_gap_evt_trigger_stop(role: u8, handle: u16) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(149);
+    }
     ret
 }
 
@@ -7953,12 +8158,14 @@ fn bindgen_test_layout_ble_gattc_evt_t() {
 #[doc = " @retval ::NRF_ERROR_BUSY Client procedure already in progress."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_primary_services_discover(
     conn_handle: u16,
     start_handle: u16,
     p_srvc_uuid: *const ble_uuid_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 155",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(start_handle) => _,
@@ -7966,6 +8173,10 @@ pub unsafe fn sd_ble_gattc_primary_services_discover(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(155);
+    }
     ret
 }
 
@@ -7993,11 +8204,13 @@ pub unsafe fn sd_ble_gattc_primary_services_discover(
 #[doc = " @retval ::NRF_ERROR_BUSY Client procedure already in progress."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_relationships_discover(
     conn_handle: u16,
     p_handle_range: *const ble_gattc_handle_range_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 156",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_handle_range) => _,
@@ -8005,6 +8218,10 @@ pub unsafe fn sd_ble_gattc_relationships_discover(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(156);
+    }
     ret
 }
 
@@ -8034,11 +8251,13 @@ pub unsafe fn sd_ble_gattc_relationships_discover(
 #[doc = " @retval ::NRF_ERROR_BUSY Client procedure already in progress."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_characteristics_discover(
     conn_handle: u16,
     p_handle_range: *const ble_gattc_handle_range_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 157",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_handle_range) => _,
@@ -8046,6 +8265,10 @@ pub unsafe fn sd_ble_gattc_characteristics_discover(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(157);
+    }
     ret
 }
 
@@ -8072,11 +8295,13 @@ pub unsafe fn sd_ble_gattc_characteristics_discover(
 #[doc = " @retval ::NRF_ERROR_BUSY Client procedure already in progress."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_descriptors_discover(
     conn_handle: u16,
     p_handle_range: *const ble_gattc_handle_range_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 158",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_handle_range) => _,
@@ -8084,6 +8309,10 @@ pub unsafe fn sd_ble_gattc_descriptors_discover(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(158);
+    }
     ret
 }
 
@@ -8111,12 +8340,14 @@ pub unsafe fn sd_ble_gattc_descriptors_discover(
 #[doc = " @retval ::NRF_ERROR_BUSY Client procedure already in progress."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_char_value_by_uuid_read(
     conn_handle: u16,
     p_uuid: *const ble_uuid_t,
     p_handle_range: *const ble_gattc_handle_range_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 160",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_uuid) => _,
@@ -8124,6 +8355,10 @@ pub unsafe fn sd_ble_gattc_char_value_by_uuid_read(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(160);
+    }
     ret
 }
 
@@ -8151,8 +8386,10 @@ pub unsafe fn sd_ble_gattc_char_value_by_uuid_read(
 #[doc = " @retval ::NRF_ERROR_BUSY Client procedure already in progress."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_read(conn_handle: u16, handle: u16, offset: u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 161",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(handle) => _,
@@ -8160,6 +8397,10 @@ pub unsafe fn sd_ble_gattc_read(conn_handle: u16, handle: u16, offset: u16) -> u
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(161);
+    }
     ret
 }
 
@@ -8186,8 +8427,10 @@ pub unsafe fn sd_ble_gattc_read(conn_handle: u16, handle: u16, offset: u16) -> u
 #[doc = " @retval ::NRF_ERROR_BUSY Client procedure already in progress."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_char_values_read(conn_handle: u16, p_handles: *const u16, handle_count: u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 162",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_handles) => _,
@@ -8195,6 +8438,10 @@ pub unsafe fn sd_ble_gattc_char_values_read(conn_handle: u16, p_handles: *const
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(162);
+    }
     ret
 }
 
@@ -8242,8 +8489,10 @@ pub unsafe fn sd_ble_gattc_char_values_read(conn_handle: u16, p_handles: *const
 #[doc = "                               Wait for a @ref BLE_GATTC_EVT_WRITE_CMD_TX_COMPLETE event and retry."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_write(conn_handle: u16, p_write_params: *const ble_gattc_write_params_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 163",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_write_params) => _,
@@ -8251,6 +8500,10 @@ pub unsafe fn sd_ble_gattc_write(conn_handle: u16, p_write_params: *const ble_ga
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(163);
+    }
     ret
 }
 
@@ -8269,8 +8522,10 @@ pub unsafe fn sd_ble_gattc_write(conn_handle: u16, p_write_params: *const ble_ga
 #[doc = " @retval ::BLE_ERROR_INVALID_ATTR_HANDLE Invalid attribute handle."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_hv_confirm(conn_handle: u16, handle: u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 164",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(handle) => _,
@@ -8278,6 +8533,10 @@ pub unsafe fn sd_ble_gattc_hv_confirm(conn_handle: u16, handle: u16) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(164);
+    }
     ret
 }
 
@@ -8297,11 +8556,13 @@ pub unsafe fn sd_ble_gattc_hv_confirm(conn_handle: u16, handle: u16) -> u32 {
 #[doc = " @retval ::NRF_ERROR_BUSY Client procedure already in progress."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_attr_info_discover(
     conn_handle: u16,
     p_handle_range: *const ble_gattc_handle_range_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 159",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_handle_range) => _,
@@ -8309,6 +8570,10 @@ pub unsafe fn sd_ble_gattc_attr_info_discover(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(159);
+    }
     ret
 }
 
@@ -8343,8 +8608,10 @@ pub unsafe fn sd_ble_gattc_attr_info_discover(
 #[doc = " @retval ::NRF_ERROR_BUSY Client procedure already in progress."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_exchange_mtu_request(conn_handle: u16, client_rx_mtu: u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 165",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(client_rx_mtu) => _,
@@ -8352,6 +8619,10 @@ pub unsafe fn sd_ble_gattc_exchange_mtu_request(conn_handle: u16, client_rx_mtu:
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(165);
+    }
     ret
 }
 
@@ -9911,8 +10182,10 @@ fn bindgen_test_layout_ble_gatts_evt_t() {
 #[doc = " @retval ::NRF_ERROR_FORBIDDEN Forbidden value supplied, certain UUIDs are reserved for the stack."]
 #[doc = " @retval ::NRF_ERROR_NO_MEM Not enough memory to complete operation."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_service_add(type_: u8, p_uuid: *const ble_uuid_t, p_handle: *mut u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 168",
         inout("r0") to_asm(type_) => ret,
         inout("r1") to_asm(p_uuid) => _,
@@ -9920,6 +10193,10 @@ pub unsafe fn sd_ble_gatts_service_add(type_: u8, p_uuid: *const ble_uuid_t, p_h
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(168);
+    }
     ret
 }
 
@@ -9946,8 +10223,10 @@ pub unsafe fn sd_ble_gatts_service_add(type_: u8, p_uuid: *const ble_uuid_t, p_h
 #[doc = " @retval ::NRF_ERROR_NO_MEM Not enough memory to complete operation."]
 #[doc = " @retval ::NRF_ERROR_NOT_FOUND Attribute not found."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_include_add(service_handle: u16, inc_srvc_handle: u16, p_include_handle: *mut u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 169",
         inout("r0") to_asm(service_handle) => ret,
         inout("r1") to_asm(inc_srvc_handle) => _,
@@ -9955,6 +10234,10 @@ pub unsafe fn sd_ble_gatts_include_add(service_handle: u16, inc_srvc_handle: u16
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(169);
+    }
     ret
 }
 
@@ -9984,6 +10267,7 @@ pub unsafe fn sd_ble_gatts_include_add(service_handle: u16, inc_srvc_handle: u16
 #[doc = " @retval ::NRF_ERROR_NO_MEM Not enough memory to complete operation."]
 #[doc = " @retval ::NRF_ERROR_DATA_SIZE Invalid data size(s) supplied, attribute lengths are restricted by @ref BLE_GATTS_ATTR_LENS_MAX."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_characteristic_add(
     service_handle: u16,
     p_char_md: *const ble_gatts_char_md_t,
@@ -9991,6 +10275,7 @@ pub unsafe fn sd_ble_gatts_characteristic_add(
     p_handles: *mut ble_gatts_char_handles_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 170",
         inout("r0") to_asm(service_handle) => ret,
         inout("r1") to_asm(p_char_md) => _,
@@ -9998,6 +10283,10 @@ pub unsafe fn sd_ble_gatts_characteristic_add(
         inout("r3") to_asm(p_handles) => _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(170);
+    }
     ret
 }
 
@@ -10021,12 +10310,14 @@ pub unsafe fn sd_ble_gatts_characteristic_add(
 #[doc = " @retval ::NRF_ERROR_NO_MEM Not enough memory to complete operation."]
 #[doc = " @retval ::NRF_ERROR_DATA_SIZE Invalid data size(s) supplied, attribute lengths are restricted by @ref BLE_GATTS_ATTR_LENS_MAX."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_descriptor_add(
     char_handle: u16,
     p_attr: *const ble_gatts_attr_t,
     p_handle: *mut u16,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 171",
         inout("r0") to_asm(char_handle) => ret,
         inout("r1") to_asm(p_attr) => _,
@@ -10034,6 +10325,10 @@ pub unsafe fn sd_ble_gatts_descriptor_add(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(171);
+    }
     ret
 }
 
@@ -10058,8 +10353,10 @@ pub unsafe fn sd_ble_gatts_descriptor_add(
 #[doc = " @retval ::NRF_ERROR_DATA_SIZE Invalid data size(s) supplied, attribute lengths are restricted by @ref BLE_GATTS_ATTR_LENS_MAX."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied on a system attribute."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_value_set(conn_handle: u16, handle: u16, p_value: *mut ble_gatts_value_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 172",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(handle) => _,
@@ -10067,6 +10364,10 @@ pub unsafe fn sd_ble_gatts_value_set(conn_handle: u16, handle: u16, p_value: *mu
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(172);
+    }
     ret
 }
 
@@ -10092,8 +10393,10 @@ pub unsafe fn sd_ble_gatts_value_set(conn_handle: u16, handle: u16, p_value: *mu
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied on a system attribute."]
 #[doc = " @retval ::BLE_ERROR_GATTS_SYS_ATTR_MISSING System attributes missing, use @ref sd_ble_gatts_sys_attr_set to set them to a known value."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_value_get(conn_handle: u16, handle: u16, p_value: *mut ble_gatts_value_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 173",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(handle) => _,
@@ -10101,6 +10404,10 @@ pub unsafe fn sd_ble_gatts_value_get(conn_handle: u16, handle: u16, p_value: *mu
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(173);
+    }
     ret
 }
 
@@ -10167,8 +10474,10 @@ pub unsafe fn sd_ble_gatts_value_get(conn_handle: u16, handle: u16, p_value: *mu
 #[doc = "                               Wait for a @ref BLE_GATTS_EVT_HVN_TX_COMPLETE event and retry."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_hvx(conn_handle: u16, p_hvx_params: *const ble_gatts_hvx_params_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 174",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_hvx_params) => _,
@@ -10176,6 +10485,10 @@ pub unsafe fn sd_ble_gatts_hvx(conn_handle: u16, p_hvx_params: *const ble_gatts_
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(174);
+    }
     ret
 }
 
@@ -10213,8 +10526,10 @@ pub unsafe fn sd_ble_gatts_hvx(conn_handle: u16, p_hvx_params: *const ble_gatts_
 #[doc = " @retval ::BLE_ERROR_GATTS_SYS_ATTR_MISSING System attributes missing, use @ref sd_ble_gatts_sys_attr_set to set them to a known value."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_service_changed(conn_handle: u16, start_handle: u16, end_handle: u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 175",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(start_handle) => _,
@@ -10222,6 +10537,10 @@ pub unsafe fn sd_ble_gatts_service_changed(conn_handle: u16, start_handle: u16,
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(175);
+    }
     ret
 }
 
@@ -10256,11 +10575,13 @@ pub unsafe fn sd_ble_gatts_service_changed(conn_handle: u16, start_handle: u16,
 #[doc = "                                         or invalid data to be written provided by the application."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_rw_authorize_reply(
     conn_handle: u16,
     p_rw_authorize_reply_params: *const ble_gatts_rw_authorize_reply_params_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 176",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_rw_authorize_reply_params) => _,
@@ -10268,6 +10589,10 @@ pub unsafe fn sd_ble_gatts_rw_authorize_reply(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(176);
+    }
     ret
 }
 
@@ -10312,8 +10637,10 @@ pub unsafe fn sd_ble_gatts_rw_authorize_reply(
 #[doc = " @retval ::NRF_ERROR_INVALID_DATA Invalid data supplied, the data should be exactly the same as retrieved with @ref sd_ble_gatts_sys_attr_get."]
 #[doc = " @retval ::NRF_ERROR_NO_MEM Not enough memory to complete operation."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_sys_attr_set(conn_handle: u16, p_sys_attr_data: *const u8, len: u16, flags: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 177",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_sys_attr_data) => _,
@@ -10321,6 +10648,10 @@ pub unsafe fn sd_ble_gatts_sys_attr_set(conn_handle: u16, p_sys_attr_data: *cons
         inout("r3") to_asm(flags) => _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(177);
+    }
     ret
 }
 
@@ -10354,6 +10685,7 @@ pub unsafe fn sd_ble_gatts_sys_attr_set(conn_handle: u16, p_sys_attr_data: *cons
 #[doc = " @retval ::NRF_ERROR_DATA_SIZE The system attribute information did not fit into the provided buffer."]
 #[doc = " @retval ::NRF_ERROR_NOT_FOUND No system attributes found."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_sys_attr_get(
     conn_handle: u16,
     p_sys_attr_data: *mut u8,
@@ -10361,6 +10693,7 @@ pub unsafe fn sd_ble_gatts_sys_attr_get(
     flags: u32,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 178",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_sys_attr_data) => _,
@@ -10368,6 +10701,10 @@ pub unsafe fn sd_ble_gatts_sys_attr_get(
         inout("r3") to_asm(flags) => _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(178);
+    }
     ret
 }
 
@@ -10378,8 +10715,10 @@ pub unsafe fn sd_ble_gatts_sys_attr_get(
 #[doc = " @retval ::NRF_SUCCESS Successfully retrieved the handle."]
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR Invalid pointer supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_initial_user_handle_get(p_handle: *mut u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 179",
         inout("r0") to_asm(p_handle) => ret,
         lateout("r1") _,
@@ -10387,6 +10726,10 @@ pub unsafe fn sd_ble_gatts_initial_user_handle_get(p_handle: *mut u16) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(179);
+    }
     ret
 }
 
@@ -10401,8 +10744,10 @@ pub unsafe fn sd_ble_gatts_initial_user_handle_get(p_handle: *mut u16) -> u32 {
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM Invalid parameters supplied. Returned when both @c p_uuid and @c p_md are NULL."]
 #[doc = " @retval ::NRF_ERROR_NOT_FOUND Attribute was not found."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_attr_get(handle: u16, p_uuid: *mut ble_uuid_t, p_md: *mut ble_gatts_attr_md_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 180",
         inout("r0") to_asm(handle) => ret,
         inout("r1") to_asm(p_uuid) => _,
@@ -10410,6 +10755,10 @@ pub unsafe fn sd_ble_gatts_attr_get(handle: u16, p_uuid: *mut ble_uuid_t, p_md:
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(180);
+    }
     ret
 }
 
@@ -10441,8 +10790,10 @@ pub unsafe fn sd_ble_gatts_attr_get(handle: u16, p_uuid: *mut ble_uuid_t, p_md:
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM Invalid Server RX MTU size supplied."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_exchange_mtu_reply(conn_handle: u16, server_rx_mtu: u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 181",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(server_rx_mtu) => _,
@@ -10450,6 +10801,10 @@ pub unsafe fn sd_ble_gatts_exchange_mtu_reply(conn_handle: u16, server_rx_mtu: u
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(181);
+    }
     ret
 }
 
@@ -11462,8 +11817,10 @@ fn bindgen_test_layout_ble_cfg_t() {
 #[doc = "                                      is currently not supported."]
 #[doc = " @retval ::NRF_ERROR_RESOURCES      The total number of L2CAP Channels configured using @ref sd_ble_cfg_set is too large."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_enable(p_app_ram_base: *mut u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 96",
         inout("r0") to_asm(p_app_ram_base) => ret,
         lateout("r1") _,
@@ -11471,6 +11828,10 @@ pub unsafe fn sd_ble_enable(p_app_ram_base: *mut u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(96);
+    }
     ret
 }
 
@@ -11508,8 +11869,10 @@ pub unsafe fn sd_ble_enable(p_app_ram_base: *mut u32) -> u32 {
 #[doc = " @retval ::NRF_ERROR_NO_MEM         The amount of memory assigned to the SoftDevice by app_ram_base is not"]
 #[doc = "                                    large enough to fit this configuration's memory requirement."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_cfg_set(cfg_id: u32, p_cfg: *const ble_cfg_t, app_ram_base: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 106",
         inout("r0") to_asm(cfg_id) => ret,
         inout("r1") to_asm(p_cfg) => _,
@@ -11517,6 +11880,10 @@ pub unsafe fn sd_ble_cfg_set(cfg_id: u32, p_cfg: *const ble_cfg_t, app_ram_base:
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(106);
+    }
     ret
 }
 
@@ -11556,8 +11923,10 @@ pub unsafe fn sd_ble_cfg_set(cfg_id: u32, p_cfg: *const ble_cfg_t, app_ram_base:
 #[doc = " @retval ::NRF_ERROR_NOT_FOUND No events ready to be pulled."]
 #[doc = " @retval ::NRF_ERROR_DATA_SIZE Event ready but could not fit into the supplied buffer."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_evt_get(p_dest: *mut u8, p_len: *mut u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 97",
         inout("r0") to_asm(p_dest) => ret,
         inout("r1") to_asm(p_len) => _,
@@ -11565,6 +11934,10 @@ pub unsafe fn sd_ble_evt_get(p_dest: *mut u8, p_len: *mut u16) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(97);
+    }
     ret
 }
 
@@ -11594,8 +11967,10 @@ pub unsafe fn sd_ble_evt_get(p_dest: *mut u8, p_len: *mut u16) -> u32 {
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR If p_vs_uuid or p_uuid_type is NULL or invalid."]
 #[doc = " @retval ::NRF_ERROR_NO_MEM If there are no more free slots for VS UUIDs."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_uuid_vs_add(p_vs_uuid: *const ble_uuid128_t, p_uuid_type: *mut u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 98",
         inout("r0") to_asm(p_vs_uuid) => ret,
         inout("r1") to_asm(p_uuid_type) => _,
@@ -11603,6 +11978,10 @@ pub unsafe fn sd_ble_uuid_vs_add(p_vs_uuid: *const ble_uuid128_t, p_uuid_type: *
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(98);
+    }
     ret
 }
 
@@ -11624,8 +12003,10 @@ pub unsafe fn sd_ble_uuid_vs_add(p_vs_uuid: *const ble_uuid128_t, p_uuid_type: *
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM If p_uuid_type points to a non-valid UUID type."]
 #[doc = " @retval ::NRF_ERROR_FORBIDDEN If the Vendor Specific base UUID is in use by the ATT Server."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_uuid_vs_remove(p_uuid_type: *mut u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 99",
         inout("r0") to_asm(p_uuid_type) => ret,
         lateout("r1") _,
@@ -11633,6 +12014,10 @@ pub unsafe fn sd_ble_uuid_vs_remove(p_uuid_type: *mut u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(99);
+    }
     ret
 }
 
@@ -11654,8 +12039,10 @@ pub unsafe fn sd_ble_uuid_vs_remove(p_uuid_type: *mut u8) -> u32 {
 #[doc = " @retval ::NRF_ERROR_INVALID_LENGTH Invalid UUID length."]
 #[doc = " @retval ::NRF_ERROR_NOT_FOUND For a 128-bit UUID, no match in the populated table of UUIDs."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_uuid_decode(uuid_le_len: u8, p_uuid_le: *const u8, p_uuid: *mut ble_uuid_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 100",
         inout("r0") to_asm(uuid_le_len) => ret,
         inout("r1") to_asm(p_uuid_le) => _,
@@ -11663,6 +12050,10 @@ pub unsafe fn sd_ble_uuid_decode(uuid_le_len: u8, p_uuid_le: *const u8, p_uuid:
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(100);
+    }
     ret
 }
 
@@ -11678,8 +12069,10 @@ pub unsafe fn sd_ble_uuid_decode(uuid_le_len: u8, p_uuid_le: *const u8, p_uuid:
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR Invalid pointer supplied."]
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM Invalid UUID type."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_uuid_encode(p_uuid: *const ble_uuid_t, p_uuid_le_len: *mut u8, p_uuid_le: *mut u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 101",
         inout("r0") to_asm(p_uuid) => ret,
         inout("r1") to_asm(p_uuid_le_len) => _,
@@ -11687,6 +12080,10 @@ pub unsafe fn sd_ble_uuid_encode(p_uuid: *const ble_uuid_t, p_uuid_le_len: *mut
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(101);
+    }
     ret
 }
 
@@ -11700,8 +12097,10 @@ pub unsafe fn sd_ble_uuid_encode(p_uuid: *const ble_uuid_t, p_uuid_le_len: *mut
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR Invalid pointer supplied."]
 #[doc = " @retval ::NRF_ERROR_BUSY The BLE stack is busy (typically doing a locally-initiated disconnection procedure)."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_version_get(p_version: *mut ble_version_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 102",
         inout("r0") to_asm(p_version) => ret,
         lateout("r1") _,
@@ -11709,6 +12108,10 @@ pub unsafe fn sd_ble_version_get(p_version: *mut ble_version_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(102);
+    }
     ret
 }
 
@@ -11735,8 +12138,10 @@ pub unsafe fn sd_ble_version_get(p_version: *mut ble_version_t) -> u32 {
 #[doc = " @retval ::NRF_ERROR_INVALID_LENGTH Invalid user memory block length supplied."]
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE Invalid Connection state or no user memory request pending."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_user_mem_reply(conn_handle: u16, p_block: *const ble_user_mem_block_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 103",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_block) => _,
@@ -11744,6 +12149,10 @@ pub unsafe fn sd_ble_user_mem_reply(conn_handle: u16, p_block: *const ble_user_m
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(103);
+    }
     ret
 }
 
@@ -11763,8 +12172,10 @@ pub unsafe fn sd_ble_user_mem_reply(conn_handle: u16, p_block: *const ble_user_m
 #[doc = ""]
 #[doc = " @note For a complete set of return values see @ref ble_opt_t."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_opt_set(opt_id: u32, p_opt: *const ble_opt_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 104",
         inout("r0") to_asm(opt_id) => ret,
         inout("r1") to_asm(p_opt) => _,
@@ -11772,6 +12183,10 @@ pub unsafe fn sd_ble_opt_set(opt_id: u32, p_opt: *const ble_opt_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(104);
+    }
     ret
 }
 
@@ -11792,8 +12207,10 @@ pub unsafe fn sd_ble_opt_set(opt_id: u32, p_opt: *const ble_opt_t) -> u32 {
 #[doc = ""]
 #[doc = " @note For a complete set of return values see @ref ble_opt_t."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_opt_get(opt_id: u32, p_opt: *mut ble_opt_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 105",
         inout("r0") to_asm(opt_id) => ret,
         inout("r1") to_asm(p_opt) => _,
@@ -11801,6 +12218,10 @@ pub unsafe fn sd_ble_opt_get(opt_id: u32, p_opt: *mut ble_opt_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(105);
+    }
     ret
 }
 
@@ -12540,8 +12961,10 @@ fn bindgen_test_layout_nrf_ecb_hal_data_block_t() {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_mutex_new(p_mutex: *mut nrf_mutex_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 44",
         inout("r0") to_asm(p_mutex) => ret,
         lateout("r1") _,
@@ -12549,6 +12972,10 @@ pub unsafe fn sd_mutex_new(p_mutex: *mut nrf_mutex_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(44);
+    }
     ret
 }
 
@@ -12559,8 +12986,10 @@ pub unsafe fn sd_mutex_new(p_mutex: *mut nrf_mutex_t) -> u32 {
 #[doc = " @retval ::NRF_SUCCESS The mutex was successfully acquired."]
 #[doc = " @retval ::NRF_ERROR_SOC_MUTEX_ALREADY_TAKEN The mutex could not be acquired."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_mutex_acquire(p_mutex: *mut nrf_mutex_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 45",
         inout("r0") to_asm(p_mutex) => ret,
         lateout("r1") _,
@@ -12568,6 +12997,10 @@ pub unsafe fn sd_mutex_acquire(p_mutex: *mut nrf_mutex_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(45);
+    }
     ret
 }
 
@@ -12577,8 +13010,10 @@ pub unsafe fn sd_mutex_acquire(p_mutex: *mut nrf_mutex_t) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_mutex_release(p_mutex: *mut nrf_mutex_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 46",
         inout("r0") to_asm(p_mutex) => ret,
         lateout("r1") _,
@@ -12586,6 +13021,10 @@ pub unsafe fn sd_mutex_release(p_mutex: *mut nrf_mutex_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(46);
+    }
     ret
 }
 
@@ -12595,8 +13034,10 @@ pub unsafe fn sd_mutex_release(p_mutex: *mut nrf_mutex_t) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_rand_application_pool_capacity_get(p_pool_capacity: *mut u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 47",
         inout("r0") to_asm(p_pool_capacity) => ret,
         lateout("r1") _,
@@ -12604,6 +13045,10 @@ pub unsafe fn sd_rand_application_pool_capacity_get(p_pool_capacity: *mut u8) ->
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(47);
+    }
     ret
 }
 
@@ -12613,8 +13058,10 @@ pub unsafe fn sd_rand_application_pool_capacity_get(p_pool_capacity: *mut u8) ->
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_rand_application_bytes_available_get(p_bytes_available: *mut u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 48",
         inout("r0") to_asm(p_bytes_available) => ret,
         lateout("r1") _,
@@ -12622,6 +13069,10 @@ pub unsafe fn sd_rand_application_bytes_available_get(p_bytes_available: *mut u8
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(48);
+    }
     ret
 }
 
@@ -12633,8 +13084,10 @@ pub unsafe fn sd_rand_application_bytes_available_get(p_bytes_available: *mut u8
 #[doc = " @retval ::NRF_SUCCESS The requested bytes were written to p_buff."]
 #[doc = " @retval ::NRF_ERROR_SOC_RAND_NOT_ENOUGH_VALUES No bytes were written to the buffer, because there were not enough bytes available."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_rand_application_vector_get(p_buff: *mut u8, length: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 49",
         inout("r0") to_asm(p_buff) => ret,
         inout("r1") to_asm(length) => _,
@@ -12642,6 +13095,10 @@ pub unsafe fn sd_rand_application_vector_get(p_buff: *mut u8, length: u8) -> u32
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(49);
+    }
     ret
 }
 
@@ -12651,8 +13108,10 @@ pub unsafe fn sd_rand_application_vector_get(p_buff: *mut u8, length: u8) -> u32
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_reset_reason_get(p_reset_reason: *mut u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 52",
         inout("r0") to_asm(p_reset_reason) => ret,
         lateout("r1") _,
@@ -12660,6 +13119,10 @@ pub unsafe fn sd_power_reset_reason_get(p_reset_reason: *mut u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(52);
+    }
     ret
 }
 
@@ -12669,8 +13132,10 @@ pub unsafe fn sd_power_reset_reason_get(p_reset_reason: *mut u32) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_reset_reason_clr(reset_reason_clr_msk: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 53",
         inout("r0") to_asm(reset_reason_clr_msk) => ret,
         lateout("r1") _,
@@ -12678,6 +13143,10 @@ pub unsafe fn sd_power_reset_reason_clr(reset_reason_clr_msk: u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(53);
+    }
     ret
 }
 
@@ -12688,8 +13157,10 @@ pub unsafe fn sd_power_reset_reason_clr(reset_reason_clr_msk: u32) -> u32 {
 #[doc = " @retval ::NRF_SUCCESS The power mode was set."]
 #[doc = " @retval ::NRF_ERROR_SOC_POWER_MODE_UNKNOWN The power mode was unknown."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_mode_set(power_mode: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 50",
         inout("r0") to_asm(power_mode) => ret,
         lateout("r1") _,
@@ -12697,6 +13168,10 @@ pub unsafe fn sd_power_mode_set(power_mode: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(50);
+    }
     ret
 }
 
@@ -12704,8 +13179,10 @@ pub unsafe fn sd_power_mode_set(power_mode: u8) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_ERROR_SOC_POWER_OFF_SHOULD_NOT_RETURN"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_system_off() -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 51",
         lateout("r0") ret,
         lateout("r1") _,
@@ -12713,6 +13190,10 @@ pub unsafe fn sd_power_system_off() -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(51);
+    }
     ret
 }
 
@@ -12725,8 +13206,10 @@ pub unsafe fn sd_power_system_off() -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_pof_enable(pof_enable: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 54",
         inout("r0") to_asm(pof_enable) => ret,
         lateout("r1") _,
@@ -12734,6 +13217,10 @@ pub unsafe fn sd_power_pof_enable(pof_enable: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(54);
+    }
     ret
 }
 
@@ -12748,8 +13235,10 @@ pub unsafe fn sd_power_pof_enable(pof_enable: u8) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_usbpwrrdy_enable(usbpwrrdy_enable: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 77",
         inout("r0") to_asm(usbpwrrdy_enable) => ret,
         lateout("r1") _,
@@ -12757,6 +13246,10 @@ pub unsafe fn sd_power_usbpwrrdy_enable(usbpwrrdy_enable: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(77);
+    }
     ret
 }
 
@@ -12771,8 +13264,10 @@ pub unsafe fn sd_power_usbpwrrdy_enable(usbpwrrdy_enable: u8) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_usbdetected_enable(usbdetected_enable: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 78",
         inout("r0") to_asm(usbdetected_enable) => ret,
         lateout("r1") _,
@@ -12780,6 +13275,10 @@ pub unsafe fn sd_power_usbdetected_enable(usbdetected_enable: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(78);
+    }
     ret
 }
 
@@ -12794,8 +13293,10 @@ pub unsafe fn sd_power_usbdetected_enable(usbdetected_enable: u8) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_usbremoved_enable(usbremoved_enable: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 79",
         inout("r0") to_asm(usbremoved_enable) => ret,
         lateout("r1") _,
@@ -12803,6 +13304,10 @@ pub unsafe fn sd_power_usbremoved_enable(usbremoved_enable: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(79);
+    }
     ret
 }
 
@@ -12814,8 +13319,10 @@ pub unsafe fn sd_power_usbremoved_enable(usbremoved_enable: u8) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_usbregstatus_get(usbregstatus: *mut u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 80",
         inout("r0") to_asm(usbregstatus) => ret,
         lateout("r1") _,
@@ -12823,6 +13330,10 @@ pub unsafe fn sd_power_usbregstatus_get(usbregstatus: *mut u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(80);
+    }
     ret
 }
 
@@ -12834,8 +13345,10 @@ pub unsafe fn sd_power_usbregstatus_get(usbregstatus: *mut u32) -> u32 {
 #[doc = " @retval ::NRF_SUCCESS The power failure threshold was set."]
 #[doc = " @retval ::NRF_ERROR_SOC_POWER_POF_THRESHOLD_UNKNOWN The power failure threshold is unknown."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_pof_threshold_set(threshold: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 55",
         inout("r0") to_asm(threshold) => ret,
         lateout("r1") _,
@@ -12843,6 +13356,10 @@ pub unsafe fn sd_power_pof_threshold_set(threshold: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(55);
+    }
     ret
 }
 
@@ -12853,8 +13370,10 @@ pub unsafe fn sd_power_pof_threshold_set(threshold: u8) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_ram_power_set(index: u8, ram_powerset: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 57",
         inout("r0") to_asm(index) => ret,
         inout("r1") to_asm(ram_powerset) => _,
@@ -12862,6 +13381,10 @@ pub unsafe fn sd_power_ram_power_set(index: u8, ram_powerset: u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(57);
+    }
     ret
 }
 
@@ -12872,8 +13395,10 @@ pub unsafe fn sd_power_ram_power_set(index: u8, ram_powerset: u32) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_ram_power_clr(index: u8, ram_powerclr: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 58",
         inout("r0") to_asm(index) => ret,
         inout("r1") to_asm(ram_powerclr) => _,
@@ -12881,6 +13406,10 @@ pub unsafe fn sd_power_ram_power_clr(index: u8, ram_powerclr: u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(58);
+    }
     ret
 }
 
@@ -12891,8 +13420,10 @@ pub unsafe fn sd_power_ram_power_clr(index: u8, ram_powerclr: u32) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_ram_power_get(index: u8, p_ram_power: *mut u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 59",
         inout("r0") to_asm(index) => ret,
         inout("r1") to_asm(p_ram_power) => _,
@@ -12900,6 +13431,10 @@ pub unsafe fn sd_power_ram_power_get(index: u8, p_ram_power: *mut u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(59);
+    }
     ret
 }
 
@@ -12910,8 +13445,10 @@ pub unsafe fn sd_power_ram_power_get(index: u8, p_ram_power: *mut u32) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_gpregret_set(gpregret_id: u32, gpregret_msk: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 60",
         inout("r0") to_asm(gpregret_id) => ret,
         inout("r1") to_asm(gpregret_msk) => _,
@@ -12919,6 +13456,10 @@ pub unsafe fn sd_power_gpregret_set(gpregret_id: u32, gpregret_msk: u32) -> u32
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(60);
+    }
     ret
 }
 
@@ -12929,8 +13470,10 @@ pub unsafe fn sd_power_gpregret_set(gpregret_id: u32, gpregret_msk: u32) -> u32
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_gpregret_clr(gpregret_id: u32, gpregret_msk: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 61",
         inout("r0") to_asm(gpregret_id) => ret,
         inout("r1") to_asm(gpregret_msk) => _,
@@ -12938,6 +13481,10 @@ pub unsafe fn sd_power_gpregret_clr(gpregret_id: u32, gpregret_msk: u32) -> u32
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(61);
+    }
     ret
 }
 
@@ -12948,8 +13495,10 @@ pub unsafe fn sd_power_gpregret_clr(gpregret_id: u32, gpregret_msk: u32) -> u32
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_gpregret_get(gpregret_id: u32, p_gpregret: *mut u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 62",
         inout("r0") to_asm(gpregret_id) => ret,
         inout("r1") to_asm(p_gpregret) => _,
@@ -12957,6 +13506,10 @@ pub unsafe fn sd_power_gpregret_get(gpregret_id: u32, p_gpregret: *mut u32) -> u
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(62);
+    }
     ret
 }
 
@@ -12967,8 +13520,10 @@ pub unsafe fn sd_power_gpregret_get(gpregret_id: u32, p_gpregret: *mut u32) -> u
 #[doc = " @retval ::NRF_SUCCESS"]
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM The DCDC mode is invalid."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_dcdc_mode_set(dcdc_mode: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 63",
         inout("r0") to_asm(dcdc_mode) => ret,
         lateout("r1") _,
@@ -12976,6 +13531,10 @@ pub unsafe fn sd_power_dcdc_mode_set(dcdc_mode: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(63);
+    }
     ret
 }
 
@@ -12989,8 +13548,10 @@ pub unsafe fn sd_power_dcdc_mode_set(dcdc_mode: u8) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_clock_hfclk_request() -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 66",
         lateout("r0") ret,
         lateout("r1") _,
@@ -12998,6 +13559,10 @@ pub unsafe fn sd_clock_hfclk_request() -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(66);
+    }
     ret
 }
 
@@ -13010,8 +13575,10 @@ pub unsafe fn sd_clock_hfclk_request() -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_clock_hfclk_release() -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 67",
         lateout("r0") ret,
         lateout("r1") _,
@@ -13019,6 +13586,10 @@ pub unsafe fn sd_clock_hfclk_release() -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(67);
+    }
     ret
 }
 
@@ -13031,8 +13602,10 @@ pub unsafe fn sd_clock_hfclk_release() -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_clock_hfclk_is_running(p_is_running: *mut u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 68",
         inout("r0") to_asm(p_is_running) => ret,
         lateout("r1") _,
@@ -13040,6 +13613,10 @@ pub unsafe fn sd_clock_hfclk_is_running(p_is_running: *mut u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(68);
+    }
     ret
 }
 
@@ -13069,8 +13646,10 @@ pub unsafe fn sd_clock_hfclk_is_running(p_is_running: *mut u32) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_app_evt_wait() -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 65",
         lateout("r0") ret,
         lateout("r1") _,
@@ -13078,6 +13657,10 @@ pub unsafe fn sd_app_evt_wait() -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(65);
+    }
     ret
 }
 
@@ -13087,8 +13670,10 @@ pub unsafe fn sd_app_evt_wait() -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ppi_channel_enable_get(p_channel_enable: *mut u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 32",
         inout("r0") to_asm(p_channel_enable) => ret,
         lateout("r1") _,
@@ -13096,6 +13681,10 @@ pub unsafe fn sd_ppi_channel_enable_get(p_channel_enable: *mut u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(32);
+    }
     ret
 }
 
@@ -13105,8 +13694,10 @@ pub unsafe fn sd_ppi_channel_enable_get(p_channel_enable: *mut u32) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ppi_channel_enable_set(channel_enable_set_msk: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 33",
         inout("r0") to_asm(channel_enable_set_msk) => ret,
         lateout("r1") _,
@@ -13114,6 +13705,10 @@ pub unsafe fn sd_ppi_channel_enable_set(channel_enable_set_msk: u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(33);
+    }
     ret
 }
 
@@ -13123,8 +13718,10 @@ pub unsafe fn sd_ppi_channel_enable_set(channel_enable_set_msk: u32) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ppi_channel_enable_clr(channel_enable_clr_msk: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 34",
         inout("r0") to_asm(channel_enable_clr_msk) => ret,
         lateout("r1") _,
@@ -13132,6 +13729,10 @@ pub unsafe fn sd_ppi_channel_enable_clr(channel_enable_clr_msk: u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(34);
+    }
     ret
 }
 
@@ -13144,12 +13745,14 @@ pub unsafe fn sd_ppi_channel_enable_clr(channel_enable_clr_msk: u32) -> u32 {
 #[doc = " @retval ::NRF_ERROR_SOC_PPI_INVALID_CHANNEL The channel number is invalid."]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ppi_channel_assign(
     channel_num: u8,
     evt_endpoint: *const self::c_void,
     task_endpoint: *const self::c_void,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 35",
         inout("r0") to_asm(channel_num) => ret,
         inout("r1") to_asm(evt_endpoint) => _,
@@ -13157,6 +13760,10 @@ pub unsafe fn sd_ppi_channel_assign(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(35);
+    }
     ret
 }
 
@@ -13167,8 +13774,10 @@ pub unsafe fn sd_ppi_channel_assign(
 #[doc = " @retval ::NRF_ERROR_SOC_PPI_INVALID_GROUP The group number is invalid"]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ppi_group_task_enable(group_num: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 36",
         inout("r0") to_asm(group_num) => ret,
         lateout("r1") _,
@@ -13176,6 +13785,10 @@ pub unsafe fn sd_ppi_group_task_enable(group_num: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(36);
+    }
     ret
 }
 
@@ -13186,8 +13799,10 @@ pub unsafe fn sd_ppi_group_task_enable(group_num: u8) -> u32 {
 #[doc = " @retval ::NRF_ERROR_SOC_PPI_INVALID_GROUP The group number is invalid."]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ppi_group_task_disable(group_num: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 37",
         inout("r0") to_asm(group_num) => ret,
         lateout("r1") _,
@@ -13195,6 +13810,10 @@ pub unsafe fn sd_ppi_group_task_disable(group_num: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(37);
+    }
     ret
 }
 
@@ -13206,8 +13825,10 @@ pub unsafe fn sd_ppi_group_task_disable(group_num: u8) -> u32 {
 #[doc = " @retval ::NRF_ERROR_SOC_PPI_INVALID_GROUP The group number is invalid."]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ppi_group_assign(group_num: u8, channel_msk: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 38",
         inout("r0") to_asm(group_num) => ret,
         inout("r1") to_asm(channel_msk) => _,
@@ -13215,6 +13836,10 @@ pub unsafe fn sd_ppi_group_assign(group_num: u8, channel_msk: u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(38);
+    }
     ret
 }
 
@@ -13226,8 +13851,10 @@ pub unsafe fn sd_ppi_group_assign(group_num: u8, channel_msk: u32) -> u32 {
 #[doc = " @retval ::NRF_ERROR_SOC_PPI_INVALID_GROUP The group number is invalid."]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ppi_group_get(group_num: u8, p_channel_msk: *mut u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 39",
         inout("r0") to_asm(group_num) => ret,
         inout("r1") to_asm(p_channel_msk) => _,
@@ -13235,6 +13862,10 @@ pub unsafe fn sd_ppi_group_get(group_num: u8, p_channel_msk: *mut u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(39);
+    }
     ret
 }
 
@@ -13267,8 +13898,10 @@ pub unsafe fn sd_ppi_group_get(group_num: u8, p_channel_msk: *mut u32) -> u32 {
 #[doc = "                                   running activities and retry."]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_radio_notification_cfg_set(type_: u8, distance: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 69",
         inout("r0") to_asm(type_) => ret,
         inout("r1") to_asm(distance) => _,
@@ -13276,6 +13909,10 @@ pub unsafe fn sd_radio_notification_cfg_set(type_: u8, distance: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(69);
+    }
     ret
 }
 
@@ -13293,8 +13930,10 @@ pub unsafe fn sd_radio_notification_cfg_set(type_: u8, distance: u8) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ecb_block_encrypt(p_ecb_data: *mut nrf_ecb_hal_data_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 70",
         inout("r0") to_asm(p_ecb_data) => ret,
         lateout("r1") _,
@@ -13302,6 +13941,10 @@ pub unsafe fn sd_ecb_block_encrypt(p_ecb_data: *mut nrf_ecb_hal_data_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(70);
+    }
     ret
 }
 
@@ -13320,8 +13963,10 @@ pub unsafe fn sd_ecb_block_encrypt(p_ecb_data: *mut nrf_ecb_hal_data_t) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ecb_blocks_encrypt(block_count: u8, p_data_blocks: *mut nrf_ecb_hal_data_block_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 71",
         inout("r0") to_asm(block_count) => ret,
         inout("r1") to_asm(p_data_blocks) => _,
@@ -13329,6 +13974,10 @@ pub unsafe fn sd_ecb_blocks_encrypt(block_count: u8, p_data_blocks: *mut nrf_ecb
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(71);
+    }
     ret
 }
 
@@ -13341,8 +13990,10 @@ pub unsafe fn sd_ecb_blocks_encrypt(block_count: u8, p_data_blocks: *mut nrf_ecb
 #[doc = " @retval ::NRF_SUCCESS An event was pending. The event id is written in the p_evt_id parameter."]
 #[doc = " @retval ::NRF_ERROR_NOT_FOUND No pending events."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_evt_get(p_evt_id: *mut u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 75",
         inout("r0") to_asm(p_evt_id) => ret,
         lateout("r1") _,
@@ -13350,6 +14001,10 @@ pub unsafe fn sd_evt_get(p_evt_id: *mut u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(75);
+    }
     ret
 }
 
@@ -13362,8 +14017,10 @@ pub unsafe fn sd_evt_get(p_evt_id: *mut u32) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS A temperature measurement was done, and the temperature was written to temp"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_temp_get(p_temp: *mut i32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 76",
         inout("r0") to_asm(p_temp) => ret,
         lateout("r1") _,
@@ -13371,6 +14028,10 @@ pub unsafe fn sd_temp_get(p_temp: *mut i32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(76);
+    }
     ret
 }
 
@@ -13409,8 +14070,10 @@ pub unsafe fn sd_temp_get(p_temp: *mut i32) -> u32 {
 #[doc = " @retval ::NRF_ERROR_FORBIDDEN      Tried to write to an address outside the application flash area."]
 #[doc = " @retval ::NRF_SUCCESS              The command was accepted."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_flash_write(p_dst: *mut u32, p_src: *const u32, size: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 41",
         inout("r0") to_asm(p_dst) => ret,
         inout("r1") to_asm(p_src) => _,
@@ -13418,6 +14081,10 @@ pub unsafe fn sd_flash_write(p_dst: *mut u32, p_src: *const u32, size: u32) -> u
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(41);
+    }
     ret
 }
 
@@ -13450,8 +14117,10 @@ pub unsafe fn sd_flash_write(p_dst: *mut u32, p_src: *const u32, size: u32) -> u
 #[doc = " @retval ::NRF_ERROR_FORBIDDEN     Tried to erase a page outside the application flash area."]
 #[doc = " @retval ::NRF_SUCCESS             The command was accepted."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_flash_page_erase(page_number: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 40",
         inout("r0") to_asm(page_number) => ret,
         lateout("r1") _,
@@ -13459,6 +14128,10 @@ pub unsafe fn sd_flash_page_erase(page_number: u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(40);
+    }
     ret
 }
 
@@ -13482,8 +14155,10 @@ pub unsafe fn sd_flash_page_erase(page_number: u32) -> u32 {
 #[doc = " @retval ::NRF_ERROR_INTERNAL If a new session could not be opened due to an internal error."]
 #[doc = " @retval ::NRF_SUCCESS Otherwise."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_radio_session_open(p_radio_signal_callback: nrf_radio_signal_callback_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 72",
         inout("r0") to_asm(p_radio_signal_callback) => ret,
         lateout("r1") _,
@@ -13491,6 +14166,10 @@ pub unsafe fn sd_radio_session_open(p_radio_signal_callback: nrf_radio_signal_ca
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(72);
+    }
     ret
 }
 
@@ -13505,8 +14184,10 @@ pub unsafe fn sd_radio_session_open(p_radio_signal_callback: nrf_radio_signal_ca
 #[doc = " @retval ::NRF_ERROR_BUSY If session is currently being closed."]
 #[doc = " @retval ::NRF_SUCCESS Otherwise."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_radio_session_close() -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 73",
         lateout("r0") ret,
         lateout("r1") _,
@@ -13514,6 +14195,10 @@ pub unsafe fn sd_radio_session_close() -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(73);
+    }
     ret
 }
 
@@ -13552,8 +14237,10 @@ pub unsafe fn sd_radio_session_close() -> u32 {
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM If the parameters of p_request are not valid."]
 #[doc = " @retval ::NRF_SUCCESS Otherwise."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_radio_request(p_request: *const nrf_radio_request_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 74",
         inout("r0") to_asm(p_request) => ret,
         lateout("r1") _,
@@ -13561,6 +14248,10 @@ pub unsafe fn sd_radio_request(p_request: *const nrf_radio_request_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(74);
+    }
     ret
 }
 
@@ -13583,8 +14274,10 @@ pub unsafe fn sd_radio_request(p_request: *const nrf_radio_request_t) -> u32 {
 #[doc = " @retval ::NRF_SUCCESS Value successfully written to register."]
 #[doc = ""]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_protected_register_write(p_register: *mut u32, value: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 43",
         inout("r0") to_asm(p_register) => ret,
         inout("r1") to_asm(value) => _,
@@ -13592,6 +14285,10 @@ pub unsafe fn sd_protected_register_write(p_register: *mut u32, value: u32) -> u
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(43);
+    }
     ret
 }
 
@@ -14031,8 +14728,10 @@ fn bindgen_test_layout_sd_mbr_command_t() {
 #[doc = " @retval ::NRF_ERROR_NO_MEM No MBR parameter page provided"]
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM if an invalid command is given."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_mbr_command(param: *mut sd_mbr_command_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 24",
         inout("r0") to_asm(param) => ret,
         lateout("r1") _,
@@ -14040,6 +14739,10 @@ pub unsafe fn sd_mbr_command(param: *mut sd_mbr_command_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(24);
+    }
     ret
 }
 
@@ -14195,11 +14898,13 @@ pub type nrf_fault_handler_t = ::core::option::Option<unsafe extern "C" fn(id: u
 #[doc = " @retval ::NRF_ERROR_SDM_LFCLK_SOURCE_UNKNOWN Unknown low frequency clock source selected."]
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM Invalid clock source configuration supplied in p_clock_lf_cfg."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_softdevice_enable(
     p_clock_lf_cfg: *const nrf_clock_lf_cfg_t,
     fault_handler: nrf_fault_handler_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 16",
         inout("r0") to_asm(p_clock_lf_cfg) => ret,
         inout("r1") to_asm(fault_handler) => _,
@@ -14207,6 +14912,10 @@ pub unsafe fn sd_softdevice_enable(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(16);
+    }
     ret
 }
 
@@ -14223,8 +14932,10 @@ pub unsafe fn sd_softdevice_enable(
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_softdevice_disable() -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 17",
         lateout("r0") ret,
         lateout("r1") _,
@@ -14232,6 +14943,10 @@ pub unsafe fn sd_softdevice_disable() -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(17);
+    }
     ret
 }
 
@@ -14241,8 +14956,10 @@ pub unsafe fn sd_softdevice_disable() -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_softdevice_is_enabled(p_softdevice_enabled: *mut u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 18",
         inout("r0") to_asm(p_softdevice_enabled) => ret,
         lateout("r1") _,
@@ -14250,6 +14967,10 @@ pub unsafe fn sd_softdevice_is_enabled(p_softdevice_enabled: *mut u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(18);
+    }
     ret
 }
 
@@ -14261,8 +14982,10 @@ pub unsafe fn sd_softdevice_is_enabled(p_softdevice_enabled: *mut u8) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_softdevice_vector_table_base_set(address: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 19",
         inout("r0") to_asm(address) => ret,
         lateout("r1") _,
@@ -14270,5 +14993,9 @@ pub unsafe fn sd_softdevice_vector_table_base_set(address: u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(19);
+    }
     ret
 }
@@ -132,6 +132,13 @@ impl<X, Y, Z, R> ToAsm for unsafe extern "C" fn(X, Y, Z) -> R {
     }
 }
 
+// SoftDevice calls can't be made off-target, but the bindings still build there so that
+// code using them can be unit tested on the host.
+#[cfg(not(target_arch = "arm"))]
+fn svc_unavailable(num: u32) -> u32 {
+    panic!("SoftDevice call svc {} is only available on ARM targets", num)
+}
+
 /* automatically generated by rust-bindgen 0.55.1 */
 
 #[repr(C)]
@@ -5811,8 +5818,10 @@ fn bindgen_test_layout_ble_gap_conn_event_trigger_t() {
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE The identity address cannot be changed while advertising,"]
 #[doc = "                                   scanning or creating a connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_addr_set(p_addr: *const ble_gap_addr_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 108",
         inout("r0") to_asm(p_addr) => ret,
         lateout("r1") _,
@@ -5820,6 +5829,10 @@ pub unsafe fn sd_ble_gap_addr_set(p_addr: *const ble_gap_addr_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(108);
+    }
     ret
 }
 
@@ -5833,8 +5846,10 @@ pub unsafe fn sd_ble_gap_addr_set(p_addr: *const ble_gap_addr_t) -> u32 {
 #[doc = " @retval ::NRF_SUCCESS Address successfully retrieved."]
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR Invalid or NULL pointer supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_addr_get(p_addr: *mut ble_gap_addr_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 109",
         inout("r0") to_asm(p_addr) => ret,
         lateout("r1") _,
@@ -5842,6 +5857,10 @@ pub unsafe fn sd_ble_gap_addr_get(p_addr: *mut ble_gap_addr_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(109);
+    }
     ret
 }
 
@@ -5861,8 +5880,10 @@ pub unsafe fn sd_ble_gap_addr_get(p_addr: *mut ble_gap_addr_t) -> u32 {
 #[doc = " @retval ::BLE_ERROR_INVALID_ADV_HANDLE The provided advertising handle was not found."]
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE      The advertising set is currently not advertising."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_adv_addr_get(adv_handle: u8, p_addr: *mut ble_gap_addr_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 147",
         inout("r0") to_asm(adv_handle) => ret,
         inout("r1") to_asm(p_addr) => _,
@@ -5870,6 +5891,10 @@ pub unsafe fn sd_ble_gap_adv_addr_get(adv_handle: u8, p_addr: *mut ble_gap_addr_
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(147);
+    }
     ret
 }
 
@@ -5896,8 +5921,10 @@ pub unsafe fn sd_ble_gap_adv_addr_get(adv_handle: u8, p_addr: *mut ble_gap_addr_
 #[doc = " @retval ::NRF_ERROR_DATA_SIZE The given whitelist size is invalid (zero or too large); this can only return when"]
 #[doc = "                               pp_wl_addrs is not NULL."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_whitelist_set(pp_wl_addrs: *const *const ble_gap_addr_t, len: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 110",
         inout("r0") to_asm(pp_wl_addrs) => ret,
         inout("r1") to_asm(len) => _,
@@ -5905,6 +5932,10 @@ pub unsafe fn sd_ble_gap_whitelist_set(pp_wl_addrs: *const *const ble_gap_addr_t
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(110);
+    }
     ret
 }
 
@@ -5936,12 +5967,14 @@ pub unsafe fn sd_ble_gap_whitelist_set(pp_wl_addrs: *const *const ble_gap_addr_t
 #[doc = " @retval ::NRF_ERROR_DATA_SIZE The given device identity list size invalid (zero or too large); this can"]
 #[doc = "                               only return when pp_id_keys is not NULL."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_device_identities_set(
     pp_id_keys: *const *const ble_gap_id_key_t,
     pp_local_irks: *const *const ble_gap_irk_t,
     len: u8,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 111",
         inout("r0") to_asm(pp_id_keys) => ret,
         inout("r1") to_asm(pp_local_irks) => _,
@@ -5949,6 +5982,10 @@ pub unsafe fn sd_ble_gap_device_identities_set(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(111);
+    }
     ret
 }
 
@@ -5977,8 +6014,10 @@ pub unsafe fn sd_ble_gap_device_identities_set(
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE Privacy settings cannot be changed while advertising, scanning"]
 #[doc = "                                   or creating a connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_privacy_set(p_privacy_params: *const ble_gap_privacy_params_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 112",
         inout("r0") to_asm(p_privacy_params) => ret,
         lateout("r1") _,
@@ -5986,6 +6025,10 @@ pub unsafe fn sd_ble_gap_privacy_set(p_privacy_params: *const ble_gap_privacy_pa
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(112);
+    }
     ret
 }
 
@@ -6000,8 +6043,10 @@ pub unsafe fn sd_ble_gap_privacy_set(p_privacy_params: *const ble_gap_privacy_pa
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR The pointer given for returning the privacy settings may be NULL or invalid."]
 #[doc = "                                  Otherwise, the p_device_irk pointer in privacy parameter is an invalid pointer."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_privacy_get(p_privacy_params: *mut ble_gap_privacy_params_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 113",
         inout("r0") to_asm(p_privacy_params) => ret,
         lateout("r1") _,
@@ -6009,6 +6054,10 @@ pub unsafe fn sd_ble_gap_privacy_get(p_privacy_params: *mut ble_gap_privacy_para
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(113);
+    }
     ret
 }
 
@@ -6056,12 +6105,14 @@ pub unsafe fn sd_ble_gap_privacy_get(p_privacy_params: *mut ble_gap_privacy_para
 #[doc = "                                                     existing advertising handle instead."]
 #[doc = " @retval ::BLE_ERROR_GAP_UUID_LIST_MISMATCH Invalid UUID list supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_adv_set_configure(
     p_adv_handle: *mut u8,
     p_adv_data: *const ble_gap_adv_data_t,
     p_adv_params: *const ble_gap_adv_params_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 114",
         inout("r0") to_asm(p_adv_handle) => ret,
         inout("r1") to_asm(p_adv_data) => _,
@@ -6069,6 +6120,10 @@ pub unsafe fn sd_ble_gap_adv_set_configure(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(114);
+    }
     ret
 }
 
@@ -6116,8 +6171,10 @@ pub unsafe fn sd_ble_gap_adv_set_configure(
 #[doc = "                                          the selected advertising phys. Use @ref sd_ble_cfg_set to increase the event length."]
 #[doc = " @retval ::NRF_ERROR_NOT_SUPPORTED Unsupported PHYs supplied to the call."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_adv_start(adv_handle: u8, conn_cfg_tag: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 115",
         inout("r0") to_asm(adv_handle) => ret,
         inout("r1") to_asm(conn_cfg_tag) => _,
@@ -6125,6 +6182,10 @@ pub unsafe fn sd_ble_gap_adv_start(adv_handle: u8, conn_cfg_tag: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(115);
+    }
     ret
 }
 
@@ -6141,8 +6202,10 @@ pub unsafe fn sd_ble_gap_adv_start(adv_handle: u8, conn_cfg_tag: u8) -> u32 {
 #[doc = " @retval ::BLE_ERROR_INVALID_ADV_HANDLE Invalid advertising handle."]
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE The advertising handle is not advertising."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_adv_stop(adv_handle: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 116",
         inout("r0") to_asm(adv_handle) => ret,
         lateout("r1") _,
@@ -6150,6 +6213,10 @@ pub unsafe fn sd_ble_gap_adv_stop(adv_handle: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(116);
+    }
     ret
 }
 
@@ -6187,8 +6254,10 @@ pub unsafe fn sd_ble_gap_adv_stop(adv_handle: u8) -> u32 {
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[doc = " @retval ::NRF_ERROR_NO_MEM Not enough memory to complete operation."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_conn_param_update(conn_handle: u16, p_conn_params: *const ble_gap_conn_params_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 117",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_conn_params) => _,
@@ -6196,6 +6265,10 @@ pub unsafe fn sd_ble_gap_conn_param_update(conn_handle: u16, p_conn_params: *con
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(117);
+    }
     ret
 }
 
@@ -6220,8 +6293,10 @@ pub unsafe fn sd_ble_gap_conn_param_update(conn_handle: u16, p_conn_params: *con
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE Disconnection in progress or link has not been established."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_disconnect(conn_handle: u16, hci_status_code: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 118",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(hci_status_code) => _,
@@ -6229,6 +6304,10 @@ pub unsafe fn sd_ble_gap_disconnect(conn_handle: u16, hci_status_code: u8) -> u3
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(118);
+    }
     ret
 }
 
@@ -6254,8 +6333,10 @@ pub unsafe fn sd_ble_gap_disconnect(conn_handle: u16, hci_status_code: u8) -> u3
 #[doc = " @retval ::BLE_ERROR_INVALID_ADV_HANDLE Advertising handle not found."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_tx_power_set(role: u8, handle: u16, tx_power: i8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 119",
         inout("r0") to_asm(role) => ret,
         inout("r1") to_asm(handle) => _,
@@ -6263,6 +6344,10 @@ pub unsafe fn sd_ble_gap_tx_power_set(role: u8, handle: u16, tx_power: i8) -> u3
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(119);
+    }
     ret
 }
 
@@ -6273,8 +6358,10 @@ pub unsafe fn sd_ble_gap_tx_power_set(role: u8, handle: u16, tx_power: i8) -> u3
 #[doc = " @retval ::NRF_SUCCESS  Appearance value set successfully."]
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM Invalid parameter(s) supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_appearance_set(appearance: u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 120",
         inout("r0") to_asm(appearance) => ret,
         lateout("r1") _,
@@ -6282,6 +6369,10 @@ pub unsafe fn sd_ble_gap_appearance_set(appearance: u16) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(120);
+    }
     ret
 }
 
@@ -6292,8 +6383,10 @@ pub unsafe fn sd_ble_gap_appearance_set(appearance: u16) -> u32 {
 #[doc = " @retval ::NRF_SUCCESS Appearance value retrieved successfully."]
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR Invalid pointer supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_appearance_get(p_appearance: *mut u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 121",
         inout("r0") to_asm(p_appearance) => ret,
         lateout("r1") _,
@@ -6301,6 +6394,10 @@ pub unsafe fn sd_ble_gap_appearance_get(p_appearance: *mut u16) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(121);
+    }
     ret
 }
 
@@ -6314,8 +6411,10 @@ pub unsafe fn sd_ble_gap_appearance_get(p_appearance: *mut u16) -> u32 {
 #[doc = " @retval ::NRF_ERROR_NOT_SUPPORTED The characteristic is not included in the Attribute Table,"]
 #[doc = "see @ref ble_gap_cfg_ppcp_incl_cfg_t."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_ppcp_set(p_conn_params: *const ble_gap_conn_params_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 122",
         inout("r0") to_asm(p_conn_params) => ret,
         lateout("r1") _,
@@ -6323,6 +6422,10 @@ pub unsafe fn sd_ble_gap_ppcp_set(p_conn_params: *const ble_gap_conn_params_t) -
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(122);
+    }
     ret
 }
 
@@ -6335,8 +6438,10 @@ pub unsafe fn sd_ble_gap_ppcp_set(p_conn_params: *const ble_gap_conn_params_t) -
 #[doc = " @retval ::NRF_ERROR_NOT_SUPPORTED The characteristic is not included in the Attribute Table,"]
 #[doc = "see @ref ble_gap_cfg_ppcp_incl_cfg_t."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_ppcp_get(p_conn_params: *mut ble_gap_conn_params_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 123",
         inout("r0") to_asm(p_conn_params) => ret,
         lateout("r1") _,
@@ -6344,6 +6449,10 @@ pub unsafe fn sd_ble_gap_ppcp_get(p_conn_params: *mut ble_gap_conn_params_t) ->
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(123);
+    }
     ret
 }
 
@@ -6362,12 +6471,14 @@ pub unsafe fn sd_ble_gap_ppcp_get(p_conn_params: *mut ble_gap_conn_params_t) ->
 #[doc = " @retval ::NRF_ERROR_DATA_SIZE Invalid data size(s) supplied."]
 #[doc = " @retval ::NRF_ERROR_FORBIDDEN Device name is not writable."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_device_name_set(
     p_write_perm: *const ble_gap_conn_sec_mode_t,
     p_dev_name: *const u8,
     len: u16,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 124",
         inout("r0") to_asm(p_write_perm) => ret,
         inout("r1") to_asm(p_dev_name) => _,
@@ -6375,6 +6486,10 @@ pub unsafe fn sd_ble_gap_device_name_set(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(124);
+    }
     ret
 }
 
@@ -6392,8 +6507,10 @@ pub unsafe fn sd_ble_gap_device_name_set(
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR Invalid pointer supplied."]
 #[doc = " @retval ::NRF_ERROR_DATA_SIZE Invalid data size(s) supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_device_name_get(p_dev_name: *mut u8, p_len: *mut u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 125",
         inout("r0") to_asm(p_dev_name) => ret,
         inout("r1") to_asm(p_len) => _,
@@ -6401,6 +6518,10 @@ pub unsafe fn sd_ble_gap_device_name_get(p_dev_name: *mut u8, p_len: *mut u16) -
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(125);
+    }
     ret
 }
 
@@ -6457,8 +6578,10 @@ pub unsafe fn sd_ble_gap_device_name_get(p_dev_name: *mut u8, p_len: *mut u16) -
 #[doc = "                                   See @ref ble_gap_cfg_car_incl_cfg_t and @ref ble_gap_cfg_role_count_t."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT A SMP timeout has occurred, and further SMP operations on this link is prohibited."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_authenticate(conn_handle: u16, p_sec_params: *const ble_gap_sec_params_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 126",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_sec_params) => _,
@@ -6466,6 +6589,10 @@ pub unsafe fn sd_ble_gap_authenticate(conn_handle: u16, p_sec_params: *const ble
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(126);
+    }
     ret
 }
 
@@ -6529,6 +6656,7 @@ pub unsafe fn sd_ble_gap_authenticate(conn_handle: u16, p_sec_params: *const ble
 #[doc = "                                   the Softdevice is configured to support peripheral roles only."]
 #[doc = "                                   See @ref ble_gap_cfg_car_incl_cfg_t and @ref ble_gap_cfg_role_count_t."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_sec_params_reply(
     conn_handle: u16,
     sec_status: u8,
@@ -6536,6 +6664,7 @@ pub unsafe fn sd_ble_gap_sec_params_reply(
     p_sec_keyset: *const ble_gap_sec_keyset_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 127",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(sec_status) => _,
@@ -6543,6 +6672,10 @@ pub unsafe fn sd_ble_gap_sec_params_reply(
         inout("r3") to_asm(p_sec_keyset) => _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(127);
+    }
     ret
 }
 
@@ -6577,8 +6710,10 @@ pub unsafe fn sd_ble_gap_sec_params_reply(
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE Authentication key has not been requested."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_auth_key_reply(conn_handle: u16, key_type: u8, p_key: *const u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 128",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(key_type) => _,
@@ -6586,6 +6721,10 @@ pub unsafe fn sd_ble_gap_auth_key_reply(conn_handle: u16, key_type: u8, p_key: *
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(128);
+    }
     ret
 }
 
@@ -6622,8 +6761,10 @@ pub unsafe fn sd_ble_gap_auth_key_reply(conn_handle: u16, key_type: u8, p_key: *
 #[doc = "                                   - The application has not pulled a @ref BLE_GAP_EVT_LESC_DHKEY_REQUEST event."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_lesc_dhkey_reply(conn_handle: u16, p_dhkey: *const ble_gap_lesc_dhkey_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 129",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_dhkey) => _,
@@ -6631,6 +6772,10 @@ pub unsafe fn sd_ble_gap_lesc_dhkey_reply(conn_handle: u16, p_dhkey: *const ble_
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(129);
+    }
     ret
 }
 
@@ -6653,8 +6798,10 @@ pub unsafe fn sd_ble_gap_lesc_dhkey_reply(conn_handle: u16, p_dhkey: *const ble_
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[doc = " @retval ::NRF_ERROR_BUSY The BLE stack is busy. Retry at later time."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_keypress_notify(conn_handle: u16, kp_not: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 130",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(kp_not) => _,
@@ -6662,6 +6809,10 @@ pub unsafe fn sd_ble_gap_keypress_notify(conn_handle: u16, kp_not: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(130);
+    }
     ret
 }
 
@@ -6683,12 +6834,14 @@ pub unsafe fn sd_ble_gap_keypress_notify(conn_handle: u16, kp_not: u8) -> u32 {
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR Invalid pointer supplied."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_lesc_oob_data_get(
     conn_handle: u16,
     p_pk_own: *const ble_gap_lesc_p256_pk_t,
     p_oobd_own: *mut ble_gap_lesc_oob_data_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 131",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_pk_own) => _,
@@ -6696,6 +6849,10 @@ pub unsafe fn sd_ble_gap_lesc_oob_data_get(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(131);
+    }
     ret
 }
 
@@ -6729,12 +6886,14 @@ pub unsafe fn sd_ble_gap_lesc_oob_data_get(
 #[doc = "                                   - Have not actually exchanged passkeys."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_lesc_oob_data_set(
     conn_handle: u16,
     p_oobd_own: *const ble_gap_lesc_oob_data_t,
     p_oobd_peer: *const ble_gap_lesc_oob_data_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 132",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_oobd_own) => _,
@@ -6742,6 +6901,10 @@ pub unsafe fn sd_ble_gap_lesc_oob_data_set(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(132);
+    }
     ret
 }
 
@@ -6771,12 +6934,14 @@ pub unsafe fn sd_ble_gap_lesc_oob_data_set(
 #[doc = " @retval ::BLE_ERROR_INVALID_ROLE Operation is not supported in the Peripheral role."]
 #[doc = " @retval ::NRF_ERROR_BUSY Procedure already in progress or not allowed at this time, wait for pending procedures to complete and retry."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_encrypt(
     conn_handle: u16,
     p_master_id: *const ble_gap_master_id_t,
     p_enc_info: *const ble_gap_enc_info_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 133",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_master_id) => _,
@@ -6784,6 +6949,10 @@ pub unsafe fn sd_ble_gap_encrypt(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(133);
+    }
     ret
 }
 
@@ -6810,6 +6979,7 @@ pub unsafe fn sd_ble_gap_encrypt(
 #[doc = "                                   - Encryption information provided by the app without being requested. See @ref ble_gap_evt_sec_info_request_t::enc_info."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_sec_info_reply(
     conn_handle: u16,
     p_enc_info: *const ble_gap_enc_info_t,
@@ -6817,6 +6987,7 @@ pub unsafe fn sd_ble_gap_sec_info_reply(
     p_sign_info: *const ble_gap_sign_info_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 134",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_enc_info) => _,
@@ -6824,6 +6995,10 @@ pub unsafe fn sd_ble_gap_sec_info_reply(
         inout("r3") to_asm(p_sign_info) => _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(134);
+    }
     ret
 }
 
@@ -6836,8 +7011,10 @@ pub unsafe fn sd_ble_gap_sec_info_reply(
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR Invalid pointer supplied."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_conn_sec_get(conn_handle: u16, p_conn_sec: *mut ble_gap_conn_sec_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 135",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_conn_sec) => _,
@@ -6845,6 +7022,10 @@ pub unsafe fn sd_ble_gap_conn_sec_get(conn_handle: u16, p_conn_sec: *mut ble_gap
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(135);
+    }
     ret
 }
 
@@ -6871,8 +7052,10 @@ pub unsafe fn sd_ble_gap_conn_sec_get(conn_handle: u16, p_conn_sec: *mut ble_gap
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE       RSSI reporting is already ongoing."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_rssi_start(conn_handle: u16, threshold_dbm: u8, skip_count: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 136",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(threshold_dbm) => _,
@@ -6880,6 +7063,10 @@ pub unsafe fn sd_ble_gap_rssi_start(conn_handle: u16, threshold_dbm: u8, skip_co
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(136);
+    }
     ret
 }
 
@@ -6899,8 +7086,10 @@ pub unsafe fn sd_ble_gap_rssi_start(conn_handle: u16, threshold_dbm: u8, skip_co
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE       RSSI reporting is not ongoing."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_rssi_stop(conn_handle: u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 137",
         inout("r0") to_asm(conn_handle) => ret,
         lateout("r1") _,
@@ -6908,6 +7097,10 @@ pub unsafe fn sd_ble_gap_rssi_stop(conn_handle: u16) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(137);
+    }
     ret
 }
 
@@ -6929,8 +7122,10 @@ pub unsafe fn sd_ble_gap_rssi_stop(conn_handle: u16) -> u32 {
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE       RSSI reporting is not ongoing."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_rssi_get(conn_handle: u16, p_rssi: *mut i8, p_ch_index: *mut u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 142",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_rssi) => _,
@@ -6938,6 +7133,10 @@ pub unsafe fn sd_ble_gap_rssi_get(conn_handle: u16, p_rssi: *mut i8, p_ch_index:
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(142);
+    }
     ret
 }
 
@@ -6992,11 +7191,13 @@ pub unsafe fn sd_ble_gap_rssi_get(conn_handle: u16, p_rssi: *mut i8, p_ch_index:
 #[doc = "                               Stop one or more currently active roles (Central, Peripheral or Broadcaster) and try again"]
 #[doc = " @retval ::NRF_ERROR_NOT_SUPPORTED Unsupported PHYs supplied to the call."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_scan_start(
     p_scan_params: *const ble_gap_scan_params_t,
     p_adv_report_buffer: *const ble_data_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 138",
         inout("r0") to_asm(p_scan_params) => ret,
         inout("r1") to_asm(p_adv_report_buffer) => _,
@@ -7004,6 +7205,10 @@ pub unsafe fn sd_ble_gap_scan_start(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(138);
+    }
     ret
 }
 
@@ -7019,8 +7224,10 @@ pub unsafe fn sd_ble_gap_scan_start(
 #[doc = " @retval ::NRF_SUCCESS Successfully stopped scanning procedure."]
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE Not in the scanning state."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_scan_stop() -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 139",
         lateout("r0") ret,
         lateout("r1") _,
@@ -7028,6 +7235,10 @@ pub unsafe fn sd_ble_gap_scan_stop() -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(139);
+    }
     ret
 }
 
@@ -7075,6 +7286,7 @@ pub unsafe fn sd_ble_gap_scan_stop() -> u32 {
 #[doc = "                                   Use @ref sd_ble_cfg_set to increase the event length."]
 #[doc = " @retval ::NRF_ERROR_NOT_SUPPORTED Unsupported PHYs supplied to the call."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_connect(
     p_peer_addr: *const ble_gap_addr_t,
     p_scan_params: *const ble_gap_scan_params_t,
@@ -7082,6 +7294,7 @@ pub unsafe fn sd_ble_gap_connect(
     conn_cfg_tag: u8,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 140",
         inout("r0") to_asm(p_peer_addr) => ret,
         inout("r1") to_asm(p_scan_params) => _,
@@ -7089,6 +7302,10 @@ pub unsafe fn sd_ble_gap_connect(
         inout("r3") to_asm(conn_cfg_tag) => _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(140);
+    }
     ret
 }
 
@@ -7102,8 +7319,10 @@ pub unsafe fn sd_ble_gap_connect(
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE No locally initiated connect procedure started or connection"]
 #[doc = "                                   completed occurred."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_connect_cancel() -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 141",
         lateout("r0") ret,
         lateout("r1") _,
@@ -7111,6 +7330,10 @@ pub unsafe fn sd_ble_gap_connect_cancel() -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(141);
+    }
     ret
 }
 
@@ -7165,8 +7388,10 @@ pub unsafe fn sd_ble_gap_connect_cancel() -> u32 {
 #[doc = " @retval ::NRF_ERROR_BUSY Procedure is already in progress or not allowed at this time. Process pending events and wait for the pending procedure to complete and retry."]
 #[doc = ""]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_phy_update(conn_handle: u16, p_gap_phys: *const ble_gap_phys_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 143",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_gap_phys) => _,
@@ -7174,6 +7399,10 @@ pub unsafe fn sd_ble_gap_phy_update(conn_handle: u16, p_gap_phys: *const ble_gap
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(143);
+    }
     ret
 }
 
@@ -7209,12 +7438,14 @@ pub unsafe fn sd_ble_gap_phy_update(conn_handle: u16, p_gap_phys: *const ble_gap
 #[doc = " @retval ::NRF_ERROR_BUSY Peer has already initiated a Data Length Update Procedure. Process the"]
 #[doc = "                          pending @ref BLE_GAP_EVT_DATA_LENGTH_UPDATE_REQUEST event to respond."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_data_length_update(
     conn_handle: u16,
     p_dl_params: *const ble_gap_data_length_params_t,
     p_dl_limitation: *mut ble_gap_data_length_limitation_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 144",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_dl_params) => _,
@@ -7222,6 +7453,10 @@ pub unsafe fn sd_ble_gap_data_length_update(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(144);
+    }
     ret
 }
 
@@ -7255,8 +7490,10 @@ pub unsafe fn sd_ble_gap_data_length_update(
 #[doc = "                                   Set @ref ble_gap_cfg_role_count_t::qos_channel_survey_role_available using"]
 #[doc = "                                   @ref sd_ble_cfg_set."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_qos_channel_survey_start(interval_us: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 145",
         inout("r0") to_asm(interval_us) => ret,
         lateout("r1") _,
@@ -7264,6 +7501,10 @@ pub unsafe fn sd_ble_gap_qos_channel_survey_start(interval_us: u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(145);
+    }
     ret
 }
 
@@ -7275,8 +7516,10 @@ pub unsafe fn sd_ble_gap_qos_channel_survey_start(interval_us: u32) -> u32 {
 #[doc = " @retval ::NRF_SUCCESS             The module is successfully stopped."]
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE Trying to stop the module when it is not running."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_qos_channel_survey_stop() -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 146",
         lateout("r0") ret,
         lateout("r1") _,
@@ -7284,6 +7527,10 @@ pub unsafe fn sd_ble_gap_qos_channel_survey_stop() -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(146);
+    }
     ret
 }
 
@@ -7308,8 +7555,10 @@ pub unsafe fn sd_ble_gap_qos_channel_survey_stop() -> u32 {
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle parameter supplied."]
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR        Invalid pointer supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_next_conn_evt_counter_get(conn_handle: u16, p_counter: *mut u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 148",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_counter) => _,
@@ -7317,6 +7566,10 @@ pub unsafe fn sd_ble_gap_next_conn_evt_counter_get(conn_handle: u16, p_counter:
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(148);
+    }
     ret
 }
 
@@ -7339,11 +7592,13 @@ pub unsafe fn sd_ble_gap_next_conn_evt_counter_get(conn_handle: u16, p_counter:
 #[doc = "                                           Use @ref sd_ble_gap_next_conn_evt_counter_get to find a new value"]
 #[doc = "to be used as ble_gap_conn_event_trigger_t::conn_evt_counter_start."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_conn_evt_trigger_start(
     conn_handle: u16,
     p_params: *const ble_gap_conn_event_trigger_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 149",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_params) => _,
@@ -7351,6 +7606,10 @@ pub unsafe fn sd_ble_gap_conn_evt_trigger_start(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(149);
+    }
     ret
 }
 
@@ -7362,8 +7621,10 @@ pub unsafe fn sd_ble_gap_conn_evt_trigger_start(
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied."]
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE       Trying to stop connection event triggering when it is not enabled."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gap_conn_evt_trigger_stop(conn_handle: u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 150",
         inout("r0") to_asm(conn_handle) => ret,
         lateout("r1") _,
@@ -7371,6 +7632,10 @@ pub unsafe fn sd_ble_gap_conn_evt_trigger_stop(conn_handle: u16) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(150);
+    }
     ret
 }
 
@@ -8150,12 +8415,14 @@ fn bindgen_test_layout_ble_l2cap_evt_t() {
 #[doc = " @retval ::NRF_ERROR_RESOURCES            The limit has been reached for available L2CAP channels,"]
 #[doc = "                                          see @ref ble_l2cap_conn_cfg_t::ch_count."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_l2cap_ch_setup(
     conn_handle: u16,
     p_local_cid: *mut u16,
     p_params: *const ble_l2cap_ch_setup_params_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 184",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_local_cid) => _,
@@ -8163,6 +8430,10 @@ pub unsafe fn sd_ble_l2cap_ch_setup(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(184);
+    }
     ret
 }
 
@@ -8187,8 +8458,10 @@ pub unsafe fn sd_ble_l2cap_ch_setup(
 #[doc = "                                          in progress for the L2CAP channel)."]
 #[doc = " @retval ::NRF_ERROR_NOT_FOUND            CID not found."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_l2cap_ch_release(conn_handle: u16, local_cid: u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 185",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(local_cid) => _,
@@ -8196,6 +8469,10 @@ pub unsafe fn sd_ble_l2cap_ch_release(conn_handle: u16, local_cid: u16) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(185);
+    }
     ret
 }
 
@@ -8231,8 +8508,10 @@ pub unsafe fn sd_ble_l2cap_ch_release(conn_handle: u16, local_cid: u16) -> u32 {
 #[doc = " @retval ::NRF_ERROR_RESOURCES            Too many SDU data buffers supplied. Wait for a"]
 #[doc = "                                          @ref BLE_L2CAP_EVT_CH_RX event and retry."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_l2cap_ch_rx(conn_handle: u16, local_cid: u16, p_sdu_buf: *const ble_data_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 186",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(local_cid) => _,
@@ -8240,6 +8519,10 @@ pub unsafe fn sd_ble_l2cap_ch_rx(conn_handle: u16, local_cid: u16, p_sdu_buf: *c
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(186);
+    }
     ret
 }
 
@@ -8286,8 +8569,10 @@ pub unsafe fn sd_ble_l2cap_ch_rx(conn_handle: u16, local_cid: u16, p_sdu_buf: *c
 #[doc = " @retval ::NRF_ERROR_RESOURCES            Too many SDUs queued for transmission. Wait for a"]
 #[doc = "                                          @ref BLE_L2CAP_EVT_CH_TX event and retry."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_l2cap_ch_tx(conn_handle: u16, local_cid: u16, p_sdu_buf: *const ble_data_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 187",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(local_cid) => _,
@@ -8295,6 +8580,10 @@ pub unsafe fn sd_ble_l2cap_ch_tx(conn_handle: u16, local_cid: u16, p_sdu_buf: *c
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(187);
+    }
     ret
 }
 
@@ -8340,8 +8629,10 @@ pub unsafe fn sd_ble_l2cap_ch_tx(conn_handle: u16, local_cid: u16, p_sdu_buf: *c
 #[doc = "                                          in progress for an L2CAP channel)."]
 #[doc = " @retval ::NRF_ERROR_NOT_FOUND            CID not found."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_l2cap_ch_flow_control(conn_handle: u16, local_cid: u16, credits: u16, p_credits: *mut u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 188",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(local_cid) => _,
@@ -8349,6 +8640,10 @@ pub unsafe fn sd_ble_l2cap_ch_flow_control(conn_handle: u16, local_cid: u16, cre
         inout("r3") to_asm(p_credits) => _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(188);
+    }
     ret
 }
 
@@ -10098,12 +10393,14 @@ fn bindgen_test_layout_ble_gattc_evt_t() {
 #[doc = " @retval ::NRF_ERROR_BUSY Client procedure already in progress."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_primary_services_discover(
     conn_handle: u16,
     start_handle: u16,
     p_srvc_uuid: *const ble_uuid_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 155",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(start_handle) => _,
@@ -10111,6 +10408,10 @@ pub unsafe fn sd_ble_gattc_primary_services_discover(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(155);
+    }
     ret
 }
 
@@ -10138,11 +10439,13 @@ pub unsafe fn sd_ble_gattc_primary_services_discover(
 #[doc = " @retval ::NRF_ERROR_BUSY Client procedure already in progress."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_relationships_discover(
     conn_handle: u16,
     p_handle_range: *const ble_gattc_handle_range_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 156",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_handle_range) => _,
@@ -10150,6 +10453,10 @@ pub unsafe fn sd_ble_gattc_relationships_discover(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(156);
+    }
     ret
 }
 
@@ -10179,11 +10486,13 @@ pub unsafe fn sd_ble_gattc_relationships_discover(
 #[doc = " @retval ::NRF_ERROR_BUSY Client procedure already in progress."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_characteristics_discover(
     conn_handle: u16,
     p_handle_range: *const ble_gattc_handle_range_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 157",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_handle_range) => _,
@@ -10191,6 +10500,10 @@ pub unsafe fn sd_ble_gattc_characteristics_discover(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(157);
+    }
     ret
 }
 
@@ -10217,11 +10530,13 @@ pub unsafe fn sd_ble_gattc_characteristics_discover(
 #[doc = " @retval ::NRF_ERROR_BUSY Client procedure already in progress."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_descriptors_discover(
     conn_handle: u16,
     p_handle_range: *const ble_gattc_handle_range_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 158",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_handle_range) => _,
@@ -10229,6 +10544,10 @@ pub unsafe fn sd_ble_gattc_descriptors_discover(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(158);
+    }
     ret
 }
 
@@ -10256,12 +10575,14 @@ pub unsafe fn sd_ble_gattc_descriptors_discover(
 #[doc = " @retval ::NRF_ERROR_BUSY Client procedure already in progress."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_char_value_by_uuid_read(
     conn_handle: u16,
     p_uuid: *const ble_uuid_t,
     p_handle_range: *const ble_gattc_handle_range_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 160",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_uuid) => _,
@@ -10269,6 +10590,10 @@ pub unsafe fn sd_ble_gattc_char_value_by_uuid_read(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(160);
+    }
     ret
 }
 
@@ -10296,8 +10621,10 @@ pub unsafe fn sd_ble_gattc_char_value_by_uuid_read(
 #[doc = " @retval ::NRF_ERROR_BUSY Client procedure already in progress."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_read(conn_handle: u16, handle: u16, offset: u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 161",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(handle) => _,
@@ -10305,6 +10632,10 @@ pub unsafe fn sd_ble_gattc_read(conn_handle: u16, handle: u16, offset: u16) -> u
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(161);
+    }
     ret
 }
 
@@ -10331,8 +10662,10 @@ pub unsafe fn sd_ble_gattc_read(conn_handle: u16, handle: u16, offset: u16) -> u
 #[doc = " @retval ::NRF_ERROR_BUSY Client procedure already in progress."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_char_values_read(conn_handle: u16, p_handles: *const u16, handle_count: u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 162",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_handles) => _,
@@ -10340,6 +10673,10 @@ pub unsafe fn sd_ble_gattc_char_values_read(conn_handle: u16, p_handles: *const
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(162);
+    }
     ret
 }
 
@@ -10387,8 +10724,10 @@ pub unsafe fn sd_ble_gattc_char_values_read(conn_handle: u16, p_handles: *const
 #[doc = "                               Wait for a @ref BLE_GATTC_EVT_WRITE_CMD_TX_COMPLETE event and retry."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_write(conn_handle: u16, p_write_params: *const ble_gattc_write_params_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 163",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_write_params) => _,
@@ -10396,6 +10735,10 @@ pub unsafe fn sd_ble_gattc_write(conn_handle: u16, p_write_params: *const ble_ga
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(163);
+    }
     ret
 }
 
@@ -10414,8 +10757,10 @@ pub unsafe fn sd_ble_gattc_write(conn_handle: u16, p_write_params: *const ble_ga
 #[doc = " @retval ::BLE_ERROR_INVALID_ATTR_HANDLE Invalid attribute handle."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_hv_confirm(conn_handle: u16, handle: u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 164",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(handle) => _,
@@ -10423,6 +10768,10 @@ pub unsafe fn sd_ble_gattc_hv_confirm(conn_handle: u16, handle: u16) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(164);
+    }
     ret
 }
 
@@ -10442,11 +10791,13 @@ pub unsafe fn sd_ble_gattc_hv_confirm(conn_handle: u16, handle: u16) -> u32 {
 #[doc = " @retval ::NRF_ERROR_BUSY Client procedure already in progress."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_attr_info_discover(
     conn_handle: u16,
     p_handle_range: *const ble_gattc_handle_range_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 159",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_handle_range) => _,
@@ -10454,6 +10805,10 @@ pub unsafe fn sd_ble_gattc_attr_info_discover(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(159);
+    }
     ret
 }
 
@@ -10488,8 +10843,10 @@ pub unsafe fn sd_ble_gattc_attr_info_discover(
 #[doc = " @retval ::NRF_ERROR_BUSY Client procedure already in progress."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gattc_exchange_mtu_request(conn_handle: u16, client_rx_mtu: u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 165",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(client_rx_mtu) => _,
@@ -10497,6 +10854,10 @@ pub unsafe fn sd_ble_gattc_exchange_mtu_request(conn_handle: u16, client_rx_mtu:
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(165);
+    }
     ret
 }
 
@@ -12056,8 +12417,10 @@ fn bindgen_test_layout_ble_gatts_evt_t() {
 #[doc = " @retval ::NRF_ERROR_FORBIDDEN Forbidden value supplied, certain UUIDs are reserved for the stack."]
 #[doc = " @retval ::NRF_ERROR_NO_MEM Not enough memory to complete operation."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_service_add(type_: u8, p_uuid: *const ble_uuid_t, p_handle: *mut u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 168",
         inout("r0") to_asm(type_) => ret,
         inout("r1") to_asm(p_uuid) => _,
@@ -12065,6 +12428,10 @@ pub unsafe fn sd_ble_gatts_service_add(type_: u8, p_uuid: *const ble_uuid_t, p_h
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(168);
+    }
     ret
 }
 
@@ -12091,8 +12458,10 @@ pub unsafe fn sd_ble_gatts_service_add(type_: u8, p_uuid: *const ble_uuid_t, p_h
 #[doc = " @retval ::NRF_ERROR_NO_MEM Not enough memory to complete operation."]
 #[doc = " @retval ::NRF_ERROR_NOT_FOUND Attribute not found."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_include_add(service_handle: u16, inc_srvc_handle: u16, p_include_handle: *mut u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 169",
         inout("r0") to_asm(service_handle) => ret,
         inout("r1") to_asm(inc_srvc_handle) => _,
@@ -12100,6 +12469,10 @@ pub unsafe fn sd_ble_gatts_include_add(service_handle: u16, inc_srvc_handle: u16
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(169);
+    }
     ret
 }
 
@@ -12129,6 +12502,7 @@ pub unsafe fn sd_ble_gatts_include_add(service_handle: u16, inc_srvc_handle: u16
 #[doc = " @retval ::NRF_ERROR_NO_MEM Not enough memory to complete operation."]
 #[doc = " @retval ::NRF_ERROR_DATA_SIZE Invalid data size(s) supplied, attribute lengths are restricted by @ref BLE_GATTS_ATTR_LENS_MAX."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_characteristic_add(
     service_handle: u16,
     p_char_md: *const ble_gatts_char_md_t,
@@ -12136,6 +12510,7 @@ pub unsafe fn sd_ble_gatts_characteristic_add(
     p_handles: *mut ble_gatts_char_handles_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 170",
         inout("r0") to_asm(service_handle) => ret,
         inout("r1") to_asm(p_char_md) => _,
@@ -12143,6 +12518,10 @@ pub unsafe fn sd_ble_gatts_characteristic_add(
         inout("r3") to_asm(p_handles) => _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(170);
+    }
     ret
 }
 
@@ -12166,12 +12545,14 @@ pub unsafe fn sd_ble_gatts_characteristic_add(
 #[doc = " @retval ::NRF_ERROR_NO_MEM Not enough memory to complete operation."]
 #[doc = " @retval ::NRF_ERROR_DATA_SIZE Invalid data size(s) supplied, attribute lengths are restricted by @ref BLE_GATTS_ATTR_LENS_MAX."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_descriptor_add(
     char_handle: u16,
     p_attr: *const ble_gatts_attr_t,
     p_handle: *mut u16,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 171",
         inout("r0") to_asm(char_handle) => ret,
         inout("r1") to_asm(p_attr) => _,
@@ -12179,6 +12560,10 @@ pub unsafe fn sd_ble_gatts_descriptor_add(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(171);
+    }
     ret
 }
 
@@ -12203,8 +12588,10 @@ pub unsafe fn sd_ble_gatts_descriptor_add(
 #[doc = " @retval ::NRF_ERROR_DATA_SIZE Invalid data size(s) supplied, attribute lengths are restricted by @ref BLE_GATTS_ATTR_LENS_MAX."]
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied on a system attribute."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_value_set(conn_handle: u16, handle: u16, p_value: *mut ble_gatts_value_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 172",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(handle) => _,
@@ -12212,6 +12599,10 @@ pub unsafe fn sd_ble_gatts_value_set(conn_handle: u16, handle: u16, p_value: *mu
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(172);
+    }
     ret
 }
 
@@ -12237,8 +12628,10 @@ pub unsafe fn sd_ble_gatts_value_set(conn_handle: u16, handle: u16, p_value: *mu
 #[doc = " @retval ::BLE_ERROR_INVALID_CONN_HANDLE Invalid connection handle supplied on a system attribute."]
 #[doc = " @retval ::BLE_ERROR_GATTS_SYS_ATTR_MISSING System attributes missing, use @ref sd_ble_gatts_sys_attr_set to set them to a known value."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_value_get(conn_handle: u16, handle: u16, p_value: *mut ble_gatts_value_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 173",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(handle) => _,
@@ -12246,6 +12639,10 @@ pub unsafe fn sd_ble_gatts_value_get(conn_handle: u16, handle: u16, p_value: *mu
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(173);
+    }
     ret
 }
 
@@ -12312,8 +12709,10 @@ pub unsafe fn sd_ble_gatts_value_get(conn_handle: u16, handle: u16, p_value: *mu
 #[doc = "                               Wait for a @ref BLE_GATTS_EVT_HVN_TX_COMPLETE event and retry."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_hvx(conn_handle: u16, p_hvx_params: *const ble_gatts_hvx_params_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 174",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_hvx_params) => _,
@@ -12321,6 +12720,10 @@ pub unsafe fn sd_ble_gatts_hvx(conn_handle: u16, p_hvx_params: *const ble_gatts_
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(174);
+    }
     ret
 }
 
@@ -12358,8 +12761,10 @@ pub unsafe fn sd_ble_gatts_hvx(conn_handle: u16, p_hvx_params: *const ble_gatts_
 #[doc = " @retval ::BLE_ERROR_GATTS_SYS_ATTR_MISSING System attributes missing, use @ref sd_ble_gatts_sys_attr_set to set them to a known value."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_service_changed(conn_handle: u16, start_handle: u16, end_handle: u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 175",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(start_handle) => _,
@@ -12367,6 +12772,10 @@ pub unsafe fn sd_ble_gatts_service_changed(conn_handle: u16, start_handle: u16,
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(175);
+    }
     ret
 }
 
@@ -12401,11 +12810,13 @@ pub unsafe fn sd_ble_gatts_service_changed(conn_handle: u16, start_handle: u16,
 #[doc = "                                         or invalid data to be written provided by the application."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_rw_authorize_reply(
     conn_handle: u16,
     p_rw_authorize_reply_params: *const ble_gatts_rw_authorize_reply_params_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 176",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_rw_authorize_reply_params) => _,
@@ -12413,6 +12824,10 @@ pub unsafe fn sd_ble_gatts_rw_authorize_reply(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(176);
+    }
     ret
 }
 
@@ -12457,8 +12872,10 @@ pub unsafe fn sd_ble_gatts_rw_authorize_reply(
 #[doc = " @retval ::NRF_ERROR_INVALID_DATA Invalid data supplied, the data should be exactly the same as retrieved with @ref sd_ble_gatts_sys_attr_get."]
 #[doc = " @retval ::NRF_ERROR_NO_MEM Not enough memory to complete operation."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_sys_attr_set(conn_handle: u16, p_sys_attr_data: *const u8, len: u16, flags: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 177",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_sys_attr_data) => _,
@@ -12466,6 +12883,10 @@ pub unsafe fn sd_ble_gatts_sys_attr_set(conn_handle: u16, p_sys_attr_data: *cons
         inout("r3") to_asm(flags) => _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(177);
+    }
     ret
 }
 
@@ -12499,6 +12920,7 @@ pub unsafe fn sd_ble_gatts_sys_attr_set(conn_handle: u16, p_sys_attr_data: *cons
 #[doc = " @retval ::NRF_ERROR_DATA_SIZE The system attribute information did not fit into the provided buffer."]
 #[doc = " @retval ::NRF_ERROR_NOT_FOUND No system attributes found."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_sys_attr_get(
     conn_handle: u16,
     p_sys_attr_data: *mut u8,
@@ -12506,6 +12928,7 @@ pub unsafe fn sd_ble_gatts_sys_attr_get(
     flags: u32,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 178",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_sys_attr_data) => _,
@@ -12513,6 +12936,10 @@ pub unsafe fn sd_ble_gatts_sys_attr_get(
         inout("r3") to_asm(flags) => _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(178);
+    }
     ret
 }
 
@@ -12523,8 +12950,10 @@ pub unsafe fn sd_ble_gatts_sys_attr_get(
 #[doc = " @retval ::NRF_SUCCESS Successfully retrieved the handle."]
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR Invalid pointer supplied."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_initial_user_handle_get(p_handle: *mut u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 179",
         inout("r0") to_asm(p_handle) => ret,
         lateout("r1") _,
@@ -12532,6 +12961,10 @@ pub unsafe fn sd_ble_gatts_initial_user_handle_get(p_handle: *mut u16) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(179);
+    }
     ret
 }
 
@@ -12546,8 +12979,10 @@ pub unsafe fn sd_ble_gatts_initial_user_handle_get(p_handle: *mut u16) -> u32 {
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM Invalid parameters supplied. Returned when both @c p_uuid and @c p_md are NULL."]
 #[doc = " @retval ::NRF_ERROR_NOT_FOUND Attribute was not found."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_attr_get(handle: u16, p_uuid: *mut ble_uuid_t, p_md: *mut ble_gatts_attr_md_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 180",
         inout("r0") to_asm(handle) => ret,
         inout("r1") to_asm(p_uuid) => _,
@@ -12555,6 +12990,10 @@ pub unsafe fn sd_ble_gatts_attr_get(handle: u16, p_uuid: *mut ble_uuid_t, p_md:
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(180);
+    }
     ret
 }
 
@@ -12586,8 +13025,10 @@ pub unsafe fn sd_ble_gatts_attr_get(handle: u16, p_uuid: *mut ble_uuid_t, p_md:
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM Invalid Server RX MTU size supplied."]
 #[doc = " @retval ::NRF_ERROR_TIMEOUT There has been a GATT procedure timeout. No new GATT procedure can be performed without reestablishing the connection."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_gatts_exchange_mtu_reply(conn_handle: u16, server_rx_mtu: u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 181",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(server_rx_mtu) => _,
@@ -12595,6 +13036,10 @@ pub unsafe fn sd_ble_gatts_exchange_mtu_reply(conn_handle: u16, server_rx_mtu: u
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(181);
+    }
     ret
 }
 
@@ -13686,8 +14131,10 @@ fn bindgen_test_layout_ble_cfg_t() {
 #[doc = "                                      is currently not supported."]
 #[doc = " @retval ::NRF_ERROR_RESOURCES      The total number of L2CAP Channels configured using @ref sd_ble_cfg_set is too large."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_enable(p_app_ram_base: *mut u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 96",
         inout("r0") to_asm(p_app_ram_base) => ret,
         lateout("r1") _,
@@ -13695,6 +14142,10 @@ pub unsafe fn sd_ble_enable(p_app_ram_base: *mut u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(96);
+    }
     ret
 }
 
@@ -13732,8 +14183,10 @@ pub unsafe fn sd_ble_enable(p_app_ram_base: *mut u32) -> u32 {
 #[doc = " @retval ::NRF_ERROR_NO_MEM         The amount of memory assigned to the SoftDevice by app_ram_base is not"]
 #[doc = "                                    large enough to fit this configuration's memory requirement."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_cfg_set(cfg_id: u32, p_cfg: *const ble_cfg_t, app_ram_base: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 105",
         inout("r0") to_asm(cfg_id) => ret,
         inout("r1") to_asm(p_cfg) => _,
@@ -13741,6 +14194,10 @@ pub unsafe fn sd_ble_cfg_set(cfg_id: u32, p_cfg: *const ble_cfg_t, app_ram_base:
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(105);
+    }
     ret
 }
 
@@ -13780,8 +14237,10 @@ pub unsafe fn sd_ble_cfg_set(cfg_id: u32, p_cfg: *const ble_cfg_t, app_ram_base:
 #[doc = " @retval ::NRF_ERROR_NOT_FOUND No events ready to be pulled."]
 #[doc = " @retval ::NRF_ERROR_DATA_SIZE Event ready but could not fit into the supplied buffer."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_evt_get(p_dest: *mut u8, p_len: *mut u16) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 97",
         inout("r0") to_asm(p_dest) => ret,
         inout("r1") to_asm(p_len) => _,
@@ -13789,6 +14248,10 @@ pub unsafe fn sd_ble_evt_get(p_dest: *mut u8, p_len: *mut u16) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(97);
+    }
     ret
 }
 
@@ -13818,8 +14281,10 @@ pub unsafe fn sd_ble_evt_get(p_dest: *mut u8, p_len: *mut u16) -> u32 {
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR If p_vs_uuid or p_uuid_type is NULL or invalid."]
 #[doc = " @retval ::NRF_ERROR_NO_MEM If there are no more free slots for VS UUIDs."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_uuid_vs_add(p_vs_uuid: *const ble_uuid128_t, p_uuid_type: *mut u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 98",
         inout("r0") to_asm(p_vs_uuid) => ret,
         inout("r1") to_asm(p_uuid_type) => _,
@@ -13827,6 +14292,10 @@ pub unsafe fn sd_ble_uuid_vs_add(p_vs_uuid: *const ble_uuid128_t, p_uuid_type: *
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(98);
+    }
     ret
 }
 
@@ -13848,8 +14317,10 @@ pub unsafe fn sd_ble_uuid_vs_add(p_vs_uuid: *const ble_uuid128_t, p_uuid_type: *
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM If p_uuid_type points to a non-valid UUID type."]
 #[doc = " @retval ::NRF_ERROR_FORBIDDEN If the Vendor Specific base UUID is in use by the ATT Server."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_uuid_vs_remove(p_uuid_type: *mut u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 106",
         inout("r0") to_asm(p_uuid_type) => ret,
         lateout("r1") _,
@@ -13857,6 +14328,10 @@ pub unsafe fn sd_ble_uuid_vs_remove(p_uuid_type: *mut u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(106);
+    }
     ret
 }
 
@@ -13878,8 +14353,10 @@ pub unsafe fn sd_ble_uuid_vs_remove(p_uuid_type: *mut u8) -> u32 {
 #[doc = " @retval ::NRF_ERROR_INVALID_LENGTH Invalid UUID length."]
 #[doc = " @retval ::NRF_ERROR_NOT_FOUND For a 128-bit UUID, no match in the populated table of UUIDs."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_uuid_decode(uuid_le_len: u8, p_uuid_le: *const u8, p_uuid: *mut ble_uuid_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 99",
         inout("r0") to_asm(uuid_le_len) => ret,
         inout("r1") to_asm(p_uuid_le) => _,
@@ -13887,6 +14364,10 @@ pub unsafe fn sd_ble_uuid_decode(uuid_le_len: u8, p_uuid_le: *const u8, p_uuid:
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(99);
+    }
     ret
 }
 
@@ -13902,8 +14383,10 @@ pub unsafe fn sd_ble_uuid_decode(uuid_le_len: u8, p_uuid_le: *const u8, p_uuid:
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR Invalid pointer supplied."]
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM Invalid UUID type."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_uuid_encode(p_uuid: *const ble_uuid_t, p_uuid_le_len: *mut u8, p_uuid_le: *mut u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 100",
         inout("r0") to_asm(p_uuid) => ret,
         inout("r1") to_asm(p_uuid_le_len) => _,
@@ -13911,6 +14394,10 @@ pub unsafe fn sd_ble_uuid_encode(p_uuid: *const ble_uuid_t, p_uuid_le_len: *mut
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(100);
+    }
     ret
 }
 
@@ -13924,8 +14411,10 @@ pub unsafe fn sd_ble_uuid_encode(p_uuid: *const ble_uuid_t, p_uuid_le_len: *mut
 #[doc = " @retval ::NRF_ERROR_INVALID_ADDR Invalid pointer supplied."]
 #[doc = " @retval ::NRF_ERROR_BUSY The BLE stack is busy (typically doing a locally-initiated disconnection procedure)."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_version_get(p_version: *mut ble_version_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 101",
         inout("r0") to_asm(p_version) => ret,
         lateout("r1") _,
@@ -13933,6 +14422,10 @@ pub unsafe fn sd_ble_version_get(p_version: *mut ble_version_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(101);
+    }
     ret
 }
 
@@ -13959,8 +14452,10 @@ pub unsafe fn sd_ble_version_get(p_version: *mut ble_version_t) -> u32 {
 #[doc = " @retval ::NRF_ERROR_INVALID_LENGTH Invalid user memory block length supplied."]
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE Invalid Connection state or no user memory request pending."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_user_mem_reply(conn_handle: u16, p_block: *const ble_user_mem_block_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 102",
         inout("r0") to_asm(conn_handle) => ret,
         inout("r1") to_asm(p_block) => _,
@@ -13968,6 +14463,10 @@ pub unsafe fn sd_ble_user_mem_reply(conn_handle: u16, p_block: *const ble_user_m
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(102);
+    }
     ret
 }
 
@@ -13985,8 +14484,10 @@ pub unsafe fn sd_ble_user_mem_reply(conn_handle: u16, p_block: *const ble_user_m
 #[doc = " @retval ::NRF_ERROR_INVALID_STATE Unable to set the parameter at this time."]
 #[doc = " @retval ::NRF_ERROR_BUSY The BLE stack is busy or the previous procedure has not completed."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_opt_set(opt_id: u32, p_opt: *const ble_opt_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 103",
         inout("r0") to_asm(opt_id) => ret,
         inout("r1") to_asm(p_opt) => _,
@@ -13994,6 +14495,10 @@ pub unsafe fn sd_ble_opt_set(opt_id: u32, p_opt: *const ble_opt_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(103);
+    }
     ret
 }
 
@@ -14013,8 +14518,10 @@ pub unsafe fn sd_ble_opt_set(opt_id: u32, p_opt: *const ble_opt_t) -> u32 {
 #[doc = " @retval ::NRF_ERROR_NOT_SUPPORTED This option is not supported."]
 #[doc = ""]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ble_opt_get(opt_id: u32, p_opt: *mut ble_opt_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 104",
         inout("r0") to_asm(opt_id) => ret,
         inout("r1") to_asm(p_opt) => _,
@@ -14022,6 +14529,10 @@ pub unsafe fn sd_ble_opt_get(opt_id: u32, p_opt: *mut ble_opt_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(104);
+    }
     ret
 }
 
@@ -14748,8 +15259,10 @@ fn bindgen_test_layout_nrf_ecb_hal_data_block_t() {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_mutex_new(p_mutex: *mut nrf_mutex_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 44",
         inout("r0") to_asm(p_mutex) => ret,
         lateout("r1") _,
@@ -14757,6 +15270,10 @@ pub unsafe fn sd_mutex_new(p_mutex: *mut nrf_mutex_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(44);
+    }
     ret
 }
 
@@ -14767,8 +15284,10 @@ pub unsafe fn sd_mutex_new(p_mutex: *mut nrf_mutex_t) -> u32 {
 #[doc = " @retval ::NRF_SUCCESS The mutex was successfully acquired."]
 #[doc = " @retval ::NRF_ERROR_SOC_MUTEX_ALREADY_TAKEN The mutex could not be acquired."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_mutex_acquire(p_mutex: *mut nrf_mutex_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 45",
         inout("r0") to_asm(p_mutex) => ret,
         lateout("r1") _,
@@ -14776,6 +15295,10 @@ pub unsafe fn sd_mutex_acquire(p_mutex: *mut nrf_mutex_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(45);
+    }
     ret
 }
 
@@ -14785,8 +15308,10 @@ pub unsafe fn sd_mutex_acquire(p_mutex: *mut nrf_mutex_t) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_mutex_release(p_mutex: *mut nrf_mutex_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 46",
         inout("r0") to_asm(p_mutex) => ret,
         lateout("r1") _,
@@ -14794,6 +15319,10 @@ pub unsafe fn sd_mutex_release(p_mutex: *mut nrf_mutex_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(46);
+    }
     ret
 }
 
@@ -14803,8 +15332,10 @@ pub unsafe fn sd_mutex_release(p_mutex: *mut nrf_mutex_t) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_rand_application_pool_capacity_get(p_pool_capacity: *mut u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 47",
         inout("r0") to_asm(p_pool_capacity) => ret,
         lateout("r1") _,
@@ -14812,6 +15343,10 @@ pub unsafe fn sd_rand_application_pool_capacity_get(p_pool_capacity: *mut u8) ->
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(47);
+    }
     ret
 }
 
@@ -14821,8 +15356,10 @@ pub unsafe fn sd_rand_application_pool_capacity_get(p_pool_capacity: *mut u8) ->
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_rand_application_bytes_available_get(p_bytes_available: *mut u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 48",
         inout("r0") to_asm(p_bytes_available) => ret,
         lateout("r1") _,
@@ -14830,6 +15367,10 @@ pub unsafe fn sd_rand_application_bytes_available_get(p_bytes_available: *mut u8
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(48);
+    }
     ret
 }
 
@@ -14841,8 +15382,10 @@ pub unsafe fn sd_rand_application_bytes_available_get(p_bytes_available: *mut u8
 #[doc = " @retval ::NRF_SUCCESS The requested bytes were written to p_buff."]
 #[doc = " @retval ::NRF_ERROR_SOC_RAND_NOT_ENOUGH_VALUES No bytes were written to the buffer, because there were not enough bytes available."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_rand_application_vector_get(p_buff: *mut u8, length: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 49",
         inout("r0") to_asm(p_buff) => ret,
         inout("r1") to_asm(length) => _,
@@ -14850,6 +15393,10 @@ pub unsafe fn sd_rand_application_vector_get(p_buff: *mut u8, length: u8) -> u32
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(49);
+    }
     ret
 }
 
@@ -14859,8 +15406,10 @@ pub unsafe fn sd_rand_application_vector_get(p_buff: *mut u8, length: u8) -> u32
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_reset_reason_get(p_reset_reason: *mut u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 52",
         inout("r0") to_asm(p_reset_reason) => ret,
         lateout("r1") _,
@@ -14868,6 +15417,10 @@ pub unsafe fn sd_power_reset_reason_get(p_reset_reason: *mut u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(52);
+    }
     ret
 }
 
@@ -14877,8 +15430,10 @@ pub unsafe fn sd_power_reset_reason_get(p_reset_reason: *mut u32) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_reset_reason_clr(reset_reason_clr_msk: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 53",
         inout("r0") to_asm(reset_reason_clr_msk) => ret,
         lateout("r1") _,
@@ -14886,6 +15441,10 @@ pub unsafe fn sd_power_reset_reason_clr(reset_reason_clr_msk: u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(53);
+    }
     ret
 }
 
@@ -14896,8 +15455,10 @@ pub unsafe fn sd_power_reset_reason_clr(reset_reason_clr_msk: u32) -> u32 {
 #[doc = " @retval ::NRF_SUCCESS The power mode was set."]
 #[doc = " @retval ::NRF_ERROR_SOC_POWER_MODE_UNKNOWN The power mode was unknown."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_mode_set(power_mode: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 50",
         inout("r0") to_asm(power_mode) => ret,
         lateout("r1") _,
@@ -14905,6 +15466,10 @@ pub unsafe fn sd_power_mode_set(power_mode: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(50);
+    }
     ret
 }
 
@@ -14912,8 +15477,10 @@ pub unsafe fn sd_power_mode_set(power_mode: u8) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_ERROR_SOC_POWER_OFF_SHOULD_NOT_RETURN"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_system_off() -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 51",
         lateout("r0") ret,
         lateout("r1") _,
@@ -14921,6 +15488,10 @@ pub unsafe fn sd_power_system_off() -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(51);
+    }
     ret
 }
 
@@ -14933,8 +15504,10 @@ pub unsafe fn sd_power_system_off() -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_pof_enable(pof_enable: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 54",
         inout("r0") to_asm(pof_enable) => ret,
         lateout("r1") _,
@@ -14942,6 +15515,10 @@ pub unsafe fn sd_power_pof_enable(pof_enable: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(54);
+    }
     ret
 }
 
@@ -14953,8 +15530,10 @@ pub unsafe fn sd_power_pof_enable(pof_enable: u8) -> u32 {
 #[doc = " @retval ::NRF_SUCCESS The power failure threshold was set."]
 #[doc = " @retval ::NRF_ERROR_SOC_POWER_POF_THRESHOLD_UNKNOWN The power failure threshold is unknown."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_pof_threshold_set(threshold: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 55",
         inout("r0") to_asm(threshold) => ret,
         lateout("r1") _,
@@ -14962,6 +15541,10 @@ pub unsafe fn sd_power_pof_threshold_set(threshold: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(55);
+    }
     ret
 }
 
@@ -14972,8 +15555,10 @@ pub unsafe fn sd_power_pof_threshold_set(threshold: u8) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_ram_power_set(index: u8, ram_powerset: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 57",
         inout("r0") to_asm(index) => ret,
         inout("r1") to_asm(ram_powerset) => _,
@@ -14981,6 +15566,10 @@ pub unsafe fn sd_power_ram_power_set(index: u8, ram_powerset: u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(57);
+    }
     ret
 }
 
@@ -14991,8 +15580,10 @@ pub unsafe fn sd_power_ram_power_set(index: u8, ram_powerset: u32) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_ram_power_clr(index: u8, ram_powerclr: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 58",
         inout("r0") to_asm(index) => ret,
         inout("r1") to_asm(ram_powerclr) => _,
@@ -15000,6 +15591,10 @@ pub unsafe fn sd_power_ram_power_clr(index: u8, ram_powerclr: u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(58);
+    }
     ret
 }
 
@@ -15010,8 +15605,10 @@ pub unsafe fn sd_power_ram_power_clr(index: u8, ram_powerclr: u32) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_ram_power_get(index: u8, p_ram_power: *mut u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 59",
         inout("r0") to_asm(index) => ret,
         inout("r1") to_asm(p_ram_power) => _,
@@ -15019,6 +15616,10 @@ pub unsafe fn sd_power_ram_power_get(index: u8, p_ram_power: *mut u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(59);
+    }
     ret
 }
 
@@ -15029,8 +15630,10 @@ pub unsafe fn sd_power_ram_power_get(index: u8, p_ram_power: *mut u32) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_gpregret_set(gpregret_id: u32, gpregret_msk: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 60",
         inout("r0") to_asm(gpregret_id) => ret,
         inout("r1") to_asm(gpregret_msk) => _,
@@ -15038,6 +15641,10 @@ pub unsafe fn sd_power_gpregret_set(gpregret_id: u32, gpregret_msk: u32) -> u32
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(60);
+    }
     ret
 }
 
@@ -15048,8 +15655,10 @@ pub unsafe fn sd_power_gpregret_set(gpregret_id: u32, gpregret_msk: u32) -> u32
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_gpregret_clr(gpregret_id: u32, gpregret_msk: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 61",
         inout("r0") to_asm(gpregret_id) => ret,
         inout("r1") to_asm(gpregret_msk) => _,
@@ -15057,6 +15666,10 @@ pub unsafe fn sd_power_gpregret_clr(gpregret_id: u32, gpregret_msk: u32) -> u32
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(61);
+    }
     ret
 }
 
@@ -15067,8 +15680,10 @@ pub unsafe fn sd_power_gpregret_clr(gpregret_id: u32, gpregret_msk: u32) -> u32
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_gpregret_get(gpregret_id: u32, p_gpregret: *mut u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 62",
         inout("r0") to_asm(gpregret_id) => ret,
         inout("r1") to_asm(p_gpregret) => _,
@@ -15076,6 +15691,10 @@ pub unsafe fn sd_power_gpregret_get(gpregret_id: u32, p_gpregret: *mut u32) -> u
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(62);
+    }
     ret
 }
 
@@ -15086,8 +15705,10 @@ pub unsafe fn sd_power_gpregret_get(gpregret_id: u32, p_gpregret: *mut u32) -> u
 #[doc = " @retval ::NRF_SUCCESS"]
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM The DCDC mode is invalid."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_power_dcdc_mode_set(dcdc_mode: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 63",
         inout("r0") to_asm(dcdc_mode) => ret,
         lateout("r1") _,
@@ -15095,6 +15716,10 @@ pub unsafe fn sd_power_dcdc_mode_set(dcdc_mode: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(63);
+    }
     ret
 }
 
@@ -15108,8 +15733,10 @@ pub unsafe fn sd_power_dcdc_mode_set(dcdc_mode: u8) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_clock_hfclk_request() -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 66",
         lateout("r0") ret,
         lateout("r1") _,
@@ -15117,6 +15744,10 @@ pub unsafe fn sd_clock_hfclk_request() -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(66);
+    }
     ret
 }
 
@@ -15129,8 +15760,10 @@ pub unsafe fn sd_clock_hfclk_request() -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_clock_hfclk_release() -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 67",
         lateout("r0") ret,
         lateout("r1") _,
@@ -15138,6 +15771,10 @@ pub unsafe fn sd_clock_hfclk_release() -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(67);
+    }
     ret
 }
 
@@ -15150,8 +15787,10 @@ pub unsafe fn sd_clock_hfclk_release() -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_clock_hfclk_is_running(p_is_running: *mut u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 68",
         inout("r0") to_asm(p_is_running) => ret,
         lateout("r1") _,
@@ -15159,6 +15798,10 @@ pub unsafe fn sd_clock_hfclk_is_running(p_is_running: *mut u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(68);
+    }
     ret
 }
 
@@ -15188,8 +15831,10 @@ pub unsafe fn sd_clock_hfclk_is_running(p_is_running: *mut u32) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_app_evt_wait() -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 65",
         lateout("r0") ret,
         lateout("r1") _,
@@ -15197,6 +15842,10 @@ pub unsafe fn sd_app_evt_wait() -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(65);
+    }
     ret
 }
 
@@ -15206,8 +15855,10 @@ pub unsafe fn sd_app_evt_wait() -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ppi_channel_enable_get(p_channel_enable: *mut u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 32",
         inout("r0") to_asm(p_channel_enable) => ret,
         lateout("r1") _,
@@ -15215,6 +15866,10 @@ pub unsafe fn sd_ppi_channel_enable_get(p_channel_enable: *mut u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(32);
+    }
     ret
 }
 
@@ -15224,8 +15879,10 @@ pub unsafe fn sd_ppi_channel_enable_get(p_channel_enable: *mut u32) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ppi_channel_enable_set(channel_enable_set_msk: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 33",
         inout("r0") to_asm(channel_enable_set_msk) => ret,
         lateout("r1") _,
@@ -15233,6 +15890,10 @@ pub unsafe fn sd_ppi_channel_enable_set(channel_enable_set_msk: u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(33);
+    }
     ret
 }
 
@@ -15242,8 +15903,10 @@ pub unsafe fn sd_ppi_channel_enable_set(channel_enable_set_msk: u32) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ppi_channel_enable_clr(channel_enable_clr_msk: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 34",
         inout("r0") to_asm(channel_enable_clr_msk) => ret,
         lateout("r1") _,
@@ -15251,6 +15914,10 @@ pub unsafe fn sd_ppi_channel_enable_clr(channel_enable_clr_msk: u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(34);
+    }
     ret
 }
 
@@ -15263,12 +15930,14 @@ pub unsafe fn sd_ppi_channel_enable_clr(channel_enable_clr_msk: u32) -> u32 {
 #[doc = " @retval ::NRF_ERROR_SOC_PPI_INVALID_CHANNEL The channel number is invalid."]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ppi_channel_assign(
     channel_num: u8,
     evt_endpoint: *const self::c_void,
     task_endpoint: *const self::c_void,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 35",
         inout("r0") to_asm(channel_num) => ret,
         inout("r1") to_asm(evt_endpoint) => _,
@@ -15276,6 +15945,10 @@ pub unsafe fn sd_ppi_channel_assign(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(35);
+    }
     ret
 }
 
@@ -15286,8 +15959,10 @@ pub unsafe fn sd_ppi_channel_assign(
 #[doc = " @retval ::NRF_ERROR_SOC_PPI_INVALID_GROUP The group number is invalid"]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ppi_group_task_enable(group_num: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 36",
         inout("r0") to_asm(group_num) => ret,
         lateout("r1") _,
@@ -15295,6 +15970,10 @@ pub unsafe fn sd_ppi_group_task_enable(group_num: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(36);
+    }
     ret
 }
 
@@ -15305,8 +15984,10 @@ pub unsafe fn sd_ppi_group_task_enable(group_num: u8) -> u32 {
 #[doc = " @retval ::NRF_ERROR_SOC_PPI_INVALID_GROUP The group number is invalid."]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ppi_group_task_disable(group_num: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 37",
         inout("r0") to_asm(group_num) => ret,
         lateout("r1") _,
@@ -15314,6 +15995,10 @@ pub unsafe fn sd_ppi_group_task_disable(group_num: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(37);
+    }
     ret
 }
 
@@ -15325,8 +16010,10 @@ pub unsafe fn sd_ppi_group_task_disable(group_num: u8) -> u32 {
 #[doc = " @retval ::NRF_ERROR_SOC_PPI_INVALID_GROUP The group number is invalid."]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ppi_group_assign(group_num: u8, channel_msk: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 38",
         inout("r0") to_asm(group_num) => ret,
         inout("r1") to_asm(channel_msk) => _,
@@ -15334,6 +16021,10 @@ pub unsafe fn sd_ppi_group_assign(group_num: u8, channel_msk: u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(38);
+    }
     ret
 }
 
@@ -15345,8 +16036,10 @@ pub unsafe fn sd_ppi_group_assign(group_num: u8, channel_msk: u32) -> u32 {
 #[doc = " @retval ::NRF_ERROR_SOC_PPI_INVALID_GROUP The group number is invalid."]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ppi_group_get(group_num: u8, p_channel_msk: *mut u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 39",
         inout("r0") to_asm(group_num) => ret,
         inout("r1") to_asm(p_channel_msk) => _,
@@ -15354,6 +16047,10 @@ pub unsafe fn sd_ppi_group_get(group_num: u8, p_channel_msk: *mut u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(39);
+    }
     ret
 }
 
@@ -15386,8 +16083,10 @@ pub unsafe fn sd_ppi_group_get(group_num: u8, p_channel_msk: *mut u32) -> u32 {
 #[doc = "                                   running activities and retry."]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_radio_notification_cfg_set(type_: u8, distance: u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 69",
         inout("r0") to_asm(type_) => ret,
         inout("r1") to_asm(distance) => _,
@@ -15395,6 +16094,10 @@ pub unsafe fn sd_radio_notification_cfg_set(type_: u8, distance: u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(69);
+    }
     ret
 }
 
@@ -15412,8 +16115,10 @@ pub unsafe fn sd_radio_notification_cfg_set(type_: u8, distance: u8) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ecb_block_encrypt(p_ecb_data: *mut nrf_ecb_hal_data_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 70",
         inout("r0") to_asm(p_ecb_data) => ret,
         lateout("r1") _,
@@ -15421,6 +16126,10 @@ pub unsafe fn sd_ecb_block_encrypt(p_ecb_data: *mut nrf_ecb_hal_data_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(70);
+    }
     ret
 }
 
@@ -15439,8 +16148,10 @@ pub unsafe fn sd_ecb_block_encrypt(p_ecb_data: *mut nrf_ecb_hal_data_t) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_ecb_blocks_encrypt(block_count: u8, p_data_blocks: *mut nrf_ecb_hal_data_block_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 71",
         inout("r0") to_asm(block_count) => ret,
         inout("r1") to_asm(p_data_blocks) => _,
@@ -15448,6 +16159,10 @@ pub unsafe fn sd_ecb_blocks_encrypt(block_count: u8, p_data_blocks: *mut nrf_ecb
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(71);
+    }
     ret
 }
 
@@ -15460,8 +16175,10 @@ pub unsafe fn sd_ecb_blocks_encrypt(block_count: u8, p_data_blocks: *mut nrf_ecb
 #[doc = " @retval ::NRF_SUCCESS An event was pending. The event id is written in the p_evt_id parameter."]
 #[doc = " @retval ::NRF_ERROR_NOT_FOUND No pending events."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_evt_get(p_evt_id: *mut u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 75",
         inout("r0") to_asm(p_evt_id) => ret,
         lateout("r1") _,
@@ -15469,6 +16186,10 @@ pub unsafe fn sd_evt_get(p_evt_id: *mut u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(75);
+    }
     ret
 }
 
@@ -15481,8 +16202,10 @@ pub unsafe fn sd_evt_get(p_evt_id: *mut u32) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS A temperature measurement was done, and the temperature was written to temp"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_temp_get(p_temp: *mut i32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 76",
         inout("r0") to_asm(p_temp) => ret,
         lateout("r1") _,
@@ -15490,6 +16213,10 @@ pub unsafe fn sd_temp_get(p_temp: *mut i32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(76);
+    }
     ret
 }
 
@@ -15528,8 +16255,10 @@ pub unsafe fn sd_temp_get(p_temp: *mut i32) -> u32 {
 #[doc = " @retval ::NRF_ERROR_FORBIDDEN      Tried to write to an address outside the application flash area."]
 #[doc = " @retval ::NRF_SUCCESS              The command was accepted."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_flash_write(p_dst: *mut u32, p_src: *const u32, size: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 41",
         inout("r0") to_asm(p_dst) => ret,
         inout("r1") to_asm(p_src) => _,
@@ -15537,6 +16266,10 @@ pub unsafe fn sd_flash_write(p_dst: *mut u32, p_src: *const u32, size: u32) -> u
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(41);
+    }
     ret
 }
 
@@ -15569,8 +16302,10 @@ pub unsafe fn sd_flash_write(p_dst: *mut u32, p_src: *const u32, size: u32) -> u
 #[doc = " @retval ::NRF_ERROR_FORBIDDEN     Tried to erase a page outside the application flash area."]
 #[doc = " @retval ::NRF_SUCCESS             The command was accepted."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_flash_page_erase(page_number: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 40",
         inout("r0") to_asm(page_number) => ret,
         lateout("r1") _,
@@ -15578,6 +16313,10 @@ pub unsafe fn sd_flash_page_erase(page_number: u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(40);
+    }
     ret
 }
 
@@ -15601,8 +16340,10 @@ pub unsafe fn sd_flash_page_erase(page_number: u32) -> u32 {
 #[doc = " @retval ::NRF_ERROR_NOT_SUPPORTED Non-zero value supplied to one or more of the unsupported parameters."]
 #[doc = " @retval ::NRF_SUCCESS Values successfully written to configuration registers."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_flash_protect(block_cfg0: u32, block_cfg1: u32, block_cfg2: u32, block_cfg3: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 42",
         inout("r0") to_asm(block_cfg0) => ret,
         inout("r1") to_asm(block_cfg1) => _,
@@ -15610,6 +16351,10 @@ pub unsafe fn sd_flash_protect(block_cfg0: u32, block_cfg1: u32, block_cfg2: u32
         inout("r3") to_asm(block_cfg3) => _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(42);
+    }
     ret
 }
 
@@ -15633,8 +16378,10 @@ pub unsafe fn sd_flash_protect(block_cfg0: u32, block_cfg1: u32, block_cfg2: u32
 #[doc = " @retval ::NRF_ERROR_INTERNAL If a new session could not be opened due to an internal error."]
 #[doc = " @retval ::NRF_SUCCESS Otherwise."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_radio_session_open(p_radio_signal_callback: nrf_radio_signal_callback_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 72",
         inout("r0") to_asm(p_radio_signal_callback) => ret,
         lateout("r1") _,
@@ -15642,6 +16389,10 @@ pub unsafe fn sd_radio_session_open(p_radio_signal_callback: nrf_radio_signal_ca
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(72);
+    }
     ret
 }
 
@@ -15656,8 +16407,10 @@ pub unsafe fn sd_radio_session_open(p_radio_signal_callback: nrf_radio_signal_ca
 #[doc = " @retval ::NRF_ERROR_BUSY If session is currently being closed."]
 #[doc = " @retval ::NRF_SUCCESS Otherwise."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_radio_session_close() -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 73",
         lateout("r0") ret,
         lateout("r1") _,
@@ -15665,6 +16418,10 @@ pub unsafe fn sd_radio_session_close() -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(73);
+    }
     ret
 }
 
@@ -15703,8 +16460,10 @@ pub unsafe fn sd_radio_session_close() -> u32 {
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM If the parameters of p_request are not valid."]
 #[doc = " @retval ::NRF_SUCCESS Otherwise."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_radio_request(p_request: *const nrf_radio_request_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 74",
         inout("r0") to_asm(p_request) => ret,
         lateout("r1") _,
@@ -15712,6 +16471,10 @@ pub unsafe fn sd_radio_request(p_request: *const nrf_radio_request_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(74);
+    }
     ret
 }
 
@@ -15734,8 +16497,10 @@ pub unsafe fn sd_radio_request(p_request: *const nrf_radio_request_t) -> u32 {
 #[doc = " @retval ::NRF_SUCCESS Value successfully written to register."]
 #[doc = ""]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_protected_register_write(p_register: *mut u32, value: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 43",
         inout("r0") to_asm(p_register) => ret,
         inout("r1") to_asm(value) => _,
@@ -15743,6 +16508,10 @@ pub unsafe fn sd_protected_register_write(p_register: *mut u32, value: u32) -> u
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(43);
+    }
     ret
 }
 
@@ -16182,8 +16951,10 @@ fn bindgen_test_layout_sd_mbr_command_t() {
 #[doc = " @retval ::NRF_ERROR_NO_MEM No MBR parameter page provided"]
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM if an invalid command is given."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_mbr_command(param: *mut sd_mbr_command_t) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 24",
         inout("r0") to_asm(param) => ret,
         lateout("r1") _,
@@ -16191,6 +16962,10 @@ pub unsafe fn sd_mbr_command(param: *mut sd_mbr_command_t) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(24);
+    }
     ret
 }
 
@@ -16346,11 +17121,13 @@ pub type nrf_fault_handler_t = ::core::option::Option<unsafe extern "C" fn(id: u
 #[doc = " @retval ::NRF_ERROR_SDM_LFCLK_SOURCE_UNKNOWN Unknown low frequency clock source selected."]
 #[doc = " @retval ::NRF_ERROR_INVALID_PARAM Invalid clock source configuration supplied in p_clock_lf_cfg."]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_softdevice_enable(
     p_clock_lf_cfg: *const nrf_clock_lf_cfg_t,
     fault_handler: nrf_fault_handler_t,
 ) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 16",
         inout("r0") to_asm(p_clock_lf_cfg) => ret,
         inout("r1") to_asm(fault_handler) => _,
@@ -16358,6 +17135,10 @@ pub unsafe fn sd_softdevice_enable(
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(16);
+    }
     ret
 }
 
@@ -16374,8 +17155,10 @@ pub unsafe fn sd_softdevice_enable(
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_softdevice_disable() -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 17",
         lateout("r0") ret,
         lateout("r1") _,
@@ -16383,6 +17166,10 @@ pub unsafe fn sd_softdevice_disable() -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(17);
+    }
     ret
 }
 
@@ -16392,8 +17179,10 @@ pub unsafe fn sd_softdevice_disable() -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_softdevice_is_enabled(p_softdevice_enabled: *mut u8) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 18",
         inout("r0") to_asm(p_softdevice_enabled) => ret,
         lateout("r1") _,
@@ -16401,6 +17190,10 @@ pub unsafe fn sd_softdevice_is_enabled(p_softdevice_enabled: *mut u8) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(18);
+    }
     ret
 }
 
@@ -16412,8 +17205,10 @@ pub unsafe fn sd_softdevice_is_enabled(p_softdevice_enabled: *mut u8) -> u32 {
 #[doc = ""]
 #[doc = " @retval ::NRF_SUCCESS"]
 #[inline(always)]
+#[cfg_attr(not(target_arch = "arm"), allow(unused_variables))]
 pub unsafe fn sd_softdevice_vector_table_base_set(address: u32) -> u32 {
     let ret: u32;
+    #[cfg(target_arch = "arm")]
     core::arch::asm!("svc 19",
         inout("r0") to_asm(address) => ret,
         lateout("r1") _,
@@ -16421,5 +17216,9 @@ pub unsafe fn sd_softdevice_vector_table_base_set(address: u32) -> u32 {
         lateout("r3") _,
         lateout("r12") _,
     );
+    #[cfg(not(target_arch = "arm"))]
+    {
+        ret = svc_unavailable(19);
+    }
     ret
 }
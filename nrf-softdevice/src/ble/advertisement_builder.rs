@@ -1,5 +1,9 @@
 #[cfg(feature = "defmt")]
 use defmt::Format;
+use fixed::types::I8F8;
+
+use crate::ble::Appearance;
+use crate::Softdevice;
 
 const LEGACY_PAYLOAD_LEN: usize = 31;
 const EXTENDED_PAYLOAD_LEN: usize = 254;
@@ -157,6 +161,7 @@ impl ServiceUuid16 {
     pub const ELECTRONIC_SHELF_LABEL: ServiceUuid16 = ServiceUuid16(0x1847);
     pub const GAMING_AUDIO: ServiceUuid16 = ServiceUuid16(0x1858);
     pub const MESH_PROXY_SOLICITATION: ServiceUuid16 = ServiceUuid16(0x1859);
+    pub const EDDYSTONE: ServiceUuid16 = ServiceUuid16(0xFEAA);
 
     pub const fn from_u16(value: u16) -> Self {
         ServiceUuid16(value)
@@ -236,7 +241,7 @@ impl<const K: usize> AdvertisementBuilder<K> {
     ///
     /// *Note: The length is automatically computed and prepended.*
     pub const fn raw(self, ad: AdvertisementDataType, data: &[u8]) -> Self {
-        self.write(&[data.len() as u8 + 1, ad.to_u8()]).write(data)
+        self.write(&[(data.len() + 1) as u8, ad.to_u8()]).write(data)
     }
 
     /// Get the resulting advertisement payload.
@@ -285,7 +290,7 @@ impl<const K: usize> AdvertisementBuilder<K> {
             ServiceList::Complete => AdvertisementDataType::COMPLETE_16_SERVICE_LIST,
         };
 
-        let mut res = self.write(&[(services.len() * 2) as u8 + 1, ad_type.to_u8()]);
+        let mut res = self.write(&[(services.len() * 2 + 1) as u8, ad_type.to_u8()]);
         let mut i = 0;
         while i < services.len() {
             res = res.write(&(services[i].to_u16()).to_le_bytes());
@@ -304,7 +309,7 @@ impl<const K: usize> AdvertisementBuilder<K> {
             ServiceList::Complete => AdvertisementDataType::COMPLETE_128_SERVICE_LIST,
         };
 
-        let mut res = self.write(&[(services.len() * 16) as u8 + 1, ad_type.to_u8()]);
+        let mut res = self.write(&[(services.len() * 16 + 1) as u8, ad_type.to_u8()]);
         let mut i = 0;
         while i < services.len() {
             res = res.write(&services[i]);
@@ -313,6 +318,96 @@ impl<const K: usize> AdvertisementBuilder<K> {
         res
     }
 
+    /// Add a list of 32-bit service uuids to the advertisement data.
+    pub const fn services_32(self, complete: ServiceList, services: &[u32]) -> Self {
+        let ad_type = match complete {
+            ServiceList::Incomplete => AdvertisementDataType::INCOMPLETE_32_SERVICE_LIST,
+            ServiceList::Complete => AdvertisementDataType::COMPLETE_32_SERVICE_LIST,
+        };
+
+        let mut res = self.write(&[(services.len() * 4 + 1) as u8, ad_type.to_u8()]);
+        let mut i = 0;
+        while i < services.len() {
+            res = res.write(&services[i].to_le_bytes());
+            i += 1;
+        }
+        res
+    }
+
+    /// Add service data for a 16-bit service uuid to the advertisement data.
+    pub const fn service_data_16(self, uuid: ServiceUuid16, data: &[u8]) -> Self {
+        self.write(&[(data.len() + 3) as u8, AdvertisementDataType::SERVICE_DATA_16.to_u8()])
+            .write(&uuid.to_u16().to_le_bytes())
+            .write(data)
+    }
+
+    /// Add service data for a 32-bit service uuid to the advertisement data.
+    pub const fn service_data_32(self, uuid: u32, data: &[u8]) -> Self {
+        self.write(&[(data.len() + 5) as u8, AdvertisementDataType::SERVICE_DATA_32.to_u8()])
+            .write(&uuid.to_le_bytes())
+            .write(data)
+    }
+
+    /// Add service data for a 128-bit service uuid to the advertisement data.
+    ///
+    /// Note that the UUID needs to be in little-endian format, i.e. opposite to what you would
+    /// normally write UUIDs.
+    pub const fn service_data_128(self, uuid: &[u8; 16], data: &[u8]) -> Self {
+        self.write(&[(data.len() + 17) as u8, AdvertisementDataType::SERVICE_DATA_128.to_u8()])
+            .write(uuid)
+            .write(data)
+    }
+
+    /// Add manufacturer specific data, prefixed with the company identifier, to the advertisement data.
+    pub const fn manufacturer_data(self, company_id: u16, data: &[u8]) -> Self {
+        self.write(&[
+            (data.len() + 3) as u8,
+            AdvertisementDataType::MANUFACTURER_SPECIFIC_DATA.to_u8(),
+        ])
+        .write(&company_id.to_le_bytes())
+        .write(data)
+    }
+
+    /// Add the device appearance to the advertisement data.
+    pub const fn appearance(self, appearance: Appearance) -> Self {
+        self.raw(AdvertisementDataType::APPEARANCE, &appearance.into_raw().to_le_bytes())
+    }
+
+    /// Add the TX power level, in dBm, to the advertisement data.
+    pub const fn tx_power_level(self, tx_power: i8) -> Self {
+        self.raw(AdvertisementDataType::TXPOWER_LEVEL, &[tx_power as u8])
+    }
+
+    /// Add the preferred connection interval range, in 1.25ms units, to the advertisement data.
+    ///
+    /// Use `0xFFFF` for either bound to indicate no specific preference.
+    pub const fn peripheral_connection_interval_range(self, min: u16, max: u16) -> Self {
+        let min = min.to_le_bytes();
+        let max = max.to_le_bytes();
+        self.raw(
+            AdvertisementDataType::PERIPHERAL_CONNECTION_INTERVAL_RANGE,
+            &[min[0], min[1], max[0], max[1]],
+        )
+    }
+
+    /// Add a URI to the advertisement data.
+    ///
+    /// The `http:` and `https:` schemes are compressed to their assigned code point; other URIs are
+    /// written out in full.
+    pub const fn uri(self, uri: &str) -> Self {
+        let bytes = uri.as_bytes();
+        let (scheme, rest) = if has_prefix_at(bytes, 0, "http:") {
+            (0x16, bytes.split_at(5).1)
+        } else if has_prefix_at(bytes, 0, "https:") {
+            (0x17, bytes.split_at(6).1)
+        } else {
+            (0x01, bytes)
+        };
+
+        self.write(&[(rest.len() + 2) as u8, AdvertisementDataType::URI.to_u8(), scheme])
+            .write(rest)
+    }
+
     /// Add a name to the advertisement data.
     pub const fn short_name(self, name: &str) -> Self {
         self.raw(AdvertisementDataType::SHORT_NAME, name.as_bytes())
@@ -342,6 +437,252 @@ impl<const K: usize> AdvertisementBuilder<K> {
             res
         }
     }
+
+    /// Add an Apple iBeacon manufacturer specific data element.
+    ///
+    /// The proximity `uuid` is in the usual big-endian order. `measured_power` is the RSSI at 1 m, in dBm.
+    /// A legacy advertisement additionally needs `flags` to fit this, and nothing else.
+    pub const fn ibeacon(self, uuid: &[u8; 16], major: u16, minor: u16, measured_power: i8) -> Self {
+        let major = major.to_be_bytes();
+        let minor = minor.to_be_bytes();
+        self.write(&[
+            26,
+            AdvertisementDataType::MANUFACTURER_SPECIFIC_DATA.to_u8(),
+            0x4c,
+            0x00,
+            0x02,
+            0x15,
+        ])
+        .write(uuid)
+        .write(&[major[0], major[1], minor[0], minor[1], measured_power as u8])
+    }
+
+    /// Add an AltBeacon manufacturer specific data element.
+    ///
+    /// `reference_rssi` is the RSSI at 1 m, in dBm.
+    pub const fn altbeacon(self, manufacturer_id: u16, beacon_id: &[u8; 20], reference_rssi: i8, reserved: u8) -> Self {
+        let manufacturer_id = manufacturer_id.to_le_bytes();
+        self.write(&[
+            27,
+            AdvertisementDataType::MANUFACTURER_SPECIFIC_DATA.to_u8(),
+            manufacturer_id[0],
+            manufacturer_id[1],
+            0xbe,
+            0xac,
+        ])
+        .write(beacon_id)
+        .write(&[reference_rssi as u8, reserved])
+    }
+
+    /// Whether a 16-bit service list containing `uuid` was already added.
+    const fn has_service_16(&self, uuid: ServiceUuid16) -> bool {
+        let end = if self.ptr < K { self.ptr } else { K };
+        let mut i = 0;
+        while i + 1 < end {
+            let len = self.buf[i] as usize;
+            if len == 0 {
+                break;
+            }
+            let ad_type = self.buf[i + 1];
+            if ad_type == AdvertisementDataType::INCOMPLETE_16_SERVICE_LIST.to_u8()
+                || ad_type == AdvertisementDataType::COMPLETE_16_SERVICE_LIST.to_u8()
+            {
+                let mut j = i + 2;
+                while j + 1 < i + 1 + len && j + 1 < end {
+                    if u16::from_le_bytes([self.buf[j], self.buf[j + 1]]) == uuid.to_u16() {
+                        return true;
+                    }
+                    j += 2;
+                }
+            }
+            i += 1 + len;
+        }
+        false
+    }
+
+    const fn eddystone(self, frame_type: u8, frame_len: usize) -> Self {
+        let res = if self.has_service_16(ServiceUuid16::EDDYSTONE) {
+            self
+        } else {
+            self.services_16(ServiceList::Complete, &[ServiceUuid16::EDDYSTONE])
+        };
+        res.write(&[
+            (frame_len + 4) as u8,
+            AdvertisementDataType::SERVICE_DATA_16.to_u8(),
+            0xaa,
+            0xfe,
+            frame_type,
+        ])
+    }
+
+    /// Add an Eddystone-UID frame, along with the Eddystone service UUID list
+    /// unless it was already added.
+    ///
+    /// `tx_power` is the calibrated TX power at 0 m, in dBm.
+    pub const fn eddystone_uid(self, tx_power: i8, namespace: &[u8; 10], instance: &[u8; 6]) -> Self {
+        self.eddystone(0x00, 19)
+            .write(&[tx_power as u8])
+            .write(namespace)
+            .write(instance)
+            .write(&[0x00, 0x00])
+    }
+
+    /// Add an Eddystone-URL frame, along with the Eddystone service UUID list
+    /// unless it was already added.
+    ///
+    /// `tx_power` is the calibrated TX power at 0 m, in dBm.
+    pub const fn eddystone_url(self, tx_power: i8, url: &EddystoneUrl) -> Self {
+        self.eddystone(0x10, 1 + url.len)
+            .write(&[tx_power as u8])
+            .write(url.as_bytes())
+    }
+
+    /// Add an unencrypted Eddystone-TLM frame, along with the Eddystone service UUID list
+    /// unless it was already added.
+    pub const fn eddystone_tlm(self, tlm: &EddystoneTlm) -> Self {
+        let battery = tlm.battery_mv.to_be_bytes();
+        let temperature = match tlm.temperature {
+            Some(t) => t.to_bits().to_be_bytes(),
+            None => [0x80, 0x00],
+        };
+        let adv_count = tlm.adv_count.to_be_bytes();
+        let uptime = tlm.uptime.to_be_bytes();
+        self.eddystone(0x20, 13)
+            .write(&[0x00, battery[0], battery[1], temperature[0], temperature[1]])
+            .write(&adv_count)
+            .write(&uptime)
+    }
+
+    /// Add an Eddystone-EID frame, along with the Eddystone service UUID list
+    /// unless it was already added.
+    ///
+    /// `tx_power` is the calibrated TX power at 0 m, in dBm.
+    pub const fn eddystone_eid(self, tx_power: i8, eid: &[u8; 8]) -> Self {
+        self.eddystone(0x30, 9).write(&[tx_power as u8]).write(eid)
+    }
+}
+
+const EDDYSTONE_URL_SCHEMES: [&str; 4] = ["http://www.", "https://www.", "http://", "https://"];
+const EDDYSTONE_URL_SUFFIXES: [&str; 14] = [
+    ".com/", ".org/", ".edu/", ".net/", ".info/", ".biz/", ".gov/", ".com", ".org", ".edu", ".net", ".info", ".biz",
+    ".gov",
+];
+const EDDYSTONE_URL_MAX_LEN: usize = 17;
+
+const fn has_prefix_at(s: &[u8], at: usize, prefix: &str) -> bool {
+    let prefix = prefix.as_bytes();
+    if at + prefix.len() > s.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < prefix.len() {
+        if s[at + i] != prefix[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// A URL compressed with the Eddystone-URL scheme and suffix encoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(Format))]
+pub struct EddystoneUrl {
+    buf: [u8; 1 + EDDYSTONE_URL_MAX_LEN],
+    len: usize,
+}
+
+impl EddystoneUrl {
+    /// Compress a URL.
+    ///
+    /// Returns `None` if the URL does not start with `http://` or `https://` (optionally followed by `www.`),
+    /// contains non-printable or non-ASCII characters, or is longer than 17 bytes after compression.
+    pub const fn new(url: &str) -> Option<Self> {
+        let url = url.as_bytes();
+        let mut buf = [0; 1 + EDDYSTONE_URL_MAX_LEN];
+
+        let mut scheme = 0;
+        let mut pos = 0;
+        let mut found = false;
+        // Longest schemes are listed first where one is a prefix of another, so the first match wins.
+        while scheme < EDDYSTONE_URL_SCHEMES.len() {
+            if has_prefix_at(url, 0, EDDYSTONE_URL_SCHEMES[scheme]) {
+                pos = EDDYSTONE_URL_SCHEMES[scheme].len();
+                found = true;
+                break;
+            }
+            scheme += 1;
+        }
+        if !found {
+            return None;
+        }
+        buf[0] = scheme as u8;
+
+        let mut len = 1;
+        while pos < url.len() {
+            if len > EDDYSTONE_URL_MAX_LEN {
+                return None;
+            }
+
+            let mut suffix = 0;
+            let mut encoded = false;
+            while suffix < EDDYSTONE_URL_SUFFIXES.len() {
+                if has_prefix_at(url, pos, EDDYSTONE_URL_SUFFIXES[suffix]) {
+                    buf[len] = suffix as u8;
+                    pos += EDDYSTONE_URL_SUFFIXES[suffix].len();
+                    encoded = true;
+                    break;
+                }
+                suffix += 1;
+            }
+
+            if !encoded {
+                let c = url[pos];
+                if c <= 0x20 || c >= 0x7f {
+                    return None;
+                }
+                buf[len] = c;
+                pos += 1;
+            }
+            len += 1;
+        }
+
+        Some(Self { buf, len })
+    }
+
+    /// The encoded scheme prefix followed by the encoded URL.
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.buf.split_at(self.len).0
+    }
+}
+
+/// Telemetry for an unencrypted Eddystone-TLM frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(Format))]
+pub struct EddystoneTlm {
+    /// Battery voltage in mV, or 0 if not supported.
+    pub battery_mv: u16,
+    /// Beacon temperature in Celsius, or `None` if not supported.
+    #[cfg_attr(feature = "defmt", defmt(Debug2Format))]
+    pub temperature: Option<I8F8>,
+    /// Number of advertising frames sent since power-up or reboot.
+    pub adv_count: u32,
+    /// Time since power-up or reboot, in 0.1 s units.
+    pub uptime: u32,
+}
+
+impl EddystoneTlm {
+    /// Fill the telemetry from the softdevice temperature sensor and a battery voltage callback, returning mV.
+    ///
+    /// The temperature is left unset if it cannot be read.
+    pub fn measure(sd: &Softdevice, battery_mv: impl FnOnce() -> u16, adv_count: u32, uptime: u32) -> Self {
+        Self {
+            battery_mv: battery_mv(),
+            temperature: crate::temperature_celsius(sd).ok().map(I8F8::saturating_from_num),
+            adv_count,
+            uptime,
+        }
+    }
 }
 
 pub type LegacyAdvertisementBuilder = AdvertisementBuilder<LEGACY_PAYLOAD_LEN>;
@@ -349,3 +690,215 @@ pub type ExtendedAdvertisementBuilder = AdvertisementBuilder<EXTENDED_PAYLOAD_LE
 
 pub type LegacyAdvertisementPayload = AdvertisementPayload<LEGACY_PAYLOAD_LEN>;
 pub type ExtendedAdvertisementPayload = AdvertisementPayload<EXTENDED_PAYLOAD_LEN>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FLAGS: [Flag; 2] = [Flag::GeneralDiscovery, Flag::LE_Only];
+
+    // Region from Apple's "Turning an iOS device into an iBeacon device" (Core Location documentation).
+    const IBEACON_UUID: [u8; 16] = [
+        0x39, 0xed, 0x98, 0xff, 0x29, 0x00, 0x44, 0x1a, 0x80, 0x2f, 0x9c, 0x39, 0x8f, 0xc1, 0x99, 0xd2,
+    ];
+    const IBEACON_MAJOR: u16 = 100;
+    const IBEACON_MINOR: u16 = 1;
+
+    #[test]
+    fn ibeacon() {
+        let payload = LegacyAdvertisementBuilder::new()
+            .flags(&FLAGS)
+            .ibeacon(&IBEACON_UUID, IBEACON_MAJOR, IBEACON_MINOR, -59)
+            .build();
+        // Layout of the Apple Proximity Beacon Specification: flags, then manufacturer data with company
+        // 0x004c, type 0x02, length 0x15, UUID, major and minor big-endian, and the measured power.
+        assert_eq!(
+            &*payload,
+            &[
+                0x02, 0x01, 0x06, 0x1a, 0xff, 0x4c, 0x00, 0x02, 0x15, 0x39, 0xed, 0x98, 0xff, 0x29, 0x00, 0x44, 0x1a,
+                0x80, 0x2f, 0x9c, 0x39, 0x8f, 0xc1, 0x99, 0xd2, 0x00, 0x64, 0x00, 0x01, 0xc5,
+            ]
+        );
+    }
+
+    #[test]
+    fn altbeacon() {
+        // Example advertisement of the AltBeacon specification: beacon id
+        // 2F234454-CF6D-4A0F-ADF2-F4911BA9FFA6-0001-0002 from Radius Networks, -59 dBm at 1 m.
+        let beacon_id = [
+            0x2f, 0x23, 0x44, 0x54, 0xcf, 0x6d, 0x4a, 0x0f, 0xad, 0xf2, 0xf4, 0x91, 0x1b, 0xa9, 0xff, 0xa6, 0x00, 0x01,
+            0x00, 0x02,
+        ];
+        let payload = LegacyAdvertisementBuilder::new()
+            .flags(&FLAGS)
+            .altbeacon(0x0118, &beacon_id, -59, 0)
+            .build();
+        assert_eq!(&payload[..3], &[0x02, 0x01, 0x06]);
+        assert_eq!(
+            &payload[3..],
+            &[
+                0x1b, 0xff, 0x18, 0x01, 0xbe, 0xac, 0x2f, 0x23, 0x44, 0x54, 0xcf, 0x6d, 0x4a, 0x0f, 0xad, 0xf2, 0xf4,
+                0x91, 0x1b, 0xa9, 0xff, 0xa6, 0x00, 0x01, 0x00, 0x02, 0xc5, 0x00,
+            ]
+        );
+    }
+
+    #[test]
+    fn eddystone_uid() {
+        // Namespace from Google's Eddystone-UID examples; the instance is arbitrary.
+        let namespace = [0xed, 0xd1, 0xeb, 0xea, 0xc0, 0x4e, 0x5d, 0xef, 0xa0, 0x17];
+        let instance = [0x01, 0x23, 0x45, 0x67, 0x89, 0xab];
+        let payload = LegacyAdvertisementBuilder::new()
+            .flags(&FLAGS)
+            .eddystone_uid(-18, &namespace, &instance)
+            .build();
+        assert_eq!(
+            &*payload,
+            &[
+                0x02, 0x01, 0x06, 0x03, 0x03, 0xaa, 0xfe, 0x17, 0x16, 0xaa, 0xfe, 0x00, 0xee, 0xed, 0xd1, 0xeb, 0xea,
+                0xc0, 0x4e, 0x5d, 0xef, 0xa0, 0x17, 0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0x00, 0x00,
+            ]
+        );
+    }
+
+    #[test]
+    fn eddystone_url() {
+        let url = EddystoneUrl::new("http://www.google.com/").unwrap();
+        let payload = LegacyAdvertisementBuilder::new()
+            .flags(&FLAGS)
+            .eddystone_url(-20, &url)
+            .build();
+        assert_eq!(
+            &*payload,
+            &[
+                0x02, 0x01, 0x06, 0x03, 0x03, 0xaa, 0xfe, 0x0d, 0x16, 0xaa, 0xfe, 0x10, 0xec, 0x00, 0x67, 0x6f, 0x6f,
+                0x67, 0x6c, 0x65, 0x00,
+            ]
+        );
+    }
+
+    #[test]
+    fn eddystone_tlm() {
+        let tlm = EddystoneTlm {
+            battery_mv: 3000,
+            temperature: Some(I8F8::from_num(24.25)),
+            adv_count: 0x1234,
+            uptime: 100,
+        };
+        let payload = LegacyAdvertisementBuilder::new().eddystone_tlm(&tlm).build();
+        assert_eq!(
+            &*payload,
+            &[
+                0x03, 0x03, 0xaa, 0xfe, 0x11, 0x16, 0xaa, 0xfe, 0x20, 0x00, 0x0b, 0xb8, 0x18, 0x40, 0x00, 0x00, 0x12,
+                0x34, 0x00, 0x00, 0x00, 0x64,
+            ]
+        );
+
+        // An unsupported temperature is sent as -128 °C.
+        let tlm = EddystoneTlm {
+            temperature: None,
+            ..tlm
+        };
+        let payload = LegacyAdvertisementBuilder::new().eddystone_tlm(&tlm).build();
+        assert_eq!(&payload[12..14], &[0x80, 0x00]);
+    }
+
+    #[test]
+    fn eddystone_eid() {
+        let payload = LegacyAdvertisementBuilder::new()
+            .eddystone_eid(-10, &[0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08])
+            .build();
+        assert_eq!(
+            &*payload,
+            &[
+                0x03, 0x03, 0xaa, 0xfe, 0x0d, 0x16, 0xaa, 0xfe, 0x30, 0xf6, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07,
+                0x08,
+            ]
+        );
+    }
+
+    #[test]
+    fn eddystone_service_list_once() {
+        let tlm = EddystoneTlm {
+            battery_mv: 0,
+            temperature: None,
+            adv_count: 0,
+            uptime: 0,
+        };
+        let payload = ExtendedAdvertisementBuilder::new()
+            .eddystone_eid(-10, &[0; 8])
+            .eddystone_tlm(&tlm)
+            .build();
+        assert_eq!(&payload[..4], &[0x03, 0x03, 0xaa, 0xfe]);
+        assert_eq!(payload[4..].windows(2).filter(|w| *w == [0x03, 0x03]).count(), 0);
+        assert_eq!(payload.len(), 4 + 14 + 18);
+
+        // A list added by the caller is reused as well.
+        let payload = LegacyAdvertisementBuilder::new()
+            .services_16(
+                ServiceList::Incomplete,
+                &[ServiceUuid16::BATTERY, ServiceUuid16::EDDYSTONE],
+            )
+            .eddystone_eid(-10, &[0; 8])
+            .build();
+        assert_eq!(&payload[..8], &[0x05, 0x02, 0x0f, 0x18, 0xaa, 0xfe, 0x0d, 0x16]);
+    }
+
+    #[test]
+    fn eddystone_url_schemes() {
+        for (url, scheme) in [
+            ("http://www.a", 0x00),
+            ("https://www.a", 0x01),
+            ("http://a", 0x02),
+            ("https://a", 0x03),
+        ] {
+            assert_eq!(EddystoneUrl::new(url).unwrap().as_bytes(), &[scheme, b'a']);
+        }
+        assert!(EddystoneUrl::new("ftp://a.com").is_none());
+        assert!(EddystoneUrl::new("www.a.com").is_none());
+    }
+
+    #[test]
+    fn eddystone_url_suffixes() {
+        for (suffix, code) in [
+            (".com/", 0x00),
+            (".org/", 0x01),
+            (".edu/", 0x02),
+            (".net/", 0x03),
+            (".info/", 0x04),
+            (".biz/", 0x05),
+            (".gov/", 0x06),
+            (".com", 0x07),
+            (".org", 0x08),
+            (".edu", 0x09),
+            (".net", 0x0a),
+            (".info", 0x0b),
+            (".biz", 0x0c),
+            (".gov", 0x0d),
+        ] {
+            let mut url = [0; 32];
+            let len = "https://a".len() + suffix.len();
+            url[..9].copy_from_slice(b"https://a");
+            url[9..len].copy_from_slice(suffix.as_bytes());
+            let url = core::str::from_utf8(&url[..len]).unwrap();
+            assert_eq!(EddystoneUrl::new(url).unwrap().as_bytes(), &[0x03, b'a', code]);
+        }
+
+        // Suffixes are expanded anywhere in the URL, not only at the end.
+        assert_eq!(
+            EddystoneUrl::new("https://goo.gl/S6zT6P").unwrap().as_bytes(),
+            b"\x03goo.gl/S6zT6P"
+        );
+        assert_eq!(
+            EddystoneUrl::new("http://www.a.com/b.org").unwrap().as_bytes(),
+            &[0x00, b'a', 0x00, b'b', 0x08]
+        );
+    }
+
+    #[test]
+    fn eddystone_url_invalid() {
+        assert!(EddystoneUrl::new("https://a b.com").is_none());
+        assert!(EddystoneUrl::new("https://abcdefghijklmnopq").is_some());
+        assert!(EddystoneUrl::new("https://abcdefghijklmnopqr").is_none());
+    }
+}
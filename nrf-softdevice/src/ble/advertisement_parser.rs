@@ -0,0 +1,471 @@
+//! Parsing of advertisement and scan response data, the counterpart of [`AdvertisementBuilder`].
+//!
+//! [`AdvertisementBuilder`]: super::advertisement_builder::AdvertisementBuilder
+
+#[cfg(feature = "defmt")]
+use defmt::Format;
+
+use super::advertisement_builder::{AdvertisementDataType, ServiceUuid16};
+use crate::ble::Appearance;
+
+/// Error returned by [`AdvertisementData::new`] for malformed data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(Format))]
+pub enum AdvParseError {
+    /// The AD element starting at `offset` extends past the end of the data.
+    Truncated { offset: usize },
+    /// The AD element starting at `offset` has a length that is invalid for its type.
+    InvalidLength {
+        offset: usize,
+        ad_type: AdvertisementDataType,
+    },
+}
+
+/// Advertisement or scan response data, validated to be a well-formed sequence of AD elements.
+///
+/// A zero length byte ends the significant part of the data; anything after it is ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(Format))]
+pub struct AdvertisementData<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> AdvertisementData<'a> {
+    /// Validate `data`.
+    ///
+    /// Fails if any AD element runs past the end of the data, or if an element of a type with a fixed
+    /// layout (flags, service lists, service data, manufacturer data, TX power, appearance, connection
+    /// interval range) has a length that does not match it.
+    pub fn new(data: &'a [u8]) -> Result<Self, AdvParseError> {
+        let mut offset = 0;
+        while offset < data.len() {
+            let len = data[offset] as usize;
+            if len == 0 {
+                break;
+            }
+            if offset + 1 + len > data.len() {
+                return Err(AdvParseError::Truncated { offset });
+            }
+
+            let ad_type = AdvertisementDataType::from_u8(data[offset + 1]);
+            if !valid_len(ad_type, len - 1) {
+                return Err(AdvParseError::InvalidLength { offset, ad_type });
+            }
+            offset += 1 + len;
+        }
+
+        Ok(Self { data })
+    }
+
+    /// The raw data, as passed to [`AdvertisementData::new`].
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Iterate over the AD elements as `(type, data)` pairs.
+    pub fn iter(&self) -> AdvertisementDataIter<'a> {
+        AdvertisementDataIter { data: self.data }
+    }
+
+    /// The data of the first AD element of the given type.
+    pub fn get(&self, ad_type: AdvertisementDataType) -> Option<&'a [u8]> {
+        self.iter().find(|(t, _)| *t == ad_type).map(|(_, data)| data)
+    }
+
+    /// The flags bits, see [`Flag`](super::advertisement_builder::Flag).
+    pub fn flags(&self) -> Option<u8> {
+        self.get(AdvertisementDataType::FLAGS).map(|data| data[0])
+    }
+
+    /// The complete local name. It is not guaranteed to be valid UTF-8.
+    pub fn full_name(&self) -> Option<&'a [u8]> {
+        self.get(AdvertisementDataType::FULL_NAME)
+    }
+
+    /// The shortened local name. It is not guaranteed to be valid UTF-8.
+    pub fn short_name(&self) -> Option<&'a [u8]> {
+        self.get(AdvertisementDataType::SHORT_NAME)
+    }
+
+    /// The complete local name if present, otherwise the shortened one.
+    pub fn name(&self) -> Option<&'a [u8]> {
+        self.full_name().or_else(|| self.short_name())
+    }
+
+    /// The TX power level, in dBm.
+    pub fn tx_power_level(&self) -> Option<i8> {
+        self.get(AdvertisementDataType::TXPOWER_LEVEL).map(|data| data[0] as i8)
+    }
+
+    /// The device appearance.
+    pub fn appearance(&self) -> Option<Appearance> {
+        self.get(AdvertisementDataType::APPEARANCE)
+            .map(|data| Appearance::from_raw(u16::from_le_bytes([data[0], data[1]])))
+    }
+
+    /// The preferred connection interval range as `(min, max)`, in 1.25ms units.
+    pub fn peripheral_connection_interval_range(&self) -> Option<(u16, u16)> {
+        self.get(AdvertisementDataType::PERIPHERAL_CONNECTION_INTERVAL_RANGE)
+            .map(|data| {
+                (
+                    u16::from_le_bytes([data[0], data[1]]),
+                    u16::from_le_bytes([data[2], data[3]]),
+                )
+            })
+    }
+
+    /// All 16-bit service uuids, from both complete and incomplete lists.
+    pub fn services_16(&self) -> impl Iterator<Item = ServiceUuid16> + 'a {
+        self.iter()
+            .filter(|(t, _)| {
+                *t == AdvertisementDataType::INCOMPLETE_16_SERVICE_LIST
+                    || *t == AdvertisementDataType::COMPLETE_16_SERVICE_LIST
+            })
+            .flat_map(|(_, data)| data.chunks_exact(2))
+            .map(|uuid| ServiceUuid16::from_u16(u16::from_le_bytes([uuid[0], uuid[1]])))
+    }
+
+    /// All 32-bit service uuids, from both complete and incomplete lists.
+    pub fn services_32(&self) -> impl Iterator<Item = u32> + 'a {
+        self.iter()
+            .filter(|(t, _)| {
+                *t == AdvertisementDataType::INCOMPLETE_32_SERVICE_LIST
+                    || *t == AdvertisementDataType::COMPLETE_32_SERVICE_LIST
+            })
+            .flat_map(|(_, data)| data.chunks_exact(4))
+            .map(|uuid| u32::from_le_bytes([uuid[0], uuid[1], uuid[2], uuid[3]]))
+    }
+
+    /// All 128-bit service uuids, from both complete and incomplete lists.
+    ///
+    /// Note that the UUIDs are in little-endian format, as in
+    /// [`AdvertisementBuilder::services_128`](super::advertisement_builder::AdvertisementBuilder::services_128).
+    pub fn services_128(&self) -> impl Iterator<Item = [u8; 16]> + 'a {
+        self.iter()
+            .filter(|(t, _)| {
+                *t == AdvertisementDataType::INCOMPLETE_128_SERVICE_LIST
+                    || *t == AdvertisementDataType::COMPLETE_128_SERVICE_LIST
+            })
+            .flat_map(|(_, data)| data.chunks_exact(16))
+            .map(|uuid| unwrap!(uuid.try_into()))
+    }
+
+    /// All service data elements.
+    pub fn service_data(&self) -> impl Iterator<Item = ServiceData<'a>> + 'a {
+        self.iter().filter_map(|(t, data)| match t {
+            AdvertisementDataType::SERVICE_DATA_16 => {
+                let (uuid, data) = data.split_at(2);
+                Some(ServiceData::Uuid16(
+                    ServiceUuid16::from_u16(u16::from_le_bytes([uuid[0], uuid[1]])),
+                    data,
+                ))
+            }
+            AdvertisementDataType::SERVICE_DATA_32 => {
+                let (uuid, data) = data.split_at(4);
+                Some(ServiceData::Uuid32(
+                    u32::from_le_bytes([uuid[0], uuid[1], uuid[2], uuid[3]]),
+                    data,
+                ))
+            }
+            AdvertisementDataType::SERVICE_DATA_128 => {
+                let (uuid, data) = data.split_at(16);
+                Some(ServiceData::Uuid128(unwrap!(uuid.try_into()), data))
+            }
+            _ => None,
+        })
+    }
+
+    /// The service data for the given 16-bit service uuid.
+    pub fn service_data_16(&self, uuid: ServiceUuid16) -> Option<&'a [u8]> {
+        self.service_data().find_map(|sd| match sd {
+            ServiceData::Uuid16(u, data) if u == uuid => Some(data),
+            _ => None,
+        })
+    }
+
+    /// The first manufacturer specific data element, as `(company_id, data)`.
+    pub fn manufacturer_data(&self) -> Option<(u16, &'a [u8])> {
+        self.get(AdvertisementDataType::MANUFACTURER_SPECIFIC_DATA).map(|data| {
+            let (company_id, data) = data.split_at(2);
+            (u16::from_le_bytes([company_id[0], company_id[1]]), data)
+        })
+    }
+}
+
+impl<'a> IntoIterator for &AdvertisementData<'a> {
+    type Item = (AdvertisementDataType, &'a [u8]);
+    type IntoIter = AdvertisementDataIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Service data of a [`AdvertisementData`] element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(Format))]
+pub enum ServiceData<'a> {
+    Uuid16(ServiceUuid16, &'a [u8]),
+    Uuid32(u32, &'a [u8]),
+    /// The UUID is in little-endian format.
+    Uuid128([u8; 16], &'a [u8]),
+}
+
+/// Iterator over the AD elements of [`AdvertisementData`].
+#[derive(Debug, Clone)]
+pub struct AdvertisementDataIter<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Iterator for AdvertisementDataIter<'a> {
+    type Item = (AdvertisementDataType, &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // The data was validated by `AdvertisementData::new`, so the element fits.
+        let len = *self.data.first()? as usize;
+        if len == 0 {
+            self.data = &[];
+            return None;
+        }
+
+        let (element, rest) = self.data.split_at(1 + len);
+        self.data = rest;
+        Some((AdvertisementDataType::from_u8(element[1]), &element[2..]))
+    }
+}
+
+fn valid_len(ad_type: AdvertisementDataType, len: usize) -> bool {
+    match ad_type {
+        AdvertisementDataType::FLAGS => len >= 1,
+        AdvertisementDataType::INCOMPLETE_16_SERVICE_LIST
+        | AdvertisementDataType::COMPLETE_16_SERVICE_LIST
+        | AdvertisementDataType::SERVICE_SOLICITATION_16 => len % 2 == 0,
+        AdvertisementDataType::INCOMPLETE_32_SERVICE_LIST
+        | AdvertisementDataType::COMPLETE_32_SERVICE_LIST
+        | AdvertisementDataType::SERVICE_SOLICITATION_32 => len % 4 == 0,
+        AdvertisementDataType::INCOMPLETE_128_SERVICE_LIST
+        | AdvertisementDataType::COMPLETE_128_SERVICE_LIST
+        | AdvertisementDataType::SERVICE_SOLICITATION_128 => len % 16 == 0,
+        AdvertisementDataType::SERVICE_DATA_16 => len >= 2,
+        AdvertisementDataType::SERVICE_DATA_32 => len >= 4,
+        AdvertisementDataType::SERVICE_DATA_128 => len >= 16,
+        AdvertisementDataType::MANUFACTURER_SPECIFIC_DATA => len >= 2,
+        AdvertisementDataType::TXPOWER_LEVEL => len == 1,
+        AdvertisementDataType::APPEARANCE => len == 2,
+        AdvertisementDataType::PERIPHERAL_CONNECTION_INTERVAL_RANGE => len == 4,
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::vec::Vec;
+
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::ble::advertisement_builder::{ExtendedAdvertisementBuilder, Flag, ServiceList};
+
+    #[test]
+    fn round_trip() {
+        let payload = ExtendedAdvertisementBuilder::new()
+            .flags(&[Flag::GeneralDiscovery, Flag::LE_Only])
+            .services_16(
+                ServiceList::Complete,
+                &[ServiceUuid16::BATTERY, ServiceUuid16::HEART_RATE],
+            )
+            .services_32(ServiceList::Incomplete, &[0x11223344])
+            .services_128(ServiceList::Complete, &[[0x77; 16]])
+            .service_data_16(ServiceUuid16::BATTERY, &[0x64])
+            .service_data_32(0xaabbccdd, &[0x01])
+            .service_data_128(&[0x11; 16], &[0x02, 0x03])
+            .manufacturer_data(0x0059, &[0x01, 0x02])
+            .appearance(Appearance::GenericTag)
+            .tx_power_level(-4)
+            .peripheral_connection_interval_range(6, 12)
+            .uri("https://example.com")
+            .short_name("hi")
+            .full_name("hello")
+            .raw(AdvertisementDataType::from_u8(0x2a), &[0xde, 0xad])
+            .build();
+        let data = AdvertisementData::new(&payload).unwrap();
+
+        assert_eq!(data.as_bytes(), &*payload);
+        assert_eq!(data.iter().count(), 15);
+        assert_eq!(data.flags(), Some(0x06));
+        assert!(data
+            .services_16()
+            .eq([ServiceUuid16::BATTERY, ServiceUuid16::HEART_RATE]));
+        assert!(data.services_32().eq([0x11223344]));
+        assert!(data.services_128().eq([[0x77; 16]]));
+        assert!(data.service_data().eq([
+            ServiceData::Uuid16(ServiceUuid16::BATTERY, &[0x64]),
+            ServiceData::Uuid32(0xaabbccdd, &[0x01]),
+            ServiceData::Uuid128([0x11; 16], &[0x02, 0x03]),
+        ]));
+        assert_eq!(data.service_data_16(ServiceUuid16::BATTERY), Some(&[0x64][..]));
+        assert_eq!(data.service_data_16(ServiceUuid16::HEART_RATE), None);
+        assert_eq!(data.manufacturer_data(), Some((0x0059, &[0x01, 0x02][..])));
+        assert_eq!(data.appearance(), Some(Appearance::GenericTag));
+        assert_eq!(data.tx_power_level(), Some(-4));
+        assert_eq!(data.peripheral_connection_interval_range(), Some((6, 12)));
+        assert_eq!(data.get(AdvertisementDataType::URI), Some(&b"\x17//example.com"[..]));
+        assert_eq!(data.short_name(), Some(&b"hi"[..]));
+        assert_eq!(data.full_name(), Some(&b"hello"[..]));
+        assert_eq!(data.name(), Some(&b"hello"[..]));
+        assert_eq!(data.get(AdvertisementDataType::from_u8(0x2a)), Some(&[0xde, 0xad][..]));
+    }
+
+    #[test]
+    fn zero_length() {
+        let data = AdvertisementData::new(&[]).unwrap();
+        assert_eq!(data.iter().next(), None);
+
+        // A zero length ends the significant part, even if garbage follows.
+        let data = AdvertisementData::new(&[0x02, 0x01, 0x06, 0x00, 0xff, 0x0a]).unwrap();
+        assert!(data.iter().eq([(AdvertisementDataType::FLAGS, &[0x06][..])]));
+        assert_eq!(data.tx_power_level(), None);
+
+        let data = AdvertisementData::new(&[0x00; 31]).unwrap();
+        assert_eq!(data.iter().next(), None);
+
+        // An element with a type but no data is fine for types without a fixed layout.
+        let data = AdvertisementData::new(&[0x01, 0x09]).unwrap();
+        assert_eq!(data.full_name(), Some(&[][..]));
+    }
+
+    #[test]
+    fn overrunning_length() {
+        assert_eq!(
+            AdvertisementData::new(&[0x02, 0x01, 0x06, 0x05, 0xff, 0x59, 0x00]),
+            Err(AdvParseError::Truncated { offset: 3 })
+        );
+        assert_eq!(
+            AdvertisementData::new(&[0xff, 0x09, b'a']),
+            Err(AdvParseError::Truncated { offset: 0 })
+        );
+    }
+
+    #[test]
+    fn truncated_element() {
+        // A length byte without the type.
+        assert_eq!(
+            AdvertisementData::new(&[0x02, 0x01, 0x06, 0x01]),
+            Err(AdvParseError::Truncated { offset: 3 })
+        );
+        // Fixed layout types cut short.
+        assert_eq!(
+            AdvertisementData::new(&[0x02, 0x03, 0x0f]),
+            Err(AdvParseError::InvalidLength {
+                offset: 0,
+                ad_type: AdvertisementDataType::COMPLETE_16_SERVICE_LIST
+            })
+        );
+        assert_eq!(
+            AdvertisementData::new(&[0x02, 0x01, 0x06, 0x03, 0x0a, 0x01, 0x02]),
+            Err(AdvParseError::InvalidLength {
+                offset: 3,
+                ad_type: AdvertisementDataType::TXPOWER_LEVEL
+            })
+        );
+        assert_eq!(
+            AdvertisementData::new(&[
+                0x10, 0x21, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e
+            ]),
+            Err(AdvParseError::InvalidLength {
+                offset: 0,
+                ad_type: AdvertisementDataType::SERVICE_DATA_128
+            })
+        );
+        assert_eq!(
+            AdvertisementData::new(&[0x02, 0xff, 0x59]),
+            Err(AdvParseError::InvalidLength {
+                offset: 0,
+                ad_type: AdvertisementDataType::MANUFACTURER_SPECIFIC_DATA
+            })
+        );
+    }
+
+    // Call every accessor; none of them may panic on data that `AdvertisementData::new` accepted.
+    fn exercise(data: &AdvertisementData<'_>) {
+        for (ad_type, _) in data {
+            assert!(data.get(ad_type).is_some());
+        }
+        let _ = (
+            data.flags(),
+            data.name(),
+            data.tx_power_level(),
+            data.appearance(),
+            data.peripheral_connection_interval_range(),
+            data.manufacturer_data(),
+            data.service_data_16(ServiceUuid16::BATTERY),
+        );
+        let _ = data.services_16().count()
+            + data.services_32().count()
+            + data.services_128().count()
+            + data.service_data().count();
+    }
+
+    // An AD element as `(type, data)`, often of a type with a fixed layout so that its length checks are hit.
+    fn element() -> impl Strategy<Value = (u8, Vec<u8>)> {
+        let ad_type = prop_oneof![
+            any::<u8>(),
+            prop::sample::select(
+                &[
+                    0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x0a, 0x12, 0x14, 0x15, 0x16, 0x19, 0x1f, 0x20, 0x21,
+                    0xff,
+                ][..]
+            ),
+        ];
+        let value = prop_oneof![
+            prop::collection::vec(any::<u8>(), 0..4),
+            prop::collection::vec(any::<u8>(), 0..40),
+        ];
+        (ad_type, value)
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(2048))]
+
+        #[test]
+        fn arbitrary_bytes(bytes in prop::collection::vec(any::<u8>(), 0..=255)) {
+            if let Ok(data) = AdvertisementData::new(&bytes) {
+                exercise(&data);
+            }
+        }
+
+        #[test]
+        fn arbitrary_elements(elements in prop::collection::vec(element(), 0..8)) {
+            let mut bytes = Vec::new();
+            for (ad_type, value) in &elements {
+                bytes.push(value.len() as u8 + 1);
+                bytes.push(*ad_type);
+                bytes.extend_from_slice(value);
+            }
+            let valid = elements
+                .iter()
+                .all(|(ad_type, value)| valid_len(AdvertisementDataType::from_u8(*ad_type), value.len()));
+
+            match AdvertisementData::new(&bytes) {
+                Ok(data) => {
+                    prop_assert!(valid);
+                    prop_assert!(data
+                        .iter()
+                        .map(|(ad_type, value)| (ad_type.to_u8(), value))
+                        .eq(elements.iter().map(|(ad_type, value)| (*ad_type, &value[..]))));
+                    exercise(&data);
+                }
+                Err(_) => prop_assert!(!valid),
+            }
+
+            // Cut anywhere, the data is either rejected or still safe to read.
+            for len in 0..bytes.len() {
+                if let Ok(data) = AdvertisementData::new(&bytes[..len]) {
+                    exercise(&data);
+                }
+            }
+        }
+    }
+}
@@ -3,10 +3,21 @@
 //! Typically the Central device is the higher-powered device, such as a smartphone or laptop, since scanning is more
 //! power-hungry than advertising.
 
+use core::cell::RefCell;
+use core::marker::PhantomPinned;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
 use core::{mem, ptr};
 
+use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
+use embassy_sync::blocking_mutex::Mutex;
+use futures::Stream;
+use heapless::{Deque, Vec};
+
+use crate::ble::advertisement_builder::ServiceUuid16;
+use crate::ble::advertisement_parser::{AdvParseError, AdvertisementData, ServiceData};
 use crate::ble::types::*;
-use crate::ble::{Address, Connection, OutOfConnsError};
+use crate::ble::{set_device_identities_list, Address, Connection, IdentityKey, OutOfConnsError};
 use crate::util::{get_union_field, OnDrop, Portal};
 use crate::{raw, RawError, Softdevice};
 
@@ -40,7 +51,7 @@ impl From<RawError> for ConnectError {
 pub(crate) static CONNECT_PORTAL: Portal<*const raw::ble_evt_t> = Portal::new();
 
 pub async fn connect(sd: &Softdevice, config: &ConnectConfig<'_>) -> Result<Connection, ConnectError> {
-    connect_inner(sd, config, Connection::new).await
+    connect_inner(sd, None, config, Connection::new).await
 }
 
 #[cfg(feature = "ble-sec")]
@@ -49,26 +60,88 @@ pub async fn connect_with_security(
     config: &ConnectConfig<'_>,
     security_handler: &'static dyn crate::ble::security::SecurityHandler,
 ) -> Result<Connection, ConnectError> {
-    connect_inner(sd, config, |conn_handle, role, peer_address, conn_params| {
+    connect_inner(sd, None, config, |conn_handle, role, peer_address, conn_params| {
         Connection::with_security_handler(conn_handle, role, peer_address, conn_params, security_handler)
     })
     .await
 }
 
+/// Connect to a single peer, without touching the whitelist. `config.scan_config.whitelist` is ignored.
+///
+/// For a bonded peer that uses resolvable private addresses, pass its identity address after adding its
+/// [`IdentityKey`] to the device identity list, or use [`connect_to_identity`].
+pub async fn connect_to(
+    sd: &Softdevice,
+    address: &Address,
+    config: &ConnectConfig<'_>,
+) -> Result<Connection, ConnectError> {
+    connect_inner(sd, Some(address), config, Connection::new).await
+}
+
+#[cfg(feature = "ble-sec")]
+pub async fn connect_to_with_security(
+    sd: &Softdevice,
+    address: &Address,
+    config: &ConnectConfig<'_>,
+    security_handler: &'static dyn crate::ble::security::SecurityHandler,
+) -> Result<Connection, ConnectError> {
+    connect_inner(
+        sd,
+        Some(address),
+        config,
+        |conn_handle, role, peer_address, conn_params| {
+            Connection::with_security_handler(conn_handle, role, peer_address, conn_params, security_handler)
+        },
+    )
+    .await
+}
+
+/// Connect to a bonded peer by its identity address, whatever private address it currently uses.
+///
+/// The device identity list is replaced with `identities`, which must contain `peer`. Pass all bonded
+/// peers, not just this one, so that address resolution keeps working for the others.
+pub async fn connect_to_identity(
+    sd: &Softdevice,
+    identities: &[IdentityKey],
+    peer: &Address,
+    config: &ConnectConfig<'_>,
+) -> Result<Connection, ConnectError> {
+    if !identities.iter().any(|id| id.addr == *peer) {
+        return Err(ConnectError::NoAddresses);
+    }
+    if let Err(err) = set_device_identities_list(sd, identities, None) {
+        warn!("sd_ble_gap_device_identities_set err {:?}", err);
+        return Err(err.into());
+    }
+    connect_inner(sd, Some(peer), config, Connection::new).await
+}
+
 // Begins an ATT MTU exchange procedure, followed by a data length update request as necessary.
-async fn connect_inner<F>(_sd: &Softdevice, config: &ConnectConfig<'_>, new_conn: F) -> Result<Connection, ConnectError>
+async fn connect_inner<F>(
+    _sd: &Softdevice,
+    peer: Option<&Address>,
+    config: &ConnectConfig<'_>,
+    new_conn: F,
+) -> Result<Connection, ConnectError>
 where
     F: Fn(u16, Role, Address, raw::ble_gap_conn_params_t) -> Result<Connection, OutOfConnsError>,
 {
-    if let Some(w) = config.scan_config.whitelist {
-        if w.len() == 0 {
-            return Err(ConnectError::NoAddresses);
+    let scan_params = match peer {
+        // The peer address is ignored by the softdevice when scanning with a whitelist.
+        Some(_) => ScanConfig {
+            whitelist: None,
+            ..config.scan_config
         }
-    } else {
-        return Err(ConnectError::NoAddresses);
-    }
-
-    let scan_params = config.scan_config.to_raw()?;
+        .to_raw()?,
+        None => {
+            match config.scan_config.whitelist {
+                Some(w) if !w.is_empty() => {}
+                _ => return Err(ConnectError::NoAddresses),
+            }
+            config.scan_config.to_raw()?
+        }
+    };
+    let p_peer_addr = peer.map(|addr| addr.as_raw() as *const _).unwrap_or(ptr::null());
 
     let d = OnDrop::new(|| {
         let ret = unsafe { raw::sd_ble_gap_connect_cancel() };
@@ -77,7 +150,7 @@ where
         }
     });
 
-    let ret = unsafe { raw::sd_ble_gap_connect(ptr::null(), &scan_params, &config.conn_params, 1) };
+    let ret = unsafe { raw::sd_ble_gap_connect(p_peer_addr, &scan_params, &config.conn_params, 1) };
     if let Err(err) = RawError::convert(ret) {
         warn!("sd_ble_gap_connect err {:?}", err);
         return Err(err.into());
@@ -155,6 +228,8 @@ impl<'a> Default for ConnectConfig<'a> {
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum ScanError {
     Timeout,
+    /// Another [`Scanner`] is already active. Only one can run at a time.
+    AlreadyScanning,
     Raw(RawError),
 }
 
@@ -166,26 +241,392 @@ impl From<RawError> for ScanError {
 
 pub(crate) static SCAN_PORTAL: Portal<*const raw::ble_evt_t> = Portal::new();
 
-pub async fn scan<'a, F, R>(_sd: &Softdevice, config: &ScanConfig<'a>, mut f: F) -> Result<R, ScanError>
+// Buffer to store received advertisement data.
+const BUF_LEN: usize = 256;
+
+// Both of these are intentionally static because Softdevice will,
+// sometimes, write to the buffer after scan_stop() has been
+// called, somewhere around evt_get().
+//
+// This can result in UB as a use-after-free, given the buffer
+// has been dropped and the scanning has been stopped.
+static mut BUF: [u8; BUF_LEN] = [0u8; BUF_LEN];
+static mut BUF_DATA: raw::ble_data_t = raw::ble_data_t {
+    p_data: unsafe { (&mut *(&raw mut BUF)).as_mut_ptr() },
+    len: BUF_LEN as u16,
+};
+
+/// Kind of advertising PDU a [`ScanReport`] was received from.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ScanReportKind {
+    /// Legacy `ADV_IND`.
+    ConnectableScannableUndirected,
+    /// Legacy `ADV_DIRECT_IND`.
+    ConnectableDirected,
+    /// Legacy `ADV_SCAN_IND`.
+    ScannableUndirected,
+    /// Legacy `ADV_NONCONN_IND`.
+    NonconnectableUndirected,
+    /// Legacy `SCAN_RSP`.
+    ScanResponse,
+    /// Extended advertising PDU.
+    Extended {
+        connectable: bool,
+        scannable: bool,
+        directed: bool,
+        scan_response: bool,
+    },
+}
+
+/// Completeness of the data in a [`ScanReport`]. Only extended advertisements can be incomplete.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum DataStatus {
+    Complete,
+    /// More data will follow in another report.
+    IncompleteMoreData,
+    /// The data was truncated, no more data will follow.
+    IncompleteTruncated,
+    /// A fragment was missed, no more data will follow.
+    IncompleteMissed,
+}
+
+/// An advertising report received while scanning.
+#[derive(Clone, Copy)]
+pub struct ScanReport<'a> {
+    raw: &'a raw::ble_gap_evt_adv_report_t,
+    data: &'a [u8],
+    status: DataStatus,
+}
+
+impl<'a> ScanReport<'a> {
+    /// Wrap a raw advertising report.
+    ///
+    /// # Safety
+    ///
+    /// `raw.data` must point to `raw.data.len` bytes that stay valid for `'a`.
+    pub unsafe fn from_raw(raw: &'a raw::ble_gap_evt_adv_report_t) -> Self {
+        let data = if raw.data.p_data.is_null() {
+            &[]
+        } else {
+            core::slice::from_raw_parts(raw.data.p_data, raw.data.len as usize)
+        };
+        let status = match raw.type_.status() {
+            0 => DataStatus::Complete,
+            1 => DataStatus::IncompleteMoreData,
+            2 => DataStatus::IncompleteTruncated,
+            _ => DataStatus::IncompleteMissed,
+        };
+        Self { raw, data, status }
+    }
+
+    /// The underlying softdevice report, for fields not exposed here.
+    pub fn raw(&self) -> &'a raw::ble_gap_evt_adv_report_t {
+        self.raw
+    }
+
+    /// Address of the advertiser. If it was resolved with a known IRK, this is its identity address.
+    pub fn peer_address(&self) -> Address {
+        Address::from_raw(self.raw.peer_addr)
+    }
+
+    /// Target address of a directed advertisement.
+    pub fn direct_address(&self) -> Option<Address> {
+        (self.raw.type_.directed() != 0).then(|| Address::from_raw(self.raw.direct_addr))
+    }
+
+    pub fn kind(&self) -> ScanReportKind {
+        let t = &self.raw.type_;
+        if t.extended_pdu() != 0 {
+            ScanReportKind::Extended {
+                connectable: t.connectable() != 0,
+                scannable: t.scannable() != 0,
+                directed: t.directed() != 0,
+                scan_response: t.scan_response() != 0,
+            }
+        } else if t.scan_response() != 0 {
+            ScanReportKind::ScanResponse
+        } else if t.directed() != 0 {
+            ScanReportKind::ConnectableDirected
+        } else {
+            match (t.connectable() != 0, t.scannable() != 0) {
+                (true, _) => ScanReportKind::ConnectableScannableUndirected,
+                (false, true) => ScanReportKind::ScannableUndirected,
+                (false, false) => ScanReportKind::NonconnectableUndirected,
+            }
+        }
+    }
+
+    pub fn data_status(&self) -> DataStatus {
+        self.status
+    }
+
+    /// RSSI in dBm.
+    pub fn rssi(&self) -> i8 {
+        self.raw.rssi
+    }
+
+    /// Channel index the report was received on.
+    pub fn ch_index(&self) -> u8 {
+        self.raw.ch_index
+    }
+
+    pub fn primary_phy(&self) -> Option<Phy> {
+        Phy::try_from_raw(self.raw.primary_phy)
+    }
+
+    /// PHY of the auxiliary packets, for extended advertisements.
+    pub fn secondary_phy(&self) -> Option<Phy> {
+        Phy::try_from_raw(self.raw.secondary_phy)
+    }
+
+    /// TX power reported by the advertiser, in dBm.
+    pub fn tx_power(&self) -> Option<i8> {
+        (self.raw.tx_power as u32 != raw::BLE_GAP_POWER_LEVEL_INVALID).then_some(self.raw.tx_power)
+    }
+
+    /// Advertising set id, for extended advertisements.
+    pub fn set_id(&self) -> Option<u8> {
+        (self.raw.set_id as u32 != raw::BLE_GAP_ADV_REPORT_SET_ID_NOT_AVAILABLE).then_some(self.raw.set_id)
+    }
+
+    /// Advertising data id, for extended advertisements.
+    pub fn data_id(&self) -> u16 {
+        self.raw.data_id()
+    }
+
+    /// The advertising or scan response data.
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// The data, parsed into AD elements.
+    pub fn advertisement_data(&self) -> Result<AdvertisementData<'a>, AdvParseError> {
+        AdvertisementData::new(self.data)
+    }
+}
+
+impl<'a> core::fmt::Debug for ScanReport<'a> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ScanReport")
+            .field("peer_address", &self.peer_address())
+            .field("kind", &self.kind())
+            .field("data_status", &self.data_status())
+            .field("rssi", &self.rssi())
+            .field("data", &self.data)
+            .finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl<'a> defmt::Format for ScanReport<'a> {
+    fn format(&self, fmt: defmt::Formatter) {
+        defmt::write!(
+            fmt,
+            "ScanReport {{ peer_address: {}, kind: {}, data_status: {}, rssi: {}, data: {:x} }}",
+            self.peer_address(),
+            self.kind(),
+            self.data_status(),
+            self.rssi(),
+            self.data
+        )
+    }
+}
+
+// Number of fragmented extended advertisements a `ScanBuffer` remembers having skipped or evicted, so that
+// their last fragment is not mistaken for a complete advertisement.
+const SKIPPED_CHAINS: usize = 4;
+
+// Number of extended advertising reports after which a fragmented advertisement that received no further
+// fragment is considered abandoned, and its slot can be taken by a new one.
+const STALE_CHAIN_REPORTS: u32 = 32;
+
+struct Chain<const N: usize> {
+    key: Option<(Address, u8)>,
+    data_id: u16,
+    updated: u32,
+    len: usize,
+    overflow: bool,
+    data: [u8; N],
+}
+
+impl<const N: usize> Chain<N> {
+    const EMPTY: Self = Self {
+        key: None,
+        data_id: 0,
+        updated: 0,
+        len: 0,
+        overflow: false,
+        data: [0; N],
+    };
+}
+
+/// Reassembly of fragmented extended advertising data.
+///
+/// The softdevice reports long extended advertising data in fragments of at most 255 bytes. A `ScanBuffer`
+/// collects the fragments of up to `C` advertisements at a time, keyed by advertiser address and set id,
+/// each up to `N` bytes. Extended advertising data is at most 1650 bytes.
+///
+/// Reports handed to the application are always final: their [`ScanReport::data_status`] is
+/// [`DataStatus::IncompleteTruncated`] if the data did not fit in `N` bytes, or if there was no free slot
+/// when the first fragment arrived or the advertisement was evicted, in which case the data is empty.
+///
+/// An advertisement whose remaining fragments never arrive is discarded when the same advertiser starts
+/// one with a new data id, or when its slot is needed and it has not received a fragment in a while.
+pub struct ScanBuffer<const N: usize, const C: usize = 1> {
+    chains: [Chain<N>; C],
+    skipped: Vec<((Address, u8), u16), SKIPPED_CHAINS>,
+    reports: u32,
+}
+
+impl<const N: usize, const C: usize> ScanBuffer<N, C> {
+    pub const fn new() -> Self {
+        Self {
+            chains: [Chain::EMPTY; C],
+            skipped: Vec::new(),
+            reports: 0,
+        }
+    }
+
+    fn skip(&mut self, key: (Address, u8), data_id: u16) {
+        if self.skipped.is_full() {
+            self.skipped.remove(0);
+        }
+        let _ = self.skipped.push((key, data_id));
+    }
+
+    /// A free slot, or the least recently updated one if it is stale.
+    fn free_slot(&mut self) -> Option<usize> {
+        if let Some(index) = self.chains.iter().position(|c| c.key.is_none()) {
+            return Some(index);
+        }
+
+        let reports = self.reports;
+        let (index, chain) = self
+            .chains
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, c)| reports.wrapping_sub(c.updated))?;
+        if reports.wrapping_sub(chain.updated) <= STALE_CHAIN_REPORTS {
+            return None;
+        }
+
+        debug!("evicting stale fragmented advertisement from scan buffer");
+        // Its remaining fragments, if they ever arrive, must not be taken for a complete advertisement.
+        let (key, data_id) = (unwrap!(chain.key), chain.data_id);
+        self.skip(key, data_id);
+        Some(index)
+    }
+
+    /// Feed a report. Returns the report to pass on, if this one completes an advertisement.
+    fn push<'r>(&'r mut self, report: &ScanReport<'r>) -> Option<ScanReport<'r>> {
+        // Legacy advertisements are never fragmented.
+        if !matches!(report.kind(), ScanReportKind::Extended { .. }) {
+            return Some(*report);
+        }
+
+        self.reports = self.reports.wrapping_add(1);
+        let more_data = report.data_status() == DataStatus::IncompleteMoreData;
+        let key = (report.peer_address(), report.raw.set_id);
+        let data_id = report.data_id();
+        let index = match self.chains.iter().position(|c| c.key == Some(key)) {
+            Some(index) if self.chains[index].data_id == data_id => index,
+            Some(index) => {
+                // The advertiser moved on to new data, so the rest of the old advertisement is lost.
+                debug!("fragmented advertisement superseded before completing, discarding it");
+                if !more_data {
+                    self.chains[index].key = None;
+                    return Some(*report);
+                }
+                index
+            }
+            None => {
+                if let Some(i) = self.skipped.iter().position(|k| *k == (key, data_id)) {
+                    if more_data {
+                        return None;
+                    }
+                    self.skipped.swap_remove(i);
+                    return Some(ScanReport {
+                        raw: report.raw,
+                        data: &[],
+                        status: DataStatus::IncompleteTruncated,
+                    });
+                }
+                if !more_data {
+                    return Some(*report);
+                }
+
+                let Some(index) = self.free_slot() else {
+                    debug!("no free scan buffer slot, skipping fragmented advertisement");
+                    self.skip(key, data_id);
+                    return None;
+                };
+                index
+            }
+        };
+
+        let chain = &mut self.chains[index];
+        if chain.key != Some(key) || chain.data_id != data_id {
+            chain.key = Some(key);
+            chain.data_id = data_id;
+            chain.len = 0;
+            chain.overflow = false;
+        }
+        chain.updated = self.reports;
+        let data = report.data();
+        if !chain.overflow && chain.len + data.len() <= N {
+            chain.data[chain.len..chain.len + data.len()].copy_from_slice(data);
+            chain.len += data.len();
+        } else {
+            chain.overflow = true;
+        }
+        if more_data {
+            return None;
+        }
+
+        chain.key = None;
+        let status = match report.data_status() {
+            DataStatus::Complete if chain.overflow => DataStatus::IncompleteTruncated,
+            status => status,
+        };
+        Some(ScanReport {
+            raw: report.raw,
+            data: &chain.data[..chain.len],
+            status,
+        })
+    }
+}
+
+impl<const N: usize, const C: usize> Default for ScanBuffer<N, C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Scan, calling `f` for each advertisement until it returns `Some`.
+///
+/// Fragmented extended advertisements are reassembled into a [`ScanBuffer`] of 256 bytes; use
+/// [`scan_with_buffer`] to receive longer ones.
+pub async fn scan<'a, F, R>(sd: &Softdevice, config: &ScanConfig<'a>, f: F) -> Result<R, ScanError>
 where
-    F: for<'b> FnMut(&'b raw::ble_gap_evt_adv_report_t) -> Option<R>,
+    F: for<'b> FnMut(&ScanReport<'b>) -> Option<R>,
 {
-    let scan_params = config.to_raw()?;
+    let mut buffer = ScanBuffer::<BUF_LEN>::new();
+    scan_with_buffer(sd, config, &mut buffer, f).await
+}
 
-    // Buffer to store received advertisement data.
-    const BUF_LEN: usize = 256;
-
-    // Both of these are intentionally static because Softdevice will,
-    // sometimes, write to the buffer after scan_stop() has been
-    // called, somewhere around evt_get().
-    //
-    // This can result in UB as a use-after-free, given the buffer
-    // has been dropped and the scanning has been stopped.
-    static mut BUF: [u8; BUF_LEN] = [0u8; BUF_LEN];
-    static mut BUF_DATA: raw::ble_data_t = raw::ble_data_t {
-        p_data: unsafe { (&mut *(&raw mut BUF)).as_mut_ptr() },
-        len: BUF_LEN as u16,
-    };
+/// Like [`scan`], reassembling fragmented extended advertisements into `buffer`.
+pub async fn scan_with_buffer<'a, const N: usize, const C: usize, F, R>(
+    _sd: &Softdevice,
+    config: &ScanConfig<'a>,
+    buffer: &mut ScanBuffer<N, C>,
+    mut f: F,
+) -> Result<R, ScanError>
+where
+    F: for<'b> FnMut(&ScanReport<'b>) -> Option<R>,
+{
+    let scan_params = config.to_raw()?;
 
     let ret = unsafe { raw::sd_ble_gap_scan_start(&scan_params, ptr::addr_of!(BUF_DATA)) };
     match RawError::convert(ret) {
@@ -212,9 +653,11 @@ where
                 raw::BLE_GAP_EVTS_BLE_GAP_EVT_TIMEOUT => return Some(Err(ScanError::Timeout)),
                 raw::BLE_GAP_EVTS_BLE_GAP_EVT_ADV_REPORT => {
                     let gap_evt = get_union_field(ble_evt, &(*ble_evt).evt.gap_evt);
-                    let params = &gap_evt.params.adv_report;
-                    if let Some(r) = f(params) {
-                        return Some(Ok(r));
+                    let report = ScanReport::from_raw(&gap_evt.params.adv_report);
+                    if let Some(report) = buffer.push(&report) {
+                        if let Some(r) = f(&report) {
+                            return Some(Ok(r));
+                        }
                     }
 
                     // Resume scan
@@ -240,12 +683,364 @@ where
     Ok(res)
 }
 
+/// Service UUID to match in a [`ScanFilter`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ServiceFilter {
+    Uuid16(ServiceUuid16),
+    Uuid32(u32),
+    /// The UUID is in little-endian format.
+    Uuid128([u8; 16]),
+}
+
+/// Filters applied by a [`Scanner`] before queueing a report. All filters that are set must match.
+///
+/// Filters on the advertising data only see the data of a single report, so a name sent in the scan
+/// response does not match a report of the advertisement itself.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ScanFilter<'a> {
+    /// Only report devices advertising this service, in a service list or service data.
+    pub service: Option<ServiceFilter>,
+    /// Only report devices whose full or short name starts with this prefix.
+    pub name_prefix: Option<&'a [u8]>,
+    /// Only report devices with manufacturer specific data from this company.
+    pub manufacturer_id: Option<u16>,
+    /// Only report reports with at least this RSSI, in dBm.
+    pub rssi_floor: Option<i8>,
+    /// Only report devices with one of these addresses.
+    pub addresses: Option<&'a [Address]>,
+}
+
+impl<'a> ScanFilter<'a> {
+    fn matches(&self, report: &ScanReport<'_>) -> bool {
+        if let Some(floor) = self.rssi_floor {
+            if report.rssi() < floor {
+                return false;
+            }
+        }
+        if let Some(addresses) = self.addresses {
+            if !addresses.contains(&report.peer_address()) {
+                return false;
+            }
+        }
+        if self.service.is_none() && self.name_prefix.is_none() && self.manufacturer_id.is_none() {
+            return true;
+        }
+
+        let Ok(data) = report.advertisement_data() else {
+            return false;
+        };
+        if let Some(service) = self.service {
+            let found = match service {
+                ServiceFilter::Uuid16(uuid) => {
+                    data.services_16().any(|u| u == uuid)
+                        || data
+                            .service_data()
+                            .any(|sd| matches!(sd, ServiceData::Uuid16(u, _) if u == uuid))
+                }
+                ServiceFilter::Uuid32(uuid) => {
+                    data.services_32().any(|u| u == uuid)
+                        || data
+                            .service_data()
+                            .any(|sd| matches!(sd, ServiceData::Uuid32(u, _) if u == uuid))
+                }
+                ServiceFilter::Uuid128(uuid) => {
+                    data.services_128().any(|u| u == uuid)
+                        || data
+                            .service_data()
+                            .any(|sd| matches!(sd, ServiceData::Uuid128(u, _) if u == uuid))
+                }
+            };
+            if !found {
+                return false;
+            }
+        }
+        if let Some(prefix) = self.name_prefix {
+            if !data.name().is_some_and(|name| name.starts_with(prefix)) {
+                return false;
+            }
+        }
+        if let Some(id) = self.manufacturer_id {
+            if !data.manufacturer_data().is_some_and(|(company_id, _)| company_id == id) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Time-windowed de-duplication for a [`Scanner`].
+///
+/// De-duplication is per peer address only: once a report from an address is queued, every other report
+/// from it within the window is dropped too, including its scan responses, its other advertising sets
+/// and later fragments of its extended advertisements. A report dropped because the queue was full does
+/// not count as seen.
+#[derive(Debug, Clone, Copy)]
+pub struct Dedup {
+    /// Reports from an address already reported within this window are dropped.
+    pub window_ms: u32,
+    /// Monotonic clock in milliseconds, for example `|| embassy_time::Instant::now().as_millis()`.
+    pub now_ms: fn() -> u64,
+}
+
+// Number of addresses remembered for de-duplication. The oldest entry is evicted when full.
+const DEDUP_ENTRIES: usize = 32;
+
+/// A [`ScanReport`] copied out of the softdevice buffer by a [`Scanner`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct OwnedScanReport<const D: usize> {
+    pub peer_address: Address,
+    pub kind: ScanReportKind,
+    pub data_status: DataStatus,
+    pub rssi: i8,
+    pub set_id: Option<u8>,
+    pub data: Vec<u8, D>,
+    /// The data did not fit in `D` bytes and was cut off. Reassembled extended advertisements that did
+    /// not fit are reported with [`DataStatus::IncompleteTruncated`] instead.
+    pub truncated: bool,
+}
+
+impl<const D: usize> OwnedScanReport<D> {
+    fn new(report: &ScanReport<'_>) -> Self {
+        let data = report.data();
+        let len = data.len().min(D);
+        Self {
+            peer_address: report.peer_address(),
+            kind: report.kind(),
+            data_status: report.data_status(),
+            rssi: report.rssi(),
+            set_id: report.set_id(),
+            data: unwrap!(Vec::from_slice(&data[..len])),
+            truncated: len < data.len(),
+        }
+    }
+
+    /// The data, parsed into AD elements.
+    pub fn advertisement_data(&self) -> Result<AdvertisementData<'_>, AdvParseError> {
+        AdvertisementData::new(&self.data)
+    }
+}
+
+struct ScannerState<const N: usize, const D: usize, const C: usize> {
+    buffer: ScanBuffer<D, C>,
+    queue: Deque<OwnedScanReport<D>, N>,
+    seen: Vec<(Address, u64), DEDUP_ENTRIES>,
+    waker: Option<Waker>,
+    error: Option<ScanError>,
+    dropped: u32,
+}
+
+/// Continuous scanning, consumed as a [`Stream`] of reports.
+///
+/// Up to `N` reports that pass the filter are queued, with up to `D` bytes of data each. Fragmented
+/// extended advertisements are reassembled, up to `C` at a time, see [`ScanBuffer`]. `D` is also the size
+/// of each reassembly slot, since a longer advertisement could not be queued whole anyway, so the scanner
+/// holds `(N + C) * D` bytes of data. When the queue
+/// is full, new reports are dropped; see [`Scanner::dropped`]. Scanning starts when the stream is first
+/// polled and stops when the scanner is dropped. The stream ends after yielding an error. Only one scanner
+/// can be active at a time; another one yields [`ScanError::AlreadyScanning`].
+///
+/// The scanner must be pinned, for example with [`core::pin::pin!`].
+pub struct Scanner<'a, const N: usize, const D: usize = 31, const C: usize = 1> {
+    config: &'a ScanConfig<'a>,
+    filter: ScanFilter<'a>,
+    dedup: Option<Dedup>,
+    state: Mutex<CriticalSectionRawMutex, RefCell<ScannerState<N, D, C>>>,
+    started: bool,
+    finished: bool,
+    _pin: PhantomPinned,
+}
+
+impl<'a, const N: usize, const D: usize, const C: usize> Scanner<'a, N, D, C> {
+    pub fn new(_sd: &Softdevice, config: &'a ScanConfig<'a>, filter: ScanFilter<'a>, dedup: Option<Dedup>) -> Self {
+        Self {
+            config,
+            filter,
+            dedup,
+            state: Mutex::new(RefCell::new(ScannerState {
+                buffer: ScanBuffer::new(),
+                queue: Deque::new(),
+                seen: Vec::new(),
+                waker: None,
+                error: None,
+                dropped: 0,
+            })),
+            started: false,
+            finished: false,
+            _pin: PhantomPinned,
+        }
+    }
+
+    /// Number of reports dropped so far because the queue was full.
+    pub fn dropped(&self) -> u32 {
+        self.state.lock(|state| state.borrow().dropped)
+    }
+
+    fn start(&mut self) -> Result<(), ScanError> {
+        let scan_params = self.config.to_raw()?;
+
+        // Safety: the scanner is pinned, and the handler is unregistered before it is dropped.
+        if !unsafe { SCAN_PORTAL.register_handler(Self::on_evt, self as *const Self as *const ()) } {
+            warn!("Scanner started while another scanner is active");
+            return Err(ScanError::AlreadyScanning);
+        }
+        let ret = unsafe { raw::sd_ble_gap_scan_start(&scan_params, ptr::addr_of!(BUF_DATA)) };
+        if let Err(err) = RawError::convert(ret) {
+            warn!("sd_ble_gap_scan_start err {:?}", err);
+            SCAN_PORTAL.unregister_handler();
+            return Err(err.into());
+        }
+
+        self.started = true;
+        debug!("Scanner started");
+        Ok(())
+    }
+
+    fn is_duplicate(dedup: &Dedup, seen: &[(Address, u64)], address: Address, now: u64) -> bool {
+        seen.iter()
+            .any(|&(a, t)| a == address && now.wrapping_sub(t) < dedup.window_ms as u64)
+    }
+
+    fn mark_seen(seen: &mut Vec<(Address, u64), DEDUP_ENTRIES>, address: Address, now: u64) {
+        if let Some(entry) = seen.iter_mut().find(|(a, _)| *a == address) {
+            entry.1 = now;
+            return;
+        }
+
+        if seen.is_full() {
+            let oldest = seen
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, (_, t))| *t)
+                .map(|(i, _)| i)
+                .unwrap_or(0);
+            seen.swap_remove(oldest);
+        }
+        let _ = seen.push((address, now));
+    }
+
+    unsafe fn on_evt(ctx: *const (), ble_evt: *const raw::ble_evt_t) {
+        let this = &*(ctx as *const Self);
+
+        let error = match (*ble_evt).header.evt_id as u32 {
+            raw::BLE_GAP_EVTS_BLE_GAP_EVT_TIMEOUT => Some(ScanError::Timeout),
+            raw::BLE_GAP_EVTS_BLE_GAP_EVT_ADV_REPORT => {
+                let gap_evt = get_union_field(ble_evt, &(*ble_evt).evt.gap_evt);
+                let report = ScanReport::from_raw(&gap_evt.params.adv_report);
+
+                this.state.lock(|state| {
+                    let mut state = state.borrow_mut();
+                    let state = &mut *state;
+                    let Some(report) = state.buffer.push(&report) else {
+                        return;
+                    };
+                    if !this.filter.matches(&report) {
+                        return;
+                    }
+                    let address = report.peer_address();
+                    let now = match &this.dedup {
+                        Some(dedup) => {
+                            let now = (dedup.now_ms)();
+                            if Self::is_duplicate(dedup, &state.seen, address, now) {
+                                return;
+                            }
+                            Some(now)
+                        }
+                        None => None,
+                    };
+
+                    // Only a queued report counts as seen, so a dropped one doesn't hide the address.
+                    if state.queue.push_back(OwnedScanReport::new(&report)).is_err() {
+                        state.dropped = state.dropped.wrapping_add(1);
+                        return;
+                    }
+                    if let Some(now) = now {
+                        Self::mark_seen(&mut state.seen, address, now);
+                    }
+                    if let Some(waker) = state.waker.take() {
+                        waker.wake();
+                    }
+                });
+
+                // Resume scan
+                let ret = raw::sd_ble_gap_scan_start(ptr::null(), ptr::addr_of!(BUF_DATA));
+                match RawError::convert(ret) {
+                    Ok(()) => None,
+                    // "The scanner has timed out when this function is called to continue scanning"
+                    Err(RawError::InvalidState) => Some(ScanError::Timeout),
+                    Err(err) => {
+                        warn!("sd_ble_gap_scan_start resume err {:?}", err);
+                        Some(ScanError::Raw(err))
+                    }
+                }
+            }
+            _ => None,
+        };
+
+        if let Some(error) = error {
+            this.state.lock(|state| {
+                let mut state = state.borrow_mut();
+                state.error = Some(error);
+                if let Some(waker) = state.waker.take() {
+                    waker.wake();
+                }
+            });
+        }
+    }
+}
+
+impl<'a, const N: usize, const D: usize, const C: usize> Stream for Scanner<'a, N, D, C> {
+    type Item = Result<OwnedScanReport<D>, ScanError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // Safety: the scanner is never moved out of the pin.
+        let this = unsafe { self.get_unchecked_mut() };
+        if this.finished {
+            return Poll::Ready(None);
+        }
+        if !this.started {
+            if let Err(err) = this.start() {
+                this.finished = true;
+                return Poll::Ready(Some(Err(err)));
+            }
+        }
+
+        this.state.lock(|state| {
+            let mut state = state.borrow_mut();
+            if let Some(report) = state.queue.pop_front() {
+                Poll::Ready(Some(Ok(report)))
+            } else if let Some(err) = state.error.take() {
+                this.finished = true;
+                Poll::Ready(Some(Err(err)))
+            } else {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        })
+    }
+}
+
+impl<'a, const N: usize, const D: usize, const C: usize> Drop for Scanner<'a, N, D, C> {
+    fn drop(&mut self) {
+        if self.started {
+            SCAN_PORTAL.unregister_handler();
+            let ret = unsafe { raw::sd_ble_gap_scan_stop() };
+            match RawError::convert(ret) {
+                Ok(_) => {}
+                Err(RawError::InvalidState) => {} // scan stopped itself due to timeout, erroring is normal.
+                Err(_e) => warn!("sd_ble_gap_scan_stop: {:?}", _e),
+            }
+        }
+    }
+}
+
 #[derive(Copy, Clone)]
 pub struct ScanConfig<'a> {
     /// Whitelist of addresses to scan. If None, all advertisements
     /// will be processed when scanning.
     ///
-    /// For connecting this must be Some, and have least 1 address.
+    /// For connecting with [`connect`] this must be Some, and have least 1 address.
     pub whitelist: Option<&'a [&'a Address]>,
 
     /// Support extended advertisements.
@@ -345,3 +1140,87 @@ impl<'a> ScanConfig<'a> {
         Ok(scan_params)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COMPLETE: u16 = 0;
+    const MORE_DATA: u16 = 1;
+
+    fn fragment(address: u8, data_id: u16, status: u16, data: &[u8]) -> raw::ble_gap_evt_adv_report_t {
+        // Safety: all-zero is a valid value of this plain C struct.
+        let mut raw: raw::ble_gap_evt_adv_report_t = unsafe { mem::zeroed() };
+        raw.type_.set_extended_pdu(1);
+        raw.type_.set_status(status);
+        raw.peer_addr.addr = [address; 6];
+        raw.set_data_id(data_id);
+        raw.data.p_data = data.as_ptr() as *mut u8;
+        raw.data.len = data.len() as u16;
+        raw
+    }
+
+    fn push<const N: usize, const C: usize>(
+        buffer: &mut ScanBuffer<N, C>,
+        raw: &raw::ble_gap_evt_adv_report_t,
+    ) -> Option<(DataStatus, Vec<u8, N>)> {
+        // Safety: `fragment` points the report at data that outlives it.
+        let report = unsafe { ScanReport::from_raw(raw) };
+        buffer
+            .push(&report)
+            .map(|report| (report.data_status(), unwrap!(Vec::from_slice(report.data()))))
+    }
+
+    #[test]
+    fn reassemble() {
+        let mut buffer = ScanBuffer::<8>::new();
+        assert_eq!(push(&mut buffer, &fragment(1, 1, MORE_DATA, &[1, 2])), None);
+        assert_eq!(
+            push(&mut buffer, &fragment(1, 1, COMPLETE, &[3])),
+            Some((DataStatus::Complete, unwrap!(Vec::from_slice(&[1, 2, 3]))))
+        );
+    }
+
+    #[test]
+    fn superseded_chain_is_replaced() {
+        let mut buffer = ScanBuffer::<8>::new();
+        assert_eq!(push(&mut buffer, &fragment(1, 1, MORE_DATA, &[1, 2])), None);
+        // The rest of data id 1 was lost, and the advertiser started over with new data.
+        assert_eq!(push(&mut buffer, &fragment(1, 2, MORE_DATA, &[3])), None);
+        assert_eq!(
+            push(&mut buffer, &fragment(1, 2, COMPLETE, &[4])),
+            Some((DataStatus::Complete, unwrap!(Vec::from_slice(&[3, 4]))))
+        );
+    }
+
+    #[test]
+    fn stale_chain_is_evicted() {
+        let mut buffer = ScanBuffer::<8>::new();
+        // The tail of this advertisement never arrives.
+        assert_eq!(push(&mut buffer, &fragment(1, 1, MORE_DATA, &[1, 2])), None);
+
+        // While it is recent, another advertisement finds no free slot and is skipped.
+        assert_eq!(push(&mut buffer, &fragment(2, 1, MORE_DATA, &[3])), None);
+        assert_eq!(
+            push(&mut buffer, &fragment(2, 1, COMPLETE, &[4])),
+            Some((DataStatus::IncompleteTruncated, Vec::new()))
+        );
+
+        for _ in 0..STALE_CHAIN_REPORTS {
+            assert!(push(&mut buffer, &fragment(3, 1, COMPLETE, &[5])).is_some());
+        }
+
+        // Once stale, its slot is taken by a new advertisement.
+        assert_eq!(push(&mut buffer, &fragment(2, 2, MORE_DATA, &[6])), None);
+        assert_eq!(
+            push(&mut buffer, &fragment(2, 2, COMPLETE, &[7])),
+            Some((DataStatus::Complete, unwrap!(Vec::from_slice(&[6, 7]))))
+        );
+
+        // A late fragment of the evicted advertisement is not taken for a complete one.
+        assert_eq!(
+            push(&mut buffer, &fragment(1, 1, COMPLETE, &[8])),
+            Some((DataStatus::IncompleteTruncated, Vec::new()))
+        );
+    }
+}
@@ -0,0 +1,142 @@
+//! Channel survey and channel map
+//!
+//! The channel survey measures the energy on all Bluetooth LE channels without disturbing ongoing activity,
+//! which can be used for site surveys, or to exclude busy channels from the channel map of central connections.
+
+#[cfg(any(feature = "s132", feature = "s140"))]
+use core::marker::PhantomData;
+
+#[cfg(any(feature = "s132", feature = "s140"))]
+use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
+#[cfg(any(feature = "s132", feature = "s140"))]
+use embassy_sync::signal::Signal;
+
+#[cfg(any(feature = "s132", feature = "s140"))]
+use crate::util::get_union_field;
+use crate::{raw, RawError, Softdevice};
+
+/// Energy measured on all channels by the channel survey.
+#[cfg(any(feature = "s132", feature = "s140"))]
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ChannelSurveyReport {
+    channel_energy: [i8; raw::BLE_GAP_CHANNEL_COUNT as usize],
+}
+
+#[cfg(any(feature = "s132", feature = "s140"))]
+impl ChannelSurveyReport {
+    /// Energy measured on `channel_index` (0..=39), in dBm.
+    ///
+    /// Returns `None` if no measurement was made on that channel during this report.
+    pub fn energy(&self, channel_index: u8) -> Option<i8> {
+        let energy = *self.channel_energy.get(channel_index as usize)?;
+        (energy != raw::BLE_GAP_POWER_LEVEL_INVALID as i8).then_some(energy)
+    }
+
+    /// Energy measured on all channels, indexed by channel index. Channels without a measurement are
+    /// set to `BLE_GAP_POWER_LEVEL_INVALID`.
+    pub fn channel_energy(&self) -> &[i8; raw::BLE_GAP_CHANNEL_COUNT as usize] {
+        &self.channel_energy
+    }
+}
+
+#[cfg(any(feature = "s132", feature = "s140"))]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ChannelSurveyError {
+    Raw(RawError),
+}
+
+#[cfg(any(feature = "s132", feature = "s140"))]
+impl From<RawError> for ChannelSurveyError {
+    fn from(err: RawError) -> Self {
+        ChannelSurveyError::Raw(err)
+    }
+}
+
+#[cfg(any(feature = "s132", feature = "s140"))]
+static SIGNAL: Signal<CriticalSectionRawMutex, ChannelSurveyReport> = Signal::new();
+
+#[cfg(any(feature = "s132", feature = "s140"))]
+pub(crate) unsafe fn on_report(ble_evt: *const raw::ble_evt_t) {
+    let gap_evt = get_union_field(ble_evt, &(*ble_evt).evt.gap_evt);
+    SIGNAL.signal(ChannelSurveyReport {
+        channel_energy: gap_evt.params.qos_channel_survey_report.channel_energy,
+    })
+}
+
+/// A running channel survey, see [`channel_survey`]. The survey is stopped when this is dropped.
+#[cfg(any(feature = "s132", feature = "s140"))]
+pub struct ChannelSurvey {
+    // Prevent Send, Sync
+    _private: PhantomData<*mut ()>,
+}
+
+#[cfg(any(feature = "s132", feature = "s140"))]
+impl ChannelSurvey {
+    /// Wait for the next report.
+    ///
+    /// Only the latest report is kept while nobody is waiting, older ones are dropped.
+    pub async fn next(&mut self) -> ChannelSurveyReport {
+        SIGNAL.wait().await
+    }
+}
+
+#[cfg(any(feature = "s132", feature = "s140"))]
+impl Drop for ChannelSurvey {
+    fn drop(&mut self) {
+        let ret = unsafe { raw::sd_ble_gap_qos_channel_survey_stop() };
+        if let Err(_e) = RawError::convert(ret) {
+            warn!("sd_ble_gap_qos_channel_survey_stop: {:?}", _e);
+        }
+    }
+}
+
+/// Start the channel survey.
+///
+/// `interval_us` is the average interval between reports in microseconds, between 7500 and 4000000,
+/// or 0 to measure at every opportunity. Reports are read with [`ChannelSurvey::next`], and the survey
+/// runs until the returned [`ChannelSurvey`] is dropped. Only one survey can run at a time.
+///
+/// The channel survey role must be enabled with `qos_channel_survey_role_available` in
+/// `Config::gap_role_count`.
+#[cfg(any(feature = "s132", feature = "s140"))]
+pub fn channel_survey(_sd: &Softdevice, interval_us: u32) -> Result<ChannelSurvey, ChannelSurveyError> {
+    // Drop a report left over from a previous survey.
+    SIGNAL.reset();
+
+    let ret = unsafe { raw::sd_ble_gap_qos_channel_survey_start(interval_us) };
+    if let Err(err) = RawError::convert(ret) {
+        warn!("sd_ble_gap_qos_channel_survey_start err {:?}", err);
+        return Err(err.into());
+    }
+
+    Ok(ChannelSurvey { _private: PhantomData })
+}
+
+/// Set the channel map used by central connections.
+///
+/// Bit `n` of `ch_map` enables data channel `n` (0..=36). At least two channels must be enabled.
+/// The softdevice has a single channel map shared by all central connections, so this cannot target one
+/// connection: the new map is applied to all existing central connections with a channel map update
+/// procedure, and used for all future ones. It can't be set again until at least 1 s has passed, this
+/// returns [`RawError::Busy`] meanwhile.
+#[cfg(feature = "ble-central")]
+pub fn set_channel_map(_sd: &Softdevice, ch_map: u64) -> Result<(), RawError> {
+    let ch_map = ch_map.to_le_bytes();
+    let ret = unsafe {
+        raw::sd_ble_opt_set(
+            raw::BLE_GAP_OPTS_BLE_GAP_OPT_CH_MAP,
+            &raw::ble_opt_t {
+                gap_opt: raw::ble_gap_opt_t {
+                    ch_map: raw::ble_gap_opt_ch_map_t {
+                        // Only used when getting the channel map.
+                        conn_handle: 0,
+                        ch_map: [ch_map[0], ch_map[1], ch_map[2], ch_map[3], ch_map[4] & 0x1f],
+                    },
+                },
+            },
+        )
+    };
+    RawError::convert(ret)
+}
@@ -0,0 +1,168 @@
+//! Connection parameter negotiation for peripherals.
+//!
+//! The central decides the connection parameters, so a peripheral can only request the ones it prefers.
+//! [`negotiate`] sends that request after an initial delay, retries a bounded number of times if the central
+//! does not apply acceptable parameters, and optionally disconnects when negotiation fails, similar to the
+//! `ble_conn_params` module of the nRF5 SDK.
+
+use embassy_futures::select::{select, Either};
+use embedded_hal_async::delay::DelayNs;
+
+use crate::ble::{Connection, ConnectionEvent, DisconnectedError, HciStatus, SetConnParamsError};
+use crate::{raw, RawError};
+
+/// Connection parameter negotiation configuration.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Config {
+    /// Time to wait before sending the first request, in milliseconds.
+    ///
+    /// This gives the central time to finish service discovery and similar procedures first.
+    pub first_update_delay_ms: u32,
+    /// Time to wait for the central to apply the parameters before sending the next request, in milliseconds.
+    pub next_update_delay_ms: u32,
+    /// Maximum number of requests to send before giving up.
+    pub max_attempts: u8,
+    /// Disconnect the link if negotiation fails.
+    pub disconnect_on_failure: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            first_update_delay_ms: 5_000,
+            next_update_delay_ms: 30_000,
+            max_attempts: 3,
+            disconnect_on_failure: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum NegotiateError {
+    Disconnected,
+    /// The central did not apply acceptable parameters. Contains the parameters in use when giving up.
+    Rejected(raw::ble_gap_conn_params_t),
+    Raw(RawError),
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for NegotiateError {
+    fn format(&self, fmt: defmt::Formatter) {
+        match self {
+            Self::Disconnected => defmt::write!(fmt, "Disconnected"),
+            Self::Rejected(conn_params) => defmt::write!(
+                fmt,
+                "Rejected {{ min_conn_interval: {}, max_conn_interval: {}, slave_latency: {}, conn_sup_timeout: {} }}",
+                conn_params.min_conn_interval,
+                conn_params.max_conn_interval,
+                conn_params.slave_latency,
+                conn_params.conn_sup_timeout
+            ),
+            Self::Raw(err) => defmt::write!(fmt, "Raw({})", err),
+        }
+    }
+}
+
+impl From<DisconnectedError> for NegotiateError {
+    fn from(_err: DisconnectedError) -> Self {
+        Self::Disconnected
+    }
+}
+
+impl From<RawError> for NegotiateError {
+    fn from(err: RawError) -> Self {
+        Self::Raw(err)
+    }
+}
+
+impl From<SetConnParamsError> for NegotiateError {
+    fn from(err: SetConnParamsError) -> Self {
+        match err {
+            SetConnParamsError::Disconnected => Self::Disconnected,
+            SetConnParamsError::Raw(err) => Self::Raw(err),
+        }
+    }
+}
+
+/// Check whether `conn_params` satisfy `preferred`.
+///
+/// The connection interval must be within the preferred range, and the slave latency and
+/// supervision timeout must match exactly.
+pub fn is_acceptable(preferred: &raw::ble_gap_conn_params_t, conn_params: &raw::ble_gap_conn_params_t) -> bool {
+    conn_params.max_conn_interval >= preferred.min_conn_interval
+        && conn_params.max_conn_interval <= preferred.max_conn_interval
+        && conn_params.slave_latency == preferred.slave_latency
+        && conn_params.conn_sup_timeout == preferred.conn_sup_timeout
+}
+
+/// Negotiate the `preferred` connection parameters with the central.
+///
+/// Returns the parameters in use as soon as they are acceptable according to [`is_acceptable`], either
+/// because the central applied the requested ones or chose suitable ones on its own.
+///
+/// Connection parameter updates are received through [`Connection::events`], so other tasks can listen
+/// for events on this connection meanwhile.
+pub async fn negotiate(
+    conn: &Connection,
+    preferred: raw::ble_gap_conn_params_t,
+    config: &Config,
+    delay: &mut impl DelayNs,
+) -> Result<raw::ble_gap_conn_params_t, NegotiateError> {
+    // Subscribe before checking the current parameters, so no update can be missed in between.
+    let mut events = conn.events();
+    let current = conn.conn_params();
+    if is_acceptable(&preferred, &current) {
+        return Ok(current);
+    }
+
+    let mut delay_ms = config.first_update_delay_ms;
+    let mut attempts = 0;
+    loop {
+        let updated = async {
+            loop {
+                if let ConnectionEvent::ConnParamsUpdated { conn_params } = events.next().await? {
+                    if is_acceptable(&preferred, &conn_params) {
+                        return Ok::<_, DisconnectedError>(conn_params);
+                    }
+                }
+            }
+        };
+
+        match select(delay.delay_ms(delay_ms), updated).await {
+            Either::First(()) => {}
+            Either::Second(res) => return Ok(res?),
+        }
+
+        // The parameters may have been updated just as the delay expired.
+        let current = conn.conn_params();
+        if is_acceptable(&preferred, &current) {
+            return Ok(current);
+        }
+
+        if attempts == config.max_attempts {
+            break;
+        }
+        attempts += 1;
+
+        debug!(
+            "conn_params: sending update request {:?}/{:?}",
+            attempts, config.max_attempts
+        );
+        match conn.set_conn_params(preferred) {
+            Ok(()) => {}
+            // A procedure is already in progress, the next attempt will try again.
+            Err(SetConnParamsError::Raw(RawError::Busy)) => {}
+            Err(err) => return Err(err.into()),
+        }
+
+        delay_ms = config.next_update_delay_ms;
+    }
+
+    warn!("conn_params: negotiation failed after {:?} attempts", attempts);
+    if config.disconnect_on_failure {
+        conn.disconnect_with_reason(HciStatus::CONN_INTERVAL_UNACCEPTABLE)?;
+    }
+
+    Err(NegotiateError::Rejected(conn.conn_params()))
+}
@@ -1,14 +1,27 @@
-use core::cell::{Cell, UnsafeCell};
+#[cfg(feature = "ble-user-data")]
+use core::any::TypeId;
+use core::cell::{Cell, RefCell, UnsafeCell};
 use core::iter::FusedIterator;
+#[cfg(feature = "ble-user-data")]
+use core::mem::{self, MaybeUninit};
+#[cfg(feature = "ble-user-data")]
+use core::ptr;
+use core::task::Poll;
+
+use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
+use embassy_sync::blocking_mutex::Mutex;
+use embassy_sync::waitqueue::MultiWakerRegistration;
+use futures::future::poll_fn;
 
 use raw::ble_gap_conn_params_t;
 
-use super::{HciStatus, PhySet};
+use super::{HciStatus, Phy, PhySet};
 #[cfg(feature = "ble-central")]
 use crate::ble::gap::default_security_params;
+use crate::ble::gap::ConnectionPolicy;
 #[cfg(feature = "ble-sec")]
 use crate::ble::security::SecurityHandler;
-use crate::ble::types::{Address, AddressType, Role, SecurityMode};
+use crate::ble::types::{Address, AddressType, Role, SecurityMode, TxPower};
 use crate::util::get_union_field;
 use crate::{raw, RawError};
 
@@ -23,6 +36,136 @@ pub(crate) struct OutOfConnsError;
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct DisconnectedError;
 
+/// Link-layer events reported by the softdevice for a connection.
+#[derive(Debug, Clone, Copy)]
+pub enum ConnectionEvent {
+    /// A PHY update procedure has completed.
+    ///
+    /// If `status` is not [`HciStatus::SUCCESS`], the PHYs are the ones that remain in use. A PHY is `None`
+    /// if the softdevice reported a value not known to this crate.
+    PhyUpdated {
+        status: HciStatus,
+        tx_phy: Option<Phy>,
+        rx_phy: Option<Phy>,
+    },
+    /// A data length update procedure has completed.
+    #[cfg(any(feature = "s113", feature = "s132", feature = "s140"))]
+    DataLengthUpdated {
+        effective_params: raw::ble_gap_data_length_params_t,
+    },
+    /// The connection parameters have been updated.
+    ConnParamsUpdated { conn_params: ble_gap_conn_params_t },
+    /// The RSSI has changed by at least [`RssiConfig::threshold_dbm`], see [`Connection::start_rssi_with_config`].
+    #[cfg(feature = "ble-rssi")]
+    RssiChanged { rssi: i8, ch_index: u8 },
+}
+
+impl ConnectionEvent {
+    unsafe fn from_raw(ble_evt: *const raw::ble_evt_t) -> Option<Self> {
+        let gap_evt = get_union_field(ble_evt, &(*ble_evt).evt.gap_evt);
+        match (*ble_evt).header.evt_id as u32 {
+            raw::BLE_GAP_EVTS_BLE_GAP_EVT_PHY_UPDATE => {
+                let params = &gap_evt.params.phy_update;
+                Some(ConnectionEvent::PhyUpdated {
+                    status: HciStatus::new(params.status),
+                    tx_phy: Phy::try_from_raw(params.tx_phy),
+                    rx_phy: Phy::try_from_raw(params.rx_phy),
+                })
+            }
+            #[cfg(any(feature = "s113", feature = "s132", feature = "s140"))]
+            raw::BLE_GAP_EVTS_BLE_GAP_EVT_DATA_LENGTH_UPDATE => Some(ConnectionEvent::DataLengthUpdated {
+                effective_params: gap_evt.params.data_length_update.effective_params,
+            }),
+            raw::BLE_GAP_EVTS_BLE_GAP_EVT_CONN_PARAM_UPDATE => Some(ConnectionEvent::ConnParamsUpdated {
+                conn_params: gap_evt.params.conn_param_update.conn_params,
+            }),
+            #[cfg(feature = "ble-rssi")]
+            raw::BLE_GAP_EVTS_BLE_GAP_EVT_RSSI_CHANGED => Some(ConnectionEvent::RssiChanged {
+                rssi: gap_evt.params.rssi_changed.rssi,
+                ch_index: gap_evt.params.rssi_changed.ch_index,
+            }),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for ConnectionEvent {
+    fn format(&self, fmt: defmt::Formatter) {
+        match self {
+            ConnectionEvent::PhyUpdated { status, tx_phy, rx_phy } => defmt::write!(
+                fmt,
+                "PhyUpdated {{ status: {}, tx_phy: {}, rx_phy: {} }}",
+                status,
+                tx_phy,
+                rx_phy
+            ),
+            #[cfg(any(feature = "s113", feature = "s132", feature = "s140"))]
+            ConnectionEvent::DataLengthUpdated { effective_params } => defmt::write!(
+                fmt,
+                "DataLengthUpdated {{ max_tx_octets: {}, max_rx_octets: {}, max_tx_time_us: {}, max_rx_time_us: {} }}",
+                effective_params.max_tx_octets,
+                effective_params.max_rx_octets,
+                effective_params.max_tx_time_us,
+                effective_params.max_rx_time_us
+            ),
+            ConnectionEvent::ConnParamsUpdated { conn_params } => defmt::write!(
+                fmt,
+                "ConnParamsUpdated {{ min_conn_interval: {}, max_conn_interval: {}, slave_latency: {}, conn_sup_timeout: {} }}",
+                conn_params.min_conn_interval,
+                conn_params.max_conn_interval,
+                conn_params.slave_latency,
+                conn_params.conn_sup_timeout
+            ),
+            #[cfg(feature = "ble-rssi")]
+            ConnectionEvent::RssiChanged { rssi, ch_index } => {
+                defmt::write!(fmt, "RssiChanged {{ rssi: {}, ch_index: {} }}", rssi, ch_index)
+            }
+        }
+    }
+}
+
+/// Subscription to the link-layer events of a connection, see [`Connection::events`].
+pub struct ConnectionEvents<'a> {
+    conn: &'a Connection,
+    // Sequence number of the next event to return.
+    next: u32,
+}
+
+impl<'a> ConnectionEvents<'a> {
+    /// Wait for the next event, or `DisconnectedError` once the connection is lost and all events
+    /// received before have been returned.
+    ///
+    /// If this subscription falls more than a few events behind, the oldest ones are skipped.
+    pub async fn next(&mut self) -> Result<ConnectionEvent, DisconnectedError> {
+        poll_fn(|cx| {
+            EVENT_QUEUES[self.conn.index as usize].lock(|queue| {
+                let mut queue = queue.borrow_mut();
+                let behind = queue.seq.wrapping_sub(self.next) as usize;
+                if behind > EVENT_QUEUE_LEN {
+                    warn!(
+                        "conn {:?}: skipped {:?} events",
+                        self.conn.index,
+                        behind - EVENT_QUEUE_LEN
+                    );
+                    self.next = queue.seq.wrapping_sub(EVENT_QUEUE_LEN as u32);
+                }
+                if self.next != queue.seq {
+                    let evt = unwrap!(queue.events[self.next as usize % EVENT_QUEUE_LEN]);
+                    self.next = self.next.wrapping_add(1);
+                    return Poll::Ready(Ok(evt));
+                }
+                if let Err(err) = self.conn.with_state(|state| state.check_connected()) {
+                    return Poll::Ready(Err(err));
+                }
+                queue.wakers.register(cx.waker());
+                Poll::Pending
+            })
+        })
+        .await
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub(crate) enum ConnHandleState {
@@ -131,6 +274,122 @@ impl From<RawError> for PhyUpdateError {
     }
 }
 
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ConnEvtTriggerError {
+    Disconnected,
+    Raw(RawError),
+}
+
+impl From<DisconnectedError> for ConnEvtTriggerError {
+    fn from(_err: DisconnectedError) -> Self {
+        Self::Disconnected
+    }
+}
+
+impl From<RawError> for ConnEvtTriggerError {
+    fn from(err: RawError) -> Self {
+        Self::Raw(err)
+    }
+}
+
+/// Filter applied to RSSI samples to compute [`Connection::rssi`].
+#[cfg(feature = "ble-rssi")]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum RssiFilter {
+    /// Report the latest sample.
+    Disabled,
+    /// Exponential moving average, giving each new sample a weight of `1 / 2^shift`.
+    ///
+    /// `shift` must be at most 7.
+    Exponential { shift: u8 },
+}
+
+#[cfg(feature = "ble-rssi")]
+impl Default for RssiFilter {
+    fn default() -> Self {
+        Self::Exponential { shift: 3 }
+    }
+}
+
+#[cfg(feature = "ble-rssi")]
+impl RssiFilter {
+    pub(crate) fn apply(self, old: Option<i8>, sample: i8) -> i8 {
+        match (self, old) {
+            (Self::Exponential { shift }, Some(old)) => {
+                let shift = shift.min(7);
+                let div = 1i16 << shift;
+                ((i16::from(old) * (div - 1) + i16::from(sample)) / div) as i8
+            }
+            _ => sample,
+        }
+    }
+}
+
+/// RSSI measurement configuration.
+#[cfg(feature = "ble-rssi")]
+#[derive(Debug, Default, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RssiConfig {
+    /// Minimum change in dBm before a new sample is reported. 0 reports every sample.
+    pub threshold_dbm: u8,
+    /// Number of samples with a change above the threshold required before a sample is reported.
+    pub skip_count: u8,
+    pub filter: RssiFilter,
+}
+
+#[cfg(feature = "ble-rssi")]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RssiSample {
+    /// RSSI in dBm.
+    pub rssi: i8,
+    /// Data channel index the sample was measured on (0..=36).
+    pub ch_index: u8,
+}
+
+#[cfg(feature = "ble-rssi")]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum RssiError {
+    Disconnected,
+    Raw(RawError),
+}
+
+#[cfg(feature = "ble-rssi")]
+impl From<DisconnectedError> for RssiError {
+    fn from(_err: DisconnectedError) -> Self {
+        Self::Disconnected
+    }
+}
+
+#[cfg(feature = "ble-rssi")]
+impl From<RawError> for RssiError {
+    fn from(err: RawError) -> Self {
+        Self::Raw(err)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SetTxPowerError {
+    Disconnected,
+    Raw(RawError),
+}
+
+impl From<DisconnectedError> for SetTxPowerError {
+    fn from(_err: DisconnectedError) -> Self {
+        Self::Disconnected
+    }
+}
+
+impl From<RawError> for SetTxPowerError {
+    fn from(err: RawError) -> Self {
+        Self::Raw(err)
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[cfg(any(feature = "ble-central", feature = "ble-peripheral"))]
@@ -180,6 +439,71 @@ impl From<RawError> for EncryptError {
 // Highest ever the softdevice can support.
 pub(crate) const CONNS_MAX: usize = 20;
 
+/// Maximum size in bytes of the per-connection user data, see [`Connection::set_user_data`].
+#[cfg(feature = "ble-user-data")]
+pub const USER_DATA_SIZE: usize = 32;
+
+/// Type-erased `Send` user data stored inline in the `ConnectionState`.
+#[cfg(feature = "ble-user-data")]
+pub(crate) struct UserData {
+    data: MaybeUninit<[u64; USER_DATA_SIZE / 8]>,
+    type_id: TypeId,
+    drop_fn: unsafe fn(*mut u8),
+}
+
+#[cfg(feature = "ble-user-data")]
+impl UserData {
+    fn new<T: Send + 'static>(value: T) -> Self {
+        const {
+            core::assert!(mem::size_of::<T>() <= USER_DATA_SIZE, "user data too large");
+            core::assert!(
+                mem::align_of::<T>() <= mem::align_of::<u64>(),
+                "user data alignment too large"
+            );
+        }
+
+        unsafe fn drop_fn<T>(ptr: *mut u8) {
+            ptr::drop_in_place(ptr.cast::<T>())
+        }
+
+        let mut data = MaybeUninit::<[u64; USER_DATA_SIZE / 8]>::uninit();
+        // Safety: size and alignment were checked above.
+        unsafe { data.as_mut_ptr().cast::<T>().write(value) };
+        Self {
+            data,
+            type_id: TypeId::of::<T>(),
+            drop_fn: drop_fn::<T>,
+        }
+    }
+
+    fn downcast_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        if self.type_id == TypeId::of::<T>() {
+            // Safety: `data` holds a valid `T`.
+            Some(unsafe { &mut *self.data.as_mut_ptr().cast::<T>() })
+        } else {
+            None
+        }
+    }
+
+    fn downcast<T: 'static>(self) -> Result<T, Self> {
+        if self.type_id == TypeId::of::<T>() {
+            let this = mem::ManuallyDrop::new(self);
+            // Safety: `data` holds a valid `T`, and `this` won't drop it again.
+            Ok(unsafe { this.data.as_ptr().cast::<T>().read() })
+        } else {
+            Err(self)
+        }
+    }
+}
+
+#[cfg(feature = "ble-user-data")]
+impl Drop for UserData {
+    fn drop(&mut self) {
+        // Safety: `data` holds a valid value of the type `drop_fn` was created for.
+        unsafe { (self.drop_fn)(self.data.as_mut_ptr().cast()) }
+    }
+}
+
 #[cfg(feature = "ble-sec")]
 #[derive(Clone, Copy)]
 pub(crate) struct EncryptionState {
@@ -230,13 +554,16 @@ const NEW_ENCRYPTION_STATE: EncryptionState = EncryptionState {
 pub(crate) struct ConnectionState {
     // Every Connection instance counts as one ref.
     //
-    // When client code drops all instances, refcount becomes 0 and disconnection is initiated.
+    // When client code drops all instances, refcount becomes 0 and disconnection is initiated,
+    // unless keep_alive is set.
     // However, disconnection is not complete until the event GAP_DISCONNECTED.
     // so there's a small gap of time where the ConnectionState is not "free" even if refcount=0.
     pub refcount: u8,
     pub conn_handle: ConnHandleState,
 
     pub disconnecting: bool,
+    // Don't disconnect when refcount becomes 0, see `Connection::set_keep_alive`.
+    pub keep_alive: bool,
     pub role: Role,
     pub peer_address: Address,
     pub security_mode: SecurityMode,
@@ -245,6 +572,8 @@ pub(crate) struct ConnectionState {
 
     #[cfg(feature = "ble-rssi")]
     pub rssi: Option<i8>,
+    #[cfg(feature = "ble-rssi")]
+    pub rssi_filter: RssiFilter,
 
     #[cfg(feature = "ble-gatt")]
     pub att_mtu: u16, // Effective ATT_MTU size (in bytes).
@@ -253,6 +582,11 @@ pub(crate) struct ConnectionState {
 
     #[cfg(feature = "ble-sec")]
     pub security: EncryptionState,
+
+    pub policy: Option<&'static dyn ConnectionPolicy>,
+
+    #[cfg(feature = "ble-user-data")]
+    pub user_data: Option<UserData>,
 }
 
 impl ConnectionState {
@@ -269,6 +603,7 @@ impl ConnectionState {
             peer_address: Address::new(AddressType::Public, [0; 6]),
             security_mode: SecurityMode::NoAccess,
             disconnecting: false,
+            keep_alive: false,
             conn_params: ble_gap_conn_params_t {
                 conn_sup_timeout: 0,
                 max_conn_interval: 0,
@@ -277,12 +612,17 @@ impl ConnectionState {
             },
             #[cfg(feature = "ble-rssi")]
             rssi: None,
+            #[cfg(feature = "ble-rssi")]
+            rssi_filter: RssiFilter::Disabled,
             #[cfg(feature = "ble-gatt")]
             att_mtu: 0,
             #[cfg(any(feature = "s113", feature = "s132", feature = "s140"))]
             data_length_effective: 0,
             #[cfg(feature = "ble-sec")]
             security: NEW_ENCRYPTION_STATE,
+            policy: None,
+            #[cfg(feature = "ble-user-data")]
+            user_data: None,
         }
     }
     pub(crate) fn check_connected(&mut self) -> Result<u16, DisconnectedError> {
@@ -313,14 +653,14 @@ impl ConnectionState {
         Ok(())
     }
 
-    pub(crate) fn on_disconnected(&mut self, ble_evt: *const raw::ble_evt_t) {
+    pub(crate) fn on_disconnected(&mut self, ble_evt: *const raw::ble_evt_t) -> Released {
         let conn_handle = unwrap!(
             self.conn_handle.handle(),
             "bug: on_disconnected when already disconnected"
         );
 
         let ibh = index_by_handle(conn_handle);
-        let _index = unwrap!(ibh.get(), "bug: conn_handle has no index");
+        let index = unwrap!(ibh.get(), "bug: conn_handle has no index");
 
         #[cfg(all(feature = "ble-gatt-server", feature = "ble-sec"))]
         if let Some(handler) = self.security.handler {
@@ -341,6 +681,16 @@ impl ConnectionState {
         };
         self.conn_handle = ConnHandleState::Disconnected(reason);
 
+        // The state is free now if no `Connection` refers to it anymore.
+        let released = Released {
+            #[cfg(feature = "ble-user-data")]
+            _user_data: if self.refcount == 0 {
+                self.user_data.take()
+            } else {
+                None
+            },
+        };
+
         // Signal possible in-progess operations that the connection has disconnected.
         #[cfg(feature = "ble-gatt-client")]
         crate::ble::gatt_client::portal(conn_handle).call(ble_evt);
@@ -350,8 +700,11 @@ impl ConnectionState {
         crate::ble::gatt_server::portal(conn_handle).call(ble_evt);
         #[cfg(feature = "ble-l2cap")]
         crate::ble::l2cap::portal(conn_handle).call(ble_evt);
+        EVENT_QUEUES[index as usize].lock(|queue| queue.borrow_mut().wakers.wake());
+        DISCONNECT_WAKERS[index as usize].lock(|wakers| wakers.borrow_mut().wake());
 
-        trace!("conn {:?}: disconnected", _index);
+        trace!("conn {:?}: disconnected", index);
+        released
     }
 
     pub(crate) fn keyset(&mut self) -> raw::ble_gap_sec_keyset_t {
@@ -388,6 +741,16 @@ impl ConnectionState {
     }
 }
 
+/// Values taken out of a `ConnectionState`, to be dropped once it is no longer borrowed.
+///
+/// Dropping user data runs arbitrary code, which may use `with_state` itself.
+#[derive(Default)]
+#[must_use]
+pub(crate) struct Released {
+    #[cfg(feature = "ble-user-data")]
+    _user_data: Option<UserData>,
+}
+
 #[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Connection {
     index: u8,
@@ -395,23 +758,31 @@ pub struct Connection {
 
 impl Drop for Connection {
     fn drop(&mut self) {
-        self.with_state(|state| {
+        let released = self.with_state(|state| {
             state.refcount = unwrap!(
                 state.refcount.checked_sub(1),
                 "bug: dropping a conn which is already at refcount 0"
             );
 
             if state.refcount == 0 {
-                if state.conn_handle.is_connected() {
+                if state.conn_handle.is_connected() && state.keep_alive {
+                    trace!("conn {:?}: dropped, keeping alive", self.index);
+                } else if state.conn_handle.is_connected() {
                     trace!("conn {:?}: dropped, disconnecting", self.index);
                     // We still leave conn_handle set, because the connection is
                     // not really disconnected until we get GAP_DISCONNECTED event.
                     unwrap!(state.disconnect());
                 } else {
                     trace!("conn {:?}: dropped, already disconnected", self.index);
+                    return Released {
+                        #[cfg(feature = "ble-user-data")]
+                        _user_data: state.user_data.take(),
+                    };
                 }
             }
+            Released::default()
         });
+        drop(released);
     }
 }
 
@@ -450,9 +821,98 @@ impl Connection {
         self.with_state(|state| state.conn_handle.handle())
     }
 
+    /// Attach `value` to this connection, replacing any previous user data.
+    ///
+    /// The user data can be accessed from any clone of this `Connection`, and is dropped once the
+    /// connection is disconnected and all `Connection` instances referring to it are dropped.
+    ///
+    /// A `Connection` must not be stored as user data, not even indirectly: it would keep itself alive,
+    /// so the link is never disconnected when the other instances are dropped and the user data is leaked.
+    ///
+    /// `T` must be `Send`, since the connection can be accessed from other threads or interrupts. It must
+    /// also fit in [`USER_DATA_SIZE`] bytes and have an alignment of at most 8, which is checked at compile
+    /// time.
+    #[cfg(feature = "ble-user-data")]
+    pub fn set_user_data<T: Send + 'static>(&self, value: T) {
+        let old = self.with_state(|state| state.user_data.replace(UserData::new(value)));
+        drop(old);
+    }
+
+    /// Access the user data of this connection.
+    ///
+    /// `f` is called with `None` if no user data is set or if it is not of type `T`.
+    #[cfg(feature = "ble-user-data")]
+    pub fn with_user_data<T: Send + 'static, R>(&self, f: impl FnOnce(Option<&mut T>) -> R) -> R {
+        // Take the user data out while `f` runs, so it can use this connection freely.
+        let mut user_data = self.with_state(|state| state.user_data.take());
+        let res = f(user_data.as_mut().and_then(UserData::downcast_mut));
+        self.with_state(|state| {
+            if state.user_data.is_none() {
+                state.user_data = user_data.take();
+            }
+        });
+        res
+    }
+
+    /// Remove the user data from this connection and return it, if it is of type `T`.
+    #[cfg(feature = "ble-user-data")]
+    pub fn take_user_data<T: Send + 'static>(&self) -> Option<T> {
+        self.with_state(|state| {
+            let user_data = state.user_data.take()?;
+            match user_data.downcast() {
+                Ok(value) => Some(value),
+                Err(user_data) => {
+                    state.user_data = Some(user_data);
+                    None
+                }
+            }
+        })
+    }
+
+    /// Keep the link alive when all `Connection` instances are dropped.
+    ///
+    /// By default, dropping the last instance disconnects the link. With keep-alive enabled, the link stays
+    /// up until it is disconnected explicitly or by the peer, and [`Connection::from_handle`] can be used to
+    /// get a `Connection` for it again, which clears keep-alive.
+    pub fn set_keep_alive(&self, keep_alive: bool) {
+        self.with_state(|state| state.keep_alive = keep_alive)
+    }
+
+    /// Drop this instance without disconnecting the link, returning the connection handle.
+    ///
+    /// This enables keep-alive, see [`Connection::set_keep_alive`]. Returns `None` if already disconnected.
+    pub fn detach(self) -> Option<u16> {
+        self.set_keep_alive(true);
+        self.handle()
+    }
+
+    /// Wait until this connection is disconnected, returning the disconnect reason.
+    ///
+    /// Returns immediately if it is already disconnected. Any number of tasks may wait at a time.
+    pub async fn wait_disconnected(&self) -> HciStatus {
+        poll_fn(|cx| {
+            DISCONNECT_WAKERS[self.index as usize].lock(|wakers| match self.disconnect_reason() {
+                Some(reason) => Poll::Ready(reason),
+                None => {
+                    wakers.borrow_mut().register(cx.waker());
+                    Poll::Pending
+                }
+            })
+        })
+        .await
+    }
+
+    /// Get a `Connection` for a live connection handle.
+    ///
+    /// This can be used to re-acquire a connection after [`Connection::detach`]. If no other instance
+    /// exists, keep-alive is cleared, so dropping the returned instance disconnects the link again unless
+    /// it is detached or kept alive once more.
     pub fn from_handle(conn_handle: u16) -> Option<Connection> {
         index_by_handle(conn_handle).get().map(|index| {
             with_state(index, |state| {
+                if state.refcount == 0 {
+                    state.keep_alive = false;
+                }
                 state.refcount = unwrap!(state.refcount.checked_add(1), "Too many references to same connection");
                 Connection { index }
             })
@@ -475,11 +935,14 @@ impl Connection {
                 security_mode: SecurityMode::Open,
 
                 disconnecting: false,
+                keep_alive: false,
 
                 conn_params,
 
                 #[cfg(feature = "ble-rssi")]
                 rssi: None,
+                #[cfg(feature = "ble-rssi")]
+                rssi_filter: RssiFilter::default(),
 
                 #[cfg(feature = "ble-gatt")]
                 att_mtu: raw::BLE_GATT_ATT_MTU_DEFAULT as _,
@@ -489,6 +952,10 @@ impl Connection {
 
                 #[cfg(feature = "ble-sec")]
                 security: NEW_ENCRYPTION_STATE,
+
+                policy: None,
+                #[cfg(feature = "ble-user-data")]
+                user_data: None,
             };
 
             // Update index_by_handle
@@ -515,17 +982,48 @@ impl Connection {
     }
 
     /// Start measuring RSSI on this connection.
+    ///
+    /// This uses the default [`RssiConfig`], see [`Connection::start_rssi_with_config`].
     #[cfg(feature = "ble-rssi")]
     pub fn start_rssi(&self) {
-        if let Ok(conn_handle) = self.with_state(|state| state.check_connected()) {
-            let ret = unsafe { raw::sd_ble_gap_rssi_start(conn_handle, 0, 0) };
-            if let Err(err) = RawError::convert(ret) {
-                warn!("sd_ble_gap_rssi_start err {:?}", err);
-            }
+        let _ = self.start_rssi_with_config(&RssiConfig::default());
+    }
+
+    /// Start measuring RSSI on this connection with the given configuration.
+    ///
+    /// Every sample that passes the threshold and skip count is reported as a
+    /// [`ConnectionEvent::RssiChanged`] and fed to the filter behind [`Connection::rssi`].
+    #[cfg(feature = "ble-rssi")]
+    pub fn start_rssi_with_config(&self, config: &RssiConfig) -> Result<(), RssiError> {
+        let conn_handle = self.with_state(|state| state.check_connected())?;
+        let ret = unsafe { raw::sd_ble_gap_rssi_start(conn_handle, config.threshold_dbm, config.skip_count) };
+        if let Err(err) = RawError::convert(ret) {
+            warn!("sd_ble_gap_rssi_start err {:?}", err);
+            return Err(err.into());
+        }
+
+        self.with_state(|state| {
+            state.rssi = None;
+            state.rssi_filter = config.filter;
+        });
+        Ok(())
+    }
+
+    /// Stop measuring RSSI on this connection.
+    #[cfg(feature = "ble-rssi")]
+    pub fn stop_rssi(&self) -> Result<(), RssiError> {
+        let conn_handle = self.with_state(|state| state.check_connected())?;
+        let ret = unsafe { raw::sd_ble_gap_rssi_stop(conn_handle) };
+        if let Err(err) = RawError::convert(ret) {
+            warn!("sd_ble_gap_rssi_stop err {:?}", err);
+            return Err(err.into());
         }
+
+        self.with_state(|state| state.rssi = None);
+        Ok(())
     }
 
-    /// Get the connection's RSSI.
+    /// Get the connection's filtered RSSI.
     ///
     /// This will return None if `start_rssi` has not been called yet, or if
     /// no measurement has been done yet.
@@ -534,6 +1032,20 @@ impl Connection {
         self.with_state(|state| state.rssi)
     }
 
+    /// Get the latest unfiltered RSSI sample and the data channel index it was measured on.
+    ///
+    /// RSSI measurement must have been started with [`Connection::start_rssi`] or
+    /// [`Connection::start_rssi_with_config`].
+    #[cfg(feature = "ble-rssi")]
+    pub fn rssi_sample(&self) -> Result<RssiSample, RssiError> {
+        let conn_handle = self.with_state(|state| state.check_connected())?;
+        let mut rssi: i8 = 0;
+        let mut ch_index: u8 = 0;
+        let ret = unsafe { raw::sd_ble_gap_rssi_get(conn_handle, &mut rssi, &mut ch_index) };
+        RawError::convert(ret)?;
+        Ok(RssiSample { rssi, ch_index })
+    }
+
     /// Get the currently active connection params.
     pub fn conn_params(&self) -> ble_gap_conn_params_t {
         with_state(self.index, |s| s.conn_params)
@@ -554,6 +1066,22 @@ impl Connection {
         with_state(self.index, |s| s.security.handler)
     }
 
+    /// Get the policy used to reply to update requests from the peer on this connection.
+    ///
+    /// This is the policy set with [`Connection::set_policy`] if any, otherwise the one set
+    /// with [`set_connection_policy`](crate::ble::set_connection_policy).
+    pub fn policy(&self) -> Option<&'static dyn ConnectionPolicy> {
+        with_state(self.index, |s| s.policy).or_else(crate::ble::gap::connection_policy)
+    }
+
+    /// Set the policy used to reply to update requests from the peer on this connection.
+    ///
+    /// This overrides the policy set with [`set_connection_policy`](crate::ble::set_connection_policy)
+    /// for this connection only. Passing `None` reverts to it.
+    pub fn set_policy(&self, policy: Option<&'static dyn ConnectionPolicy>) {
+        with_state(self.index, |s| s.policy = policy)
+    }
+
     /// Set the connection params.
     ///
     /// Note that this just initiates the connection param change, it does not wait for completion.
@@ -615,6 +1143,41 @@ impl Connection {
         Ok(())
     }
 
+    /// Subscribe to link-layer events on this connection.
+    ///
+    /// Any number of subscriptions can be active at a time, each receives every event that occurs after
+    /// it was created.
+    pub fn events(&self) -> ConnectionEvents<'_> {
+        let next = EVENT_QUEUES[self.index as usize].lock(|queue| queue.borrow().seq);
+        ConnectionEvents { conn: self, next }
+    }
+
+    /// Wait for link-layer events on this connection.
+    ///
+    /// `f` is called for every [`ConnectionEvent`] received while waiting. This returns as soon as `f`
+    /// returns `Some`, or with `DisconnectedError` when the connection is lost.
+    ///
+    /// Any number of tasks may wait at a time. Events that occur while not waiting are not seen, use
+    /// [`Connection::events`] to keep receiving them between calls.
+    pub async fn wait_for_event<F, R>(&self, mut f: F) -> Result<R, DisconnectedError>
+    where
+        F: FnMut(ConnectionEvent) -> Option<R>,
+    {
+        let mut events = self.events();
+        loop {
+            if let Some(res) = f(events.next().await?) {
+                return Ok(res);
+            }
+        }
+    }
+
+    /// Wait for the next link-layer event on this connection.
+    ///
+    /// See [`Connection::wait_for_event`] for details.
+    pub async fn next_event(&self) -> Result<ConnectionEvent, DisconnectedError> {
+        self.wait_for_event(Some).await
+    }
+
     pub(crate) fn with_state<T>(&self, f: impl FnOnce(&mut ConnectionState) -> T) -> T {
         with_state(self.index, f)
     }
@@ -688,6 +1251,76 @@ impl Connection {
         Ok(())
     }
 
+    /// Set the radio transmit power used on this connection.
+    ///
+    /// By default, a connection uses the transmit power of the advertiser or initiator that created it.
+    pub fn set_tx_power(&self, tx_power: TxPower) -> Result<(), SetTxPowerError> {
+        let conn_handle = self.with_state(|state| state.check_connected())?;
+        let ret = unsafe {
+            raw::sd_ble_gap_tx_power_set(
+                raw::BLE_GAP_TX_POWER_ROLES_BLE_GAP_TX_POWER_ROLE_CONN as _,
+                conn_handle,
+                tx_power as i8,
+            )
+        };
+        if let Err(err) = RawError::convert(ret) {
+            warn!("sd_ble_gap_tx_power_set err {:?}", err);
+            return Err(err.into());
+        }
+
+        Ok(())
+    }
+
+    /// Get the counter of the next connection event.
+    pub fn next_conn_evt_counter(&self) -> Result<u16, ConnEvtTriggerError> {
+        let conn_handle = self.with_state(|state| state.check_connected())?;
+        let mut counter: u16 = 0;
+        let ret = unsafe { raw::sd_ble_gap_next_conn_evt_counter_get(conn_handle, &mut counter) };
+        RawError::convert(ret)?;
+        Ok(counter)
+    }
+
+    /// Trigger a task through PPI at the start of connection events.
+    ///
+    /// The task is triggered every `period_in_events` connection events, starting `start_offset` events after
+    /// the next one. For peripheral connections using slave latency, `period_in_events` should be a multiple
+    /// of the slave latency plus one to avoid waking up for otherwise skipped events.
+    ///
+    /// The PPI channel is reserved until the returned [`ConnEvtTrigger`] is dropped or stopped, which stops
+    /// the triggering.
+    ///
+    /// # Safety
+    ///
+    /// `task` must be the address of a task register of a peripheral, which is safe to trigger at any time.
+    #[cfg(any(feature = "s113", feature = "s132", feature = "s140"))]
+    pub unsafe fn start_conn_evt_trigger<C: PpiChannel>(
+        &self,
+        ppi_ch: C,
+        task: *const u32,
+        start_offset: u16,
+        period_in_events: u16,
+    ) -> Result<ConnEvtTrigger<C>, ConnEvtTriggerError> {
+        let conn_handle = self.with_state(|state| state.check_connected())?;
+        let counter = self.next_conn_evt_counter()?;
+
+        let params = raw::ble_gap_conn_event_trigger_t {
+            ppi_ch_id: ppi_ch.number(),
+            task_endpoint: task as u32,
+            conn_evt_counter_start: counter.wrapping_add(start_offset),
+            period_in_events,
+        };
+        let ret = raw::sd_ble_gap_conn_evt_trigger_start(conn_handle, &params);
+        if let Err(err) = RawError::convert(ret) {
+            warn!("sd_ble_gap_conn_evt_trigger_start err {:?}", err);
+            return Err(err.into());
+        }
+
+        Ok(ConnEvtTrigger {
+            conn: self.clone(),
+            ppi_ch: Some(ppi_ch),
+        })
+    }
+
     #[cfg(feature = "ble-central")]
     /// Send a pairing request to the peripheral.
     pub fn request_pairing(&self) -> Result<(), AuthenticateError> {
@@ -780,6 +1413,49 @@ impl Connection {
     }
 }
 
+/// A PPI channel that can be handed over to the SoftDevice.
+pub trait PpiChannel {
+    /// The channel number. It must not be one of the channels reserved by the SoftDevice.
+    fn number(&self) -> u8;
+}
+
+/// Connection event triggering started with [`Connection::start_conn_evt_trigger`].
+///
+/// Triggering stops when this is dropped.
+#[cfg(any(feature = "s113", feature = "s132", feature = "s140"))]
+pub struct ConnEvtTrigger<C: PpiChannel> {
+    conn: Connection,
+    ppi_ch: Option<C>,
+}
+
+#[cfg(any(feature = "s113", feature = "s132", feature = "s140"))]
+impl<C: PpiChannel> ConnEvtTrigger<C> {
+    /// Stop triggering and get the PPI channel back.
+    pub fn stop(mut self) -> C {
+        self.stop_inner();
+        unwrap!(self.ppi_ch.take())
+    }
+
+    fn stop_inner(&mut self) {
+        // The SoftDevice stops triggering by itself when the connection is lost.
+        if let Some(conn_handle) = self.conn.handle() {
+            let ret = unsafe { raw::sd_ble_gap_conn_evt_trigger_stop(conn_handle) };
+            if let Err(_e) = RawError::convert(ret) {
+                warn!("sd_ble_gap_conn_evt_trigger_stop: {:?}", _e);
+            }
+        }
+    }
+}
+
+#[cfg(any(feature = "s113", feature = "s132", feature = "s140"))]
+impl<C: PpiChannel> Drop for ConnEvtTrigger<C> {
+    fn drop(&mut self) {
+        if self.ppi_ch.is_some() {
+            self.stop_inner();
+        }
+    }
+}
+
 pub struct ConnectionIter(u8);
 
 impl Iterator for ConnectionIter {
@@ -848,3 +1524,89 @@ static mut INDEX_BY_HANDLE: [Cell<Option<u8>>; CONNS_MAX] = [INDEX_NONE; CONNS_M
 fn index_by_handle(conn_handle: u16) -> &'static Cell<Option<u8>> {
     unsafe { &INDEX_BY_HANDLE[conn_handle as usize] }
 }
+
+// Number of events kept per connection for subscriptions that fall behind.
+const EVENT_QUEUE_LEN: usize = 4;
+// Number of tasks waiting for events on a connection before all are woken to make room.
+const EVENT_WAKERS: usize = 4;
+
+struct EventQueue {
+    // Sequence number of the next event to be pushed.
+    seq: u32,
+    events: [Option<ConnectionEvent>; EVENT_QUEUE_LEN],
+    wakers: MultiWakerRegistration<EVENT_WAKERS>,
+}
+
+// Indexed by state index, which is stable for the lifetime of a `Connection`.
+static EVENT_QUEUES: [Mutex<CriticalSectionRawMutex, RefCell<EventQueue>>; CONNS_MAX] = [const {
+    Mutex::new(RefCell::new(EventQueue {
+        seq: 0,
+        events: [None; EVENT_QUEUE_LEN],
+        wakers: MultiWakerRegistration::new(),
+    }))
+}; CONNS_MAX];
+
+pub(crate) fn publish_event(conn_handle: u16, ble_evt: *const raw::ble_evt_t) {
+    let Some(index) = index_by_handle(conn_handle).get() else {
+        return;
+    };
+    let Some(evt) = (unsafe { ConnectionEvent::from_raw(ble_evt) }) else {
+        return;
+    };
+
+    EVENT_QUEUES[index as usize].lock(|queue| {
+        let mut queue = queue.borrow_mut();
+        let slot = queue.seq as usize % EVENT_QUEUE_LEN;
+        queue.events[slot] = Some(evt);
+        queue.seq = queue.seq.wrapping_add(1);
+        queue.wakers.wake();
+    });
+}
+
+// Number of tasks waiting for the disconnection of a connection before all are woken to make room.
+const DISCONNECT_WAKERS_LEN: usize = 4;
+
+type DisconnectWakers = Mutex<CriticalSectionRawMutex, RefCell<MultiWakerRegistration<DISCONNECT_WAKERS_LEN>>>;
+
+// Indexed by state index, which is stable for the lifetime of a `Connection`.
+static DISCONNECT_WAKERS: [DisconnectWakers; CONNS_MAX] =
+    [const { Mutex::new(RefCell::new(MultiWakerRegistration::new())) }; CONNS_MAX];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ble::AddressType;
+
+    // Free the state of a connection without instances, as the disconnect event would.
+    fn disconnected(conn_handle: u16) {
+        let ibh = index_by_handle(conn_handle);
+        let index = unwrap!(ibh.take());
+        with_state(index, |state| {
+            assert_eq!(state.refcount, 0);
+            state.conn_handle = ConnHandleState::Disconnected(HciStatus::REMOTE_USER_TERMINATED_CONNECTION);
+        });
+    }
+
+    #[cfg(feature = "ble-peripheral")]
+    #[test]
+    fn reacquire_clears_keep_alive() {
+        let address = Address::new(AddressType::Public, [1, 2, 3, 4, 5, 6]);
+        // Safety: all-zero is a valid value of this plain C struct.
+        let conn_params = unsafe { core::mem::zeroed() };
+        let conn = unwrap!(Connection::new(7, Role::Peripheral, address, conn_params));
+
+        let conn_handle = unwrap!(conn.detach());
+        let conn = unwrap!(Connection::from_handle(conn_handle));
+        assert!(!conn.with_state(|state| state.keep_alive));
+
+        // Another instance doesn't change the keep-alive of live instances.
+        conn.set_keep_alive(true);
+        let other = unwrap!(Connection::from_handle(conn_handle));
+        assert!(other.with_state(|state| state.keep_alive));
+
+        drop(conn);
+        drop(other);
+        disconnected(conn_handle);
+        assert!(Connection::from_handle(conn_handle).is_none());
+    }
+}
@@ -28,7 +28,9 @@ pub(crate) unsafe fn on_evt(ble_evt: *const raw::ble_evt_t) {
         }
         raw::BLE_GAP_EVTS_BLE_GAP_EVT_DISCONNECTED => {
             trace!("on_disconnected conn_handle={:?}", gap_evt.conn_handle);
-            connection::with_state_by_conn_handle(gap_evt.conn_handle, |state| state.on_disconnected(ble_evt));
+            let released =
+                connection::with_state_by_conn_handle(gap_evt.conn_handle, |state| state.on_disconnected(ble_evt));
+            drop(released);
         }
         raw::BLE_GAP_EVTS_BLE_GAP_EVT_CONN_PARAM_UPDATE => {
             let conn_params = gap_evt.params.conn_param_update.conn_params;
@@ -45,6 +47,7 @@ pub(crate) unsafe fn on_evt(ble_evt: *const raw::ble_evt_t) {
             connection::with_state_by_conn_handle(gap_evt.conn_handle, |state| {
                 state.conn_params = conn_params;
             });
+            connection::publish_event(gap_evt.conn_handle, ble_evt);
         }
         #[cfg(feature = "ble-central")]
         raw::BLE_GAP_EVTS_BLE_GAP_EVT_CONN_PARAM_UPDATE_REQUEST => {
@@ -59,7 +62,21 @@ pub(crate) unsafe fn on_evt(ble_evt: *const raw::ble_evt_t) {
                 conn_params.slave_latency,
             );
 
-            let ret = raw::sd_ble_gap_conn_param_update(conn_handle, &conn_params);
+            let reply = match Connection::from_handle(conn_handle) {
+                Some(conn) => conn
+                    .policy()
+                    .map(|p| p.conn_param_update_request(&conn, conn_params))
+                    .unwrap_or(Some(conn_params)),
+                None => Some(conn_params),
+            };
+            if reply.is_none() {
+                debug!("rejecting conn_param_update_request conn_handle={:?}", conn_handle);
+            }
+
+            let ret = raw::sd_ble_gap_conn_param_update(
+                conn_handle,
+                reply.as_ref().map(core::ptr::from_ref).unwrap_or(core::ptr::null()),
+            );
             if let Err(err) = RawError::convert(ret) {
                 warn!("sd_ble_gap_conn_param_update err {:?}", err);
             }
@@ -81,11 +98,31 @@ pub(crate) unsafe fn on_evt(ble_evt: *const raw::ble_evt_t) {
             trace!("adv_set_termnated");
             peripheral::ADV_PORTAL.call(ble_evt);
         }
+        #[cfg(feature = "ble-peripheral")]
+        raw::BLE_GAP_EVTS_BLE_GAP_EVT_SCAN_REQ_REPORT => {
+            let params = &gap_evt.params.scan_req_report;
+            let report = peripheral::ScanRequest {
+                peer_address: Address::from_raw(params.peer_addr),
+                rssi: params.rssi,
+            };
+            trace!(
+                "on_scan_req_report peer_addr={:?} rssi={:?}",
+                report.peer_address,
+                report.rssi
+            );
+            if peripheral::SCAN_REQUESTS.try_send(report).is_err() {
+                trace!("scan request queue full, dropping report");
+            }
+        }
         #[cfg(feature = "ble-central")]
         raw::BLE_GAP_EVTS_BLE_GAP_EVT_ADV_REPORT => {
             trace!("central on_adv_report");
             central::SCAN_PORTAL.call(ble_evt);
         }
+        #[cfg(any(feature = "s132", feature = "s140"))]
+        raw::BLE_GAP_EVTS_BLE_GAP_EVT_QOS_CHANNEL_SURVEY_REPORT => {
+            channels::on_report(ble_evt);
+        }
         raw::BLE_GAP_EVTS_BLE_GAP_EVT_PHY_UPDATE_REQUEST => {
             let peer_preferred_phys = gap_evt.params.phy_update_request.peer_preferred_phys;
             let conn_handle = gap_evt.conn_handle;
@@ -97,9 +134,12 @@ pub(crate) unsafe fn on_evt(ble_evt: *const raw::ble_evt_t) {
                 peer_preferred_phys.tx_phys
             );
 
-            let phys = raw::ble_gap_phys_t {
-                rx_phys: peer_preferred_phys.rx_phys,
-                tx_phys: peer_preferred_phys.tx_phys,
+            let phys = match Connection::from_handle(conn_handle) {
+                Some(conn) => conn
+                    .policy()
+                    .map(|p| p.phy_update_request(&conn, peer_preferred_phys))
+                    .unwrap_or(peer_preferred_phys),
+                None => peer_preferred_phys,
             };
 
             let ret = raw::sd_ble_gap_phy_update(conn_handle, &phys as *const raw::ble_gap_phys_t);
@@ -118,23 +158,27 @@ pub(crate) unsafe fn on_evt(ble_evt: *const raw::ble_evt_t) {
                 _phy_update.rx_phy,
                 _phy_update.tx_phy
             );
+            connection::publish_event(gap_evt.conn_handle, ble_evt);
         }
         #[cfg(any(feature = "s113", feature = "s132", feature = "s140"))]
         raw::BLE_GAP_EVTS_BLE_GAP_EVT_DATA_LENGTH_UPDATE_REQUEST => {
-            let _peer_params = gap_evt.params.data_length_update_request.peer_params;
+            let peer_params = gap_evt.params.data_length_update_request.peer_params;
 
             trace!(
                 "on_data_length_update_request conn_handle={:?} max_rx_octets={:?} max_rx_time_us={:?} max_tx_octets={:?} max_tx_time_us={:?}",
                 gap_evt.conn_handle,
-                _peer_params.max_rx_octets,
-                _peer_params.max_rx_time_us,
-                _peer_params.max_tx_octets,
-                _peer_params.max_tx_time_us,
+                peer_params.max_rx_octets,
+                peer_params.max_rx_time_us,
+                peer_params.max_tx_octets,
+                peer_params.max_tx_time_us,
             );
 
             let conn_handle = gap_evt.conn_handle;
             if let Some(mut conn) = Connection::from_handle(conn_handle) {
-                let _ = conn.data_length_update(None);
+                let params = conn
+                    .policy()
+                    .and_then(|p| p.data_length_update_request(&conn, peer_params));
+                let _ = conn.data_length_update(params.as_ref());
             }
         }
         #[cfg(any(feature = "s113", feature = "s132", feature = "s140"))]
@@ -153,16 +197,15 @@ pub(crate) unsafe fn on_evt(ble_evt: *const raw::ble_evt_t) {
                 effective_params.max_tx_octets,
                 effective_params.max_tx_time_us,
             );
+            connection::publish_event(gap_evt.conn_handle, ble_evt);
         }
         #[cfg(feature = "ble-rssi")]
         raw::BLE_GAP_EVTS_BLE_GAP_EVT_RSSI_CHANGED => {
             let new_rssi = gap_evt.params.rssi_changed.rssi;
             connection::with_state_by_conn_handle(gap_evt.conn_handle, |state| {
-                state.rssi = match state.rssi {
-                    None => Some(new_rssi),
-                    Some(old_rssi) => Some((((old_rssi as i16) * 7 + (new_rssi as i16)) / 8) as i8),
-                };
+                state.rssi = Some(state.rssi_filter.apply(state.rssi, new_rssi));
             });
+            connection::publish_event(gap_evt.conn_handle, ble_evt);
         }
         raw::BLE_GAP_EVTS_BLE_GAP_EVT_SEC_PARAMS_REQUEST => {
             let params = &gap_evt.params.sec_params_request;
@@ -369,13 +412,63 @@ pub(crate) unsafe fn on_evt(ble_evt: *const raw::ble_evt_t) {
         }
         // BLE_GAP_EVTS_BLE_GAP_EVT_KEY_PRESSED (LESC central pairing)
         // BLE_GAP_EVTS_BLE_GAP_EVT_LESC_DHKEY_REQUEST (LESC key calculation)
-        // BLE_GAP_EVTS_BLE_GAP_EVT_RSSI_CHANGED
-        // BLE_GAP_EVTS_BLE_GAP_EVT_SCAN_REQ_REPORT
-        // BLE_GAP_EVTS_BLE_GAP_EVT_QOS_CHANNEL_SURVEY_REPORT
         _ => {}
     }
 }
 
+/// Decides how to reply to link-layer update requests from the peer.
+///
+/// Set it for all connections with [`set_connection_policy`], or for a single connection with
+/// [`Connection::set_policy`]. The default implementations behave as if no policy was set.
+pub trait ConnectionPolicy {
+    /// Choose the PHYs to reply with to a PHY update request from the peer.
+    ///
+    /// The default implementation accepts the peer's preferred PHYs.
+    fn phy_update_request(&self, conn: &Connection, peer_preferred_phys: raw::ble_gap_phys_t) -> raw::ble_gap_phys_t {
+        let _ = conn;
+        peer_preferred_phys
+    }
+
+    /// Choose the parameters to reply with to a data length update request from the peer.
+    ///
+    /// Returning `None` lets the softdevice pick the parameters, which is what the default implementation does.
+    #[cfg(any(feature = "s113", feature = "s132", feature = "s140"))]
+    fn data_length_update_request(
+        &self,
+        conn: &Connection,
+        peer_params: raw::ble_gap_data_length_params_t,
+    ) -> Option<raw::ble_gap_data_length_params_t> {
+        let _ = (conn, peer_params);
+        None
+    }
+
+    /// Choose the connection parameters to apply in reply to a connection parameter update request
+    /// from the peripheral.
+    ///
+    /// Returning `None` rejects the request. The default implementation accepts the requested parameters.
+    #[cfg(feature = "ble-central")]
+    fn conn_param_update_request(
+        &self,
+        conn: &Connection,
+        conn_params: raw::ble_gap_conn_params_t,
+    ) -> Option<raw::ble_gap_conn_params_t> {
+        let _ = conn;
+        Some(conn_params)
+    }
+}
+
+static mut CONNECTION_POLICY: Option<&'static dyn ConnectionPolicy> = None;
+
+/// Set the policy used to reply to update requests on connections that don't have their own.
+pub fn set_connection_policy(sd: &Softdevice, policy: Option<&'static dyn ConnectionPolicy>) {
+    let _ = sd;
+    unsafe { CONNECTION_POLICY = policy };
+}
+
+pub(crate) fn connection_policy() -> Option<&'static dyn ConnectionPolicy> {
+    unsafe { CONNECTION_POLICY }
+}
+
 pub fn set_device_identities_list(
     sd: &Softdevice,
     id_keys: &[IdentityKey],
@@ -440,6 +533,62 @@ pub fn set_whitelist(sd: &Softdevice, addrs: &[Address]) -> Result<(), RawError>
     RawError::convert(ret)
 }
 
+/// Set the device name exposed in the GAP service.
+///
+/// `write_perm` controls whether and how peers may write the Device Name characteristic.
+/// Use [`SecurityMode::NoAccess`] to make it read-only.
+pub fn set_device_name(sd: &Softdevice, name: &[u8], write_perm: SecurityMode) -> Result<(), RawError> {
+    let _ = sd;
+    let write_perm = write_perm.into_raw();
+    let ret = unsafe { raw::sd_ble_gap_device_name_set(&write_perm, name.as_ptr(), name.len() as u16) };
+    RawError::convert(ret)
+}
+
+/// Read the device name into `buf`, returning its length.
+///
+/// This includes changes written by peers if the name is writable.
+pub fn get_device_name(sd: &Softdevice, buf: &mut [u8]) -> Result<usize, RawError> {
+    let _ = sd;
+    let mut len = buf.len() as u16;
+    let ret = unsafe { raw::sd_ble_gap_device_name_get(buf.as_mut_ptr(), &mut len) };
+    RawError::convert(ret)?;
+    Ok(len as usize)
+}
+
+/// Set the appearance exposed in the GAP service.
+pub fn set_appearance(sd: &Softdevice, appearance: Appearance) -> Result<(), RawError> {
+    let _ = sd;
+    let ret = unsafe { raw::sd_ble_gap_appearance_set(appearance.into_raw()) };
+    RawError::convert(ret)
+}
+
+pub fn get_appearance(sd: &Softdevice) -> Result<Appearance, RawError> {
+    let _ = sd;
+    let mut appearance: u16 = 0;
+    let ret = unsafe { raw::sd_ble_gap_appearance_get(&mut appearance) };
+    RawError::convert(ret)?;
+    Ok(Appearance::from_raw(appearance))
+}
+
+/// Set the Peripheral Preferred Connection Parameters exposed in the GAP service.
+///
+/// The characteristic is only present if it was enabled with `Config::gap_ppcp_incl`.
+#[cfg(any(feature = "s112", feature = "s113", feature = "s132", feature = "s140"))]
+pub fn set_ppcp(sd: &Softdevice, conn_params: &raw::ble_gap_conn_params_t) -> Result<(), RawError> {
+    let _ = sd;
+    let ret = unsafe { raw::sd_ble_gap_ppcp_set(conn_params) };
+    RawError::convert(ret)
+}
+
+#[cfg(any(feature = "s112", feature = "s113", feature = "s132", feature = "s140"))]
+pub fn get_ppcp(sd: &Softdevice) -> Result<raw::ble_gap_conn_params_t, RawError> {
+    let _ = sd;
+    let mut conn_params: raw::ble_gap_conn_params_t = unsafe { core::mem::zeroed() };
+    let ret = unsafe { raw::sd_ble_gap_ppcp_get(&mut conn_params) };
+    RawError::convert(ret)?;
+    Ok(conn_params)
+}
+
 pub fn default_security_params() -> raw::ble_gap_sec_params_t {
     let mut sec_params: raw::ble_gap_sec_params_t = unsafe { core::mem::zeroed() };
 
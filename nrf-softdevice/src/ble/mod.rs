@@ -16,14 +16,27 @@ mod common;
 #[cfg(feature = "ble-sec")]
 pub mod security;
 
+#[cfg(any(feature = "ble-central", feature = "s132", feature = "s140"))]
+pub mod channels;
+
+pub mod privacy;
+
+#[cfg(feature = "ble-rssi")]
+pub mod tx_power;
+
 #[cfg(feature = "ble-central")]
 pub mod central;
 
-#[cfg(feature = "ble-peripheral")]
+#[cfg(any(feature = "ble-peripheral", feature = "ble-central"))]
 pub mod advertisement_builder;
+#[cfg(any(feature = "ble-peripheral", feature = "ble-central"))]
+pub mod advertisement_parser;
 #[cfg(feature = "ble-peripheral")]
 pub mod peripheral;
 
+#[cfg(feature = "ble-conn-params")]
+pub mod conn_params;
+
 #[cfg(feature = "ble-gatt-client")]
 pub mod gatt_client;
 
@@ -1,6 +1,22 @@
 //! Bluetooth Peripheral operations. Peripheral devices emit advertisements, and optionally accept connections from Central devices.
 
+#[cfg(feature = "ble-gatt-server")]
+use core::future::Future;
+use core::marker::PhantomData;
+#[cfg(feature = "ble-gatt-server")]
+use core::pin::{pin, Pin};
 use core::ptr;
+use core::sync::atomic::{AtomicBool, Ordering};
+#[cfg(feature = "ble-gatt-server")]
+use core::task::{Context, Poll};
+
+#[cfg(feature = "ble-gatt-server")]
+use embassy_futures::select::{select, Either};
+use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
+use embassy_sync::channel::Channel;
+#[cfg(feature = "ble-gatt-server")]
+use futures::future::poll_fn;
+use futures::Stream;
 
 use crate::ble::*;
 use crate::util::{get_union_field, OnDrop, Portal};
@@ -207,6 +223,8 @@ impl<'a> From<NonconnectableAdvertisement<'a>> for RawAdvertisement<'a> {
 pub enum AdvertiseError {
     Timeout,
     NoFreeConn,
+    /// Another advertising procedure or [`Advertiser`] is active.
+    AlreadyAdvertising,
     Raw(RawError),
 }
 
@@ -219,7 +237,54 @@ impl From<RawError> for AdvertiseError {
 static mut ADV_HANDLE: u8 = raw::BLE_GAP_ADV_SET_HANDLE_NOT_SET as u8;
 pub(crate) static ADV_PORTAL: Portal<*const raw::ble_evt_t> = Portal::new();
 
-fn start_adv(adv: RawAdvertisement<'_>, config: &Config) -> Result<(), AdvertiseError> {
+// The advertising set, its buffers and `ADV_PORTAL` can only be used by one procedure at a time.
+static ADV_TAKEN: AtomicBool = AtomicBool::new(false);
+
+/// Exclusive use of the advertising set, released on drop.
+struct AdvGuard(());
+
+impl AdvGuard {
+    fn take() -> Result<Self, AdvertiseError> {
+        if ADV_TAKEN
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .is_err()
+        {
+            return Err(AdvertiseError::AlreadyAdvertising);
+        }
+        Ok(Self(()))
+    }
+}
+
+impl Drop for AdvGuard {
+    fn drop(&mut self) {
+        ADV_TAKEN.store(false, Ordering::Release);
+    }
+}
+
+/// A scan request received while advertising with [`Config::scan_request_notification`] enabled.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ScanRequest {
+    /// Address of the scanner. If it was resolved with a known IRK, this is its identity address.
+    pub peer_address: Address,
+    /// RSSI of the scan request, in dBm.
+    pub rssi: i8,
+}
+
+const SCAN_REQUEST_QUEUE_SIZE: usize = 8;
+pub(crate) static SCAN_REQUESTS: Channel<CriticalSectionRawMutex, ScanRequest, SCAN_REQUEST_QUEUE_SIZE> =
+    Channel::new();
+
+/// Stream of scan requests received while advertising.
+///
+/// Reports are only generated when advertising with [`Config::scan_request_notification`] set, using
+/// a scannable advertisement type. Up to 8 reports are queued; further reports are dropped until the
+/// stream is polled again. Stale reports are discarded every time advertising starts.
+pub fn scan_requests(_sd: &Softdevice) -> impl Stream<Item = ScanRequest> + 'static {
+    SCAN_REQUESTS.receiver()
+}
+
+fn adv_params(adv: &RawAdvertisement<'_>, config: &Config) -> raw::ble_gap_adv_params_t {
     let mut adv_params: raw::ble_gap_adv_params_t = unsafe { core::mem::zeroed() };
 
     adv_params.properties.type_ = adv.kind;
@@ -233,78 +298,125 @@ fn start_adv(adv: RawAdvertisement<'_>, config: &Config) -> Result<(), Advertise
     adv_params.interval = config.interval;
     adv_params.filter_policy = config.filter_policy as u8;
     adv_params.set_set_id(adv.set_id);
-    // Unsupported: channel_mask and scan_req_notification
-
-    let map_data = |data: Option<&[u8]>| {
-        if let Some(data) = data {
-            assert!(data.len() < u16::MAX as usize);
-            raw::ble_data_t {
-                p_data: data.as_ptr() as _,
-                len: data.len() as u16,
-            }
-        } else {
-            raw::ble_data_t {
-                p_data: ptr::null_mut(),
-                len: 0,
-            }
-        }
-    };
+    adv_params.set_scan_req_notification(u8::from(config.scan_request_notification));
+    adv_params.channel_mask = config.channel_mask.to_raw();
 
-    let datas = raw::ble_gap_adv_data_t {
-        adv_data: map_data(adv.adv_data),
-        scan_rsp_data: map_data(adv.scan_data),
-    };
+    adv_params
+}
+
+fn map_data(data: Option<&[u8]>) -> raw::ble_data_t {
+    if let Some(data) = data {
+        assert!(data.len() < u16::MAX as usize);
+        raw::ble_data_t {
+            p_data: data.as_ptr() as _,
+            len: data.len() as u16,
+        }
+    } else {
+        raw::ble_data_t {
+            p_data: ptr::null_mut(),
+            len: 0,
+        }
+    }
+}
 
-    let ret =
-        unsafe { raw::sd_ble_gap_adv_set_configure(ptr::addr_of!(ADV_HANDLE) as _, &datas as _, &adv_params as _) };
-    RawError::convert(ret).map_err(|err| {
+fn configure_adv(
+    datas: &raw::ble_gap_adv_data_t,
+    adv_params: Option<&raw::ble_gap_adv_params_t>,
+) -> Result<(), RawError> {
+    let adv_params = adv_params.map(|p| p as *const _).unwrap_or(ptr::null());
+    let ret = unsafe { raw::sd_ble_gap_adv_set_configure(ptr::addr_of_mut!(ADV_HANDLE), datas as _, adv_params) };
+    if let Err(err) = RawError::convert(ret) {
         warn!("sd_ble_gap_adv_set_configure err {:?}", err);
-        err
-    })?;
+        return Err(err);
+    }
+    Ok(())
+}
 
+fn set_adv_tx_power(tx_power: TxPower) -> Result<(), RawError> {
     let ret = unsafe {
         raw::sd_ble_gap_tx_power_set(
             raw::BLE_GAP_TX_POWER_ROLES_BLE_GAP_TX_POWER_ROLE_ADV as _,
             ADV_HANDLE as _,
-            config.tx_power as i8,
+            tx_power as i8,
         )
     };
-    RawError::convert(ret).map_err(|err| {
+    if let Err(err) = RawError::convert(ret) {
         warn!("sd_ble_gap_tx_power_set err {:?}", err);
-        err
-    })?;
+        return Err(err);
+    }
+    Ok(())
+}
 
+fn adv_start() -> Result<(), RawError> {
     let ret = unsafe { raw::sd_ble_gap_adv_start(ADV_HANDLE, 1u8) };
-    RawError::convert(ret).map_err(|err| {
+    if let Err(err) = RawError::convert(ret) {
         warn!("sd_ble_gap_adv_start err {:?}", err);
-        err
-    })?;
+        return Err(err);
+    }
+    Ok(())
+}
+
+fn adv_stop() {
+    let ret = unsafe { raw::sd_ble_gap_adv_stop(ADV_HANDLE) };
+    if let Err(_e) = RawError::convert(ret) {
+        warn!("sd_ble_gap_adv_stop: {:?}", _e);
+    }
+}
+
+fn start_adv(adv: RawAdvertisement<'_>, config: &Config) -> Result<(), AdvertiseError> {
+    let adv_params = adv_params(&adv, config);
+    if config.scan_request_notification {
+        SCAN_REQUESTS.clear();
+    }
+    let datas = raw::ble_gap_adv_data_t {
+        adv_data: map_data(adv.adv_data),
+        scan_rsp_data: map_data(adv.scan_data),
+    };
+
+    configure_adv(&datas, Some(&adv_params))?;
+    set_adv_tx_power(config.tx_power)?;
+    adv_start()?;
 
     Ok(())
 }
 
+/// Get the address currently used for advertising.
+///
+/// With privacy enabled this is the current private address, which changes every time it is rotated.
+/// Returns [`RawError::BleInvalidAdvHandle`] if no advertising set has been configured yet.
+pub fn get_adv_address(_sd: &Softdevice) -> Result<Address, RawError> {
+    if unsafe { ADV_HANDLE } == raw::BLE_GAP_ADV_SET_HANDLE_NOT_SET as u8 {
+        return Err(RawError::BleInvalidAdvHandle);
+    }
+    let mut addr: raw::ble_gap_addr_t = unsafe { core::mem::zeroed() };
+    let ret = unsafe { raw::sd_ble_gap_adv_addr_get(ADV_HANDLE, &mut addr) };
+    RawError::convert(ret)?;
+    Ok(Address::from_raw(addr))
+}
+
 /// Perform non-connectable advertising.
+///
+/// Fails with [`AdvertiseError::AlreadyAdvertising`] if another advertising procedure is active.
 pub async fn advertise(
     _sd: &Softdevice,
     adv: NonconnectableAdvertisement<'_>,
     config: &Config,
 ) -> Result<(), AdvertiseError> {
-    let d = OnDrop::new(|| {
-        let ret = unsafe { raw::sd_ble_gap_adv_stop(ADV_HANDLE) };
-        if let Err(_e) = RawError::convert(ret) {
-            warn!("sd_ble_gap_adv_stop: {:?}", _e);
-        }
-    });
+    let _guard = AdvGuard::take()?;
+    let d = OnDrop::new(adv_stop);
 
     start_adv(adv.into(), config)?;
 
     // The advertising data needs to be kept alive for the entire duration of the advertising procedure.
     let res = ADV_PORTAL
-        .wait_once(|ble_evt| unsafe {
+        .wait_many(|ble_evt| unsafe {
             match (*ble_evt).header.evt_id as u32 {
-                raw::BLE_GAP_EVTS_BLE_GAP_EVT_TIMEOUT => Err(AdvertiseError::Timeout),
-                raw::BLE_GAP_EVTS_BLE_GAP_EVT_ADV_SET_TERMINATED => Err(AdvertiseError::Timeout),
-                e => panic!("unexpected event {}", e),
+                raw::BLE_GAP_EVTS_BLE_GAP_EVT_TIMEOUT => Some(Err(AdvertiseError::Timeout)),
+                raw::BLE_GAP_EVTS_BLE_GAP_EVT_ADV_SET_TERMINATED => Some(Err(AdvertiseError::Timeout)),
+                e => {
+                    warn!("advertise: ignoring unexpected event {:?}", e);
+                    None
+                }
             }
         })
         .await;
@@ -314,6 +426,8 @@ pub async fn advertise(
 }
 
 /// Perform connectable advertising, returning the connection that's established as a result.
+///
+/// Fails with [`AdvertiseError::AlreadyAdvertising`] if another advertising procedure is active.
 pub async fn advertise_connectable(
     sd: &Softdevice,
     adv: ConnectableAdvertisement<'_>,
@@ -344,50 +458,416 @@ async fn advertise_inner<'a, F>(
 where
     F: FnMut(u16, Role, Address, raw::ble_gap_conn_params_t) -> Result<Connection, OutOfConnsError>,
 {
-    let d = OnDrop::new(|| {
-        let ret = unsafe { raw::sd_ble_gap_adv_stop(ADV_HANDLE) };
-        if let Err(_e) = RawError::convert(ret) {
-            warn!("sd_ble_gap_adv_stop: {:?}", _e);
-        }
-    });
+    let _guard = AdvGuard::take()?;
+    let d = OnDrop::new(adv_stop);
 
     start_adv(adv.into(), config)?;
 
     // The advertising data needs to be kept alive for the entire duration of the advertising procedure.
     let res = ADV_PORTAL
-        .wait_once(|ble_evt| unsafe {
-            match (*ble_evt).header.evt_id as u32 {
-                raw::BLE_GAP_EVTS_BLE_GAP_EVT_CONNECTED => {
-                    let gap_evt = get_union_field(ble_evt, &(*ble_evt).evt.gap_evt);
-                    let params = &gap_evt.params.connected;
-                    let conn_handle = gap_evt.conn_handle;
-                    let role = Role::from_raw(params.role);
-                    let peer_address = Address::from_raw(params.peer_addr);
-                    let conn_params = params.conn_params;
-                    debug!("connected role={:?} peer_addr={:?}", role, peer_address);
-
-                    match f(conn_handle, role, peer_address, conn_params) {
-                        Ok(conn) => Ok(conn),
-                        Err(_) => {
-                            raw::sd_ble_gap_disconnect(
-                                conn_handle,
-                                raw::BLE_HCI_REMOTE_DEV_TERMINATION_DUE_TO_LOW_RESOURCES as _,
-                            );
-                            Err(AdvertiseError::NoFreeConn)
-                        }
-                    }
-                }
-                raw::BLE_GAP_EVTS_BLE_GAP_EVT_TIMEOUT => Err(AdvertiseError::Timeout),
-                raw::BLE_GAP_EVTS_BLE_GAP_EVT_ADV_SET_TERMINATED => Err(AdvertiseError::Timeout),
-                e => panic!("unexpected event {}", e),
-            }
-        })
+        .wait_many(|ble_evt| unsafe { on_connectable_adv_evt(ble_evt, &mut f) })
         .await;
 
     d.defuse();
     res
 }
 
+/// Serve up to `N` concurrent connections with a GATT server, advertising whenever a connection slot is free.
+///
+/// For every connection, the GATT server runs with `on_event` receiving its events, concurrently with
+/// the future returned by `handler`. When `handler` completes the connection is kept open; it is
+/// closed once the GATT server stops because the link dropped. Advertising resumes as soon as a slot
+/// frees up.
+///
+/// The number of concurrent connections is also capped by `periph_role_count` from the softdevice
+/// `gap_role_count` config. Advertising timeouts restart advertising. Returns only on other errors.
+#[cfg(feature = "ble-gatt-server")]
+pub async fn serve<const N: usize, S, F>(
+    sd: &Softdevice,
+    adv: ConnectableAdvertisement<'_>,
+    config: &Config,
+    server: &S,
+    on_event: impl Fn(&Connection, S::Event),
+    handler: impl Fn(Connection) -> F,
+) -> AdvertiseError
+where
+    S: crate::ble::gatt_server::Server,
+    F: Future<Output = ()>,
+{
+    let limit = N.min(sd.periph_role_count as usize);
+    let slots: [Option<_>; N] = core::array::from_fn(|_| None);
+    let mut slots = pin!(slots);
+
+    loop {
+        let Some(index) = free_slot(&slots, limit) else {
+            poll_fn(|cx| poll_slots(slots.as_mut(), cx)).await;
+            continue;
+        };
+
+        let mut adv_fut = pin!(advertise_connectable(sd, adv, config));
+        let res = loop {
+            match select(adv_fut.as_mut(), poll_fn(|cx| poll_slots(slots.as_mut(), cx))).await {
+                Either::First(res) => break res,
+                Either::Second(()) => {}
+            }
+        };
+
+        match res {
+            Ok(conn) => {
+                let fut = serve_connection(conn.clone(), server, &on_event, handler(conn));
+                set_slot(slots.as_mut(), index, fut);
+            }
+            Err(AdvertiseError::Timeout | AdvertiseError::NoFreeConn) => {}
+            Err(err) => return err,
+        }
+    }
+}
+
+#[cfg(feature = "ble-gatt-server")]
+async fn serve_connection<S: crate::ble::gatt_server::Server>(
+    conn: Connection,
+    server: &S,
+    on_event: &impl Fn(&Connection, S::Event),
+    handler: impl Future<Output = ()>,
+) {
+    let gatt = crate::ble::gatt_server::run(&conn, server, |e| on_event(&conn, e));
+    let handler = async {
+        handler.await;
+        core::future::pending::<()>().await
+    };
+    let _ = select(gatt, handler).await;
+}
+
+#[cfg(feature = "ble-gatt-server")]
+fn free_slot<F, const N: usize>(slots: &[Option<F>; N], limit: usize) -> Option<usize> {
+    if slots.iter().filter(|s| s.is_some()).count() >= limit {
+        return None;
+    }
+    slots.iter().position(Option::is_none)
+}
+
+#[cfg(feature = "ble-gatt-server")]
+fn set_slot<F, const N: usize>(slots: Pin<&mut [Option<F>; N]>, index: usize, fut: F) {
+    // Safety: the slot is dropped in place before being overwritten, and never moved.
+    unsafe { Pin::new_unchecked(&mut slots.get_unchecked_mut()[index]).set(Some(fut)) }
+}
+
+/// Poll all running connections, returning `Ready` if at least one of them finished.
+#[cfg(feature = "ble-gatt-server")]
+fn poll_slots<F: Future<Output = ()>, const N: usize>(
+    slots: Pin<&mut [Option<F>; N]>,
+    cx: &mut Context<'_>,
+) -> Poll<()> {
+    // Safety: the futures are never moved out of their slots.
+    let slots = unsafe { slots.get_unchecked_mut() };
+    let mut finished = false;
+    for slot in slots.iter_mut() {
+        if let Some(fut) = slot {
+            if unsafe { Pin::new_unchecked(fut) }.poll(cx).is_ready() {
+                *slot = None;
+                finished = true;
+            }
+        }
+    }
+    if finished {
+        Poll::Ready(())
+    } else {
+        Poll::Pending
+    }
+}
+
+unsafe fn on_connectable_adv_evt<F>(
+    ble_evt: *const raw::ble_evt_t,
+    f: &mut F,
+) -> Option<Result<Connection, AdvertiseError>>
+where
+    F: FnMut(u16, Role, Address, raw::ble_gap_conn_params_t) -> Result<Connection, OutOfConnsError>,
+{
+    match (*ble_evt).header.evt_id as u32 {
+        raw::BLE_GAP_EVTS_BLE_GAP_EVT_CONNECTED => {
+            let gap_evt = get_union_field(ble_evt, &(*ble_evt).evt.gap_evt);
+            let params = &gap_evt.params.connected;
+            let conn_handle = gap_evt.conn_handle;
+            let role = Role::from_raw(params.role);
+            let peer_address = Address::from_raw(params.peer_addr);
+            let conn_params = params.conn_params;
+            debug!("connected role={:?} peer_addr={:?}", role, peer_address);
+
+            match f(conn_handle, role, peer_address, conn_params) {
+                Ok(conn) => Some(Ok(conn)),
+                Err(_) => {
+                    raw::sd_ble_gap_disconnect(
+                        conn_handle,
+                        raw::BLE_HCI_REMOTE_DEV_TERMINATION_DUE_TO_LOW_RESOURCES as _,
+                    );
+                    Some(Err(AdvertiseError::NoFreeConn))
+                }
+            }
+        }
+        raw::BLE_GAP_EVTS_BLE_GAP_EVT_TIMEOUT => Some(Err(AdvertiseError::Timeout)),
+        raw::BLE_GAP_EVTS_BLE_GAP_EVT_ADV_SET_TERMINATED => Some(Err(AdvertiseError::Timeout)),
+        e => {
+            warn!("advertise: ignoring unexpected event {:?}", e);
+            None
+        }
+    }
+}
+
+#[cfg(any(feature = "s132", feature = "s140"))]
+const ADV_DATA_MAX: usize = raw::BLE_GAP_ADV_SET_DATA_SIZE_EXTENDED_MAX_SUPPORTED as usize;
+#[cfg(not(any(feature = "s132", feature = "s140")))]
+const ADV_DATA_MAX: usize = raw::BLE_GAP_ADV_SET_DATA_SIZE_MAX as usize;
+
+struct AdvBuffer {
+    adv_data: [u8; ADV_DATA_MAX],
+    scan_data: [u8; ADV_DATA_MAX],
+}
+
+// The softdevice keeps pointers to the advertising data for as long as the set is configured,
+// and refuses to update it in place while advertising. `Advertiser` alternates between these two.
+static mut ADV_BUFFERS: [AdvBuffer; 2] = [
+    AdvBuffer {
+        adv_data: [0; ADV_DATA_MAX],
+        scan_data: [0; ADV_DATA_MAX],
+    },
+    AdvBuffer {
+        adv_data: [0; ADV_DATA_MAX],
+        scan_data: [0; ADV_DATA_MAX],
+    },
+];
+
+/// Handle to a running advertising set whose data and parameters can be changed without restarting it.
+///
+/// Unlike [`advertise`] and [`advertise_connectable`], the advertising data is copied into
+/// buffers owned by the handle, so it can be swapped while advertising without gaps.
+///
+/// Advertising is stopped when the `Advertiser` is dropped.
+pub struct Advertiser {
+    kind: u8,
+    peer: Option<Address>,
+    anonymous: bool,
+    set_id: u8,
+    config: Config,
+    adv_len: Option<usize>,
+    scan_len: Option<usize>,
+    current: usize,
+    advertising: bool,
+    _guard: AdvGuard,
+    // Prevent Send, Sync
+    _private: PhantomData<*mut ()>,
+}
+
+impl Advertiser {
+    /// Start non-connectable advertising.
+    ///
+    /// Fails with [`AdvertiseError::AlreadyAdvertising`] if another `Advertiser` is alive or another
+    /// advertising procedure is active.
+    pub fn new(sd: &Softdevice, adv: NonconnectableAdvertisement<'_>, config: &Config) -> Result<Self, AdvertiseError> {
+        Self::new_inner(sd, adv.into(), config)
+    }
+
+    /// Start connectable advertising. Use [`Advertiser::wait`] to accept the connection.
+    ///
+    /// Fails with [`AdvertiseError::AlreadyAdvertising`] if another `Advertiser` is alive or another
+    /// advertising procedure is active.
+    pub fn new_connectable(
+        sd: &Softdevice,
+        adv: ConnectableAdvertisement<'_>,
+        config: &Config,
+    ) -> Result<Self, AdvertiseError> {
+        Self::new_inner(sd, adv.into(), config)
+    }
+
+    fn new_inner(_sd: &Softdevice, adv: RawAdvertisement<'_>, config: &Config) -> Result<Self, AdvertiseError> {
+        let guard = AdvGuard::take()?;
+        let mut this = Self {
+            kind: adv.kind,
+            peer: adv.peer,
+            anonymous: adv.anonymous,
+            set_id: adv.set_id,
+            config: *config,
+            adv_len: None,
+            scan_len: None,
+            current: 0,
+            advertising: false,
+            _guard: guard,
+            _private: PhantomData,
+        };
+
+        let buf = unsafe { &mut *ptr::addr_of_mut!(ADV_BUFFERS[0]) };
+        this.adv_len = copy_data(&mut buf.adv_data, adv.adv_data)?;
+        this.scan_len = copy_data(&mut buf.scan_data, adv.scan_data)?;
+
+        if config.scan_request_notification {
+            SCAN_REQUESTS.clear();
+        }
+        configure_adv(&this.datas(0), Some(&this.params()))?;
+        set_adv_tx_power(config.tx_power)?;
+        adv_start()?;
+        this.advertising = true;
+
+        Ok(this)
+    }
+
+    fn params(&self) -> raw::ble_gap_adv_params_t {
+        let adv = RawAdvertisement {
+            kind: self.kind,
+            adv_data: None,
+            scan_data: None,
+            peer: self.peer,
+            anonymous: self.anonymous,
+            set_id: self.set_id,
+        };
+        adv_params(&adv, &self.config)
+    }
+
+    fn datas(&self, index: usize) -> raw::ble_gap_adv_data_t {
+        let buf = unsafe { &*ptr::addr_of!(ADV_BUFFERS[index]) };
+        raw::ble_gap_adv_data_t {
+            adv_data: map_data(self.adv_len.map(|len| &buf.adv_data[..len])),
+            scan_rsp_data: map_data(self.scan_len.map(|len| &buf.scan_data[..len])),
+        }
+    }
+
+    /// Whether the advertising set is currently running, as far as the handle knows.
+    ///
+    /// The set stopping on its own (timeout, max events, connection) is only observed by [`Advertiser::wait`].
+    pub fn is_advertising(&self) -> bool {
+        self.advertising
+    }
+
+    /// Replace the advertising data and scan response data.
+    ///
+    /// Only the data kinds used by the advertisement type passed at creation can be updated;
+    /// the other one is ignored.
+    pub fn set_data(&mut self, adv_data: &[u8], scan_data: &[u8]) -> Result<(), AdvertiseError> {
+        self.update(Some(adv_data), Some(scan_data))
+    }
+
+    /// Replace the advertising data, keeping the scan response data.
+    pub fn set_adv_data(&mut self, adv_data: &[u8]) -> Result<(), AdvertiseError> {
+        self.update(Some(adv_data), None)
+    }
+
+    /// Replace the scan response data, keeping the advertising data.
+    pub fn set_scan_data(&mut self, scan_data: &[u8]) -> Result<(), AdvertiseError> {
+        self.update(None, Some(scan_data))
+    }
+
+    fn update(&mut self, adv_data: Option<&[u8]>, scan_data: Option<&[u8]>) -> Result<(), AdvertiseError> {
+        let next = 1 - self.current;
+        let (cur, buf) = unsafe {
+            (
+                &*ptr::addr_of!(ADV_BUFFERS[self.current]),
+                &mut *ptr::addr_of_mut!(ADV_BUFFERS[next]),
+            )
+        };
+
+        let adv_len = match self.adv_len {
+            Some(len) => copy_data(&mut buf.adv_data, Some(adv_data.unwrap_or(&cur.adv_data[..len])))?,
+            None => None,
+        };
+        let scan_len = match self.scan_len {
+            Some(len) => copy_data(&mut buf.scan_data, Some(scan_data.unwrap_or(&cur.scan_data[..len])))?,
+            None => None,
+        };
+
+        let (old_adv_len, old_scan_len) = (self.adv_len, self.scan_len);
+        self.adv_len = adv_len;
+        self.scan_len = scan_len;
+        if let Err(err) = configure_adv(&self.datas(next), None) {
+            self.adv_len = old_adv_len;
+            self.scan_len = old_scan_len;
+            return Err(err.into());
+        }
+
+        self.current = next;
+        Ok(())
+    }
+
+    /// Change the advertising TX power. Takes effect immediately.
+    pub fn set_tx_power(&mut self, tx_power: TxPower) -> Result<(), RawError> {
+        set_adv_tx_power(tx_power)?;
+        self.config.tx_power = tx_power;
+        Ok(())
+    }
+
+    /// Change the advertising interval, in 625us units.
+    ///
+    /// The softdevice does not allow changing the parameters of a running set, so advertising
+    /// is briefly stopped and restarted. If the new interval is rejected, advertising is restarted with the
+    /// previous one; it is only left stopped if that fails too, see [`Advertiser::is_advertising`].
+    pub fn set_interval(&mut self, interval: u32) -> Result<(), AdvertiseError> {
+        let old_interval = self.config.interval;
+        self.config.interval = interval;
+
+        let was_advertising = self.advertising;
+        if was_advertising {
+            adv_stop();
+            self.advertising = false;
+        }
+
+        if let Err(err) = configure_adv(&self.datas(self.current), Some(&self.params())) {
+            // The set keeps its previous parameters when configuring fails.
+            self.config.interval = old_interval;
+            if was_advertising {
+                let _ = self.start();
+            }
+            return Err(err.into());
+        }
+
+        if was_advertising {
+            self.start()?;
+        }
+        Ok(())
+    }
+
+    /// Restart advertising after it stopped, with the current data and parameters.
+    pub fn start(&mut self) -> Result<(), AdvertiseError> {
+        adv_start()?;
+        self.advertising = true;
+        Ok(())
+    }
+
+    /// Stop advertising. It can be restarted with [`Advertiser::start`].
+    pub fn stop(&mut self) {
+        if self.advertising {
+            adv_stop();
+            self.advertising = false;
+        }
+    }
+
+    /// Wait for the advertising set to stop.
+    ///
+    /// Returns the connection for connectable advertising, or [`AdvertiseError::Timeout`]
+    /// when the set times out or reaches `max_events`. Connections arriving while nobody is waiting
+    /// are rejected.
+    pub async fn wait(&mut self) -> Result<Connection, AdvertiseError> {
+        let res = ADV_PORTAL
+            .wait_many(|ble_evt| unsafe { on_connectable_adv_evt(ble_evt, &mut Connection::new) })
+            .await;
+        self.advertising = false;
+        res
+    }
+}
+
+impl Drop for Advertiser {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+fn copy_data(buf: &mut [u8; ADV_DATA_MAX], data: Option<&[u8]>) -> Result<Option<usize>, RawError> {
+    match data {
+        Some(data) => {
+            let dst = buf.get_mut(..data.len()).ok_or(RawError::DataSize)?;
+            dst.copy_from_slice(data);
+            Ok(Some(data.len()))
+        }
+        None => Ok(None),
+    }
+}
+
 #[repr(u8)]
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
@@ -399,6 +879,67 @@ pub enum FilterPolicy {
     Both = raw::BLE_GAP_ADV_FP_FILTER_BOTH as u8,
 }
 
+/// Set of primary advertising channels to use. Always contains at least one channel.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AdvChannels(u8);
+
+impl AdvChannels {
+    pub const CH37: Self = Self(0b001);
+    pub const CH38: Self = Self(0b010);
+    pub const CH39: Self = Self(0b100);
+    pub const ALL: Self = Self(0b111);
+
+    /// Build a channel set, returning `None` if no channel is enabled.
+    pub const fn new(ch37: bool, ch38: bool, ch39: bool) -> Option<Self> {
+        let bits = (ch37 as u8) | (ch38 as u8) << 1 | (ch39 as u8) << 2;
+        if bits == 0 {
+            None
+        } else {
+            Some(Self(bits))
+        }
+    }
+
+    /// Whether the given advertising channel (37, 38 or 39) is enabled.
+    pub const fn contains(self, channel: u8) -> bool {
+        match channel {
+            37..=39 => self.0 & (1 << (channel - 37)) != 0,
+            _ => false,
+        }
+    }
+
+    /// Remove a channel from the set, returning `None` if it was the last one.
+    pub const fn without(self, other: Self) -> Option<Self> {
+        let bits = self.0 & !other.0;
+        if bits == 0 {
+            None
+        } else {
+            Some(Self(bits))
+        }
+    }
+
+    fn to_raw(self) -> raw::ble_gap_ch_mask_t {
+        // The softdevice mask has a bit set for every *excluded* channel, with 37..39 in the top bits.
+        let mut mask: raw::ble_gap_ch_mask_t = [0; 5];
+        mask[4] = (!self.0 & 0b111) << 5;
+        mask
+    }
+}
+
+impl Default for AdvChannels {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+impl core::ops::BitOr for AdvChannels {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
 #[derive(Copy, Clone)]
 pub struct Config {
     pub primary_phy: Phy,
@@ -413,6 +954,12 @@ pub struct Config {
     pub interval: u32,
 
     pub filter_policy: FilterPolicy,
+
+    /// Primary advertising channels to advertise on.
+    pub channel_mask: AdvChannels,
+
+    /// Report scan requests from scanners, see [`scan_requests`].
+    pub scan_request_notification: bool,
 }
 
 impl Default for Config {
@@ -425,6 +972,8 @@ impl Default for Config {
             max_events: None,
             interval: 400, // 250ms
             filter_policy: FilterPolicy::default(),
+            channel_mask: AdvChannels::ALL,
+            scan_request_notification: false,
         }
     }
 }
@@ -0,0 +1,115 @@
+//! LE Privacy
+//!
+//! With privacy enabled, the SoftDevice advertises, scans and initiates connections using private addresses
+//! that it rotates periodically, so that peers without the local IRK can't track the device.
+
+use core::ptr;
+
+use crate::ble::IdentityResolutionKey;
+use crate::{raw, RawError, Softdevice};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PrivacyMode {
+    /// Use the identity address.
+    Off,
+    /// Use private addresses, but accept identity addresses from peers in the device identities list.
+    Device,
+    /// Use private addresses, and require private addresses from peers in the device identities list.
+    Network,
+    /// A mode reported by the SoftDevice that this crate doesn't know.
+    Unknown(u8),
+}
+
+impl PrivacyMode {
+    fn from_raw(raw: u8) -> Self {
+        match raw as u32 {
+            raw::BLE_GAP_PRIVACY_MODE_OFF => Self::Off,
+            raw::BLE_GAP_PRIVACY_MODE_DEVICE_PRIVACY => Self::Device,
+            raw::BLE_GAP_PRIVACY_MODE_NETWORK_PRIVACY => Self::Network,
+            _ => Self::Unknown(raw),
+        }
+    }
+
+    fn into_raw(self) -> u8 {
+        match self {
+            Self::Off => raw::BLE_GAP_PRIVACY_MODE_OFF as u8,
+            Self::Device => raw::BLE_GAP_PRIVACY_MODE_DEVICE_PRIVACY as u8,
+            Self::Network => raw::BLE_GAP_PRIVACY_MODE_NETWORK_PRIVACY as u8,
+            Self::Unknown(raw) => raw,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PrivateAddressType {
+    /// Resolvable by peers that know the local IRK.
+    Resolvable,
+    NonResolvable,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Config {
+    pub mode: PrivacyMode,
+    pub address_type: PrivateAddressType,
+    /// Private address rotation interval in seconds. `0` uses the SoftDevice default of 15 minutes.
+    pub address_cycle_s: u16,
+    /// IRK used to generate resolvable private addresses. `None` uses the SoftDevice's device IRK.
+    ///
+    /// When read back with [`get_privacy`], this is the IRK currently in use.
+    pub irk: Option<IdentityResolutionKey>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            mode: PrivacyMode::Off,
+            address_type: PrivateAddressType::Resolvable,
+            address_cycle_s: raw::BLE_GAP_DEFAULT_PRIVATE_ADDR_CYCLE_INTERVAL_S as u16,
+            irk: None,
+        }
+    }
+}
+
+/// Configure privacy.
+///
+/// This can't be changed while advertising, scanning or initiating a connection.
+pub fn set_privacy(sd: &Softdevice, config: &Config) -> Result<(), RawError> {
+    let _ = sd;
+    let mut irk = config.irk.map(|irk| *irk.as_raw());
+    let params = raw::ble_gap_privacy_params_t {
+        privacy_mode: config.mode.into_raw(),
+        private_addr_type: match config.address_type {
+            PrivateAddressType::Resolvable => raw::BLE_GAP_ADDR_TYPE_RANDOM_PRIVATE_RESOLVABLE as u8,
+            PrivateAddressType::NonResolvable => raw::BLE_GAP_ADDR_TYPE_RANDOM_PRIVATE_NON_RESOLVABLE as u8,
+        },
+        private_addr_cycle_s: config.address_cycle_s,
+        p_device_irk: irk.as_mut().map(|x| x as *mut _).unwrap_or(ptr::null_mut()),
+    };
+
+    let ret = unsafe { raw::sd_ble_gap_privacy_set(&params) };
+    RawError::convert(ret)
+}
+
+/// Read the current privacy configuration, including the IRK in use.
+pub fn get_privacy(sd: &Softdevice) -> Result<Config, RawError> {
+    let _ = sd;
+    let mut irk: raw::ble_gap_irk_t = unsafe { core::mem::zeroed() };
+    let mut params: raw::ble_gap_privacy_params_t = unsafe { core::mem::zeroed() };
+    params.p_device_irk = &mut irk;
+
+    let ret = unsafe { raw::sd_ble_gap_privacy_get(&mut params) };
+    RawError::convert(ret)?;
+
+    Ok(Config {
+        mode: PrivacyMode::from_raw(params.privacy_mode),
+        address_type: match params.private_addr_type as u32 {
+            raw::BLE_GAP_ADDR_TYPE_RANDOM_PRIVATE_NON_RESOLVABLE => PrivateAddressType::NonResolvable,
+            _ => PrivateAddressType::Resolvable,
+        },
+        address_cycle_s: params.private_addr_cycle_s,
+        irk: Some(IdentityResolutionKey::from_raw(irk)),
+    })
+}
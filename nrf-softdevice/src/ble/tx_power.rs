@@ -0,0 +1,157 @@
+//! Automatic transmit power control for connections.
+//!
+//! [`run`] adjusts the transmit power of a connection one step at a time based on its smoothed RSSI, lowering
+//! it on close links to save power and raising it on weak links to avoid losing the connection.
+
+use crate::ble::{Connection, ConnectionEvent, DisconnectedError, RssiConfig, RssiError, SetTxPowerError, TxPower};
+use crate::RawError;
+
+/// Supported transmit power levels, in ascending order.
+const LEVELS: &[TxPower] = &[
+    TxPower::Minus40dBm,
+    TxPower::Minus20dBm,
+    TxPower::Minus16dBm,
+    TxPower::Minus12dBm,
+    TxPower::Minus8dBm,
+    TxPower::Minus4dBm,
+    TxPower::ZerodBm,
+    #[cfg(feature = "s140")]
+    TxPower::Plus2dBm,
+    TxPower::Plus3dBm,
+    TxPower::Plus4dBm,
+    #[cfg(feature = "s140")]
+    TxPower::Plus5dBm,
+    #[cfg(feature = "s140")]
+    TxPower::Plus6dBm,
+    #[cfg(feature = "s140")]
+    TxPower::Plus7dBm,
+    #[cfg(feature = "s140")]
+    TxPower::Plus8dBm,
+];
+
+/// Transmit power control configuration.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Config {
+    /// Transmit power set when starting.
+    pub initial: TxPower,
+    /// Lowest transmit power to use.
+    pub min: TxPower,
+    /// Highest transmit power to use. This must not be lower than `min`.
+    pub max: TxPower,
+    /// Raise the transmit power when the RSSI drops below this value, in dBm.
+    pub low_rssi: i8,
+    /// Lower the transmit power when the RSSI rises above this value, in dBm.
+    ///
+    /// This must be higher than `low_rssi`, the gap between both acts as hysteresis.
+    pub high_rssi: i8,
+    /// Number of RSSI samples to ignore after changing the transmit power, so the smoothed RSSI can settle.
+    pub settle_samples: u8,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            initial: TxPower::ZerodBm,
+            min: TxPower::Minus20dBm,
+            max: TxPower::ZerodBm,
+            low_rssi: -80,
+            high_rssi: -55,
+            settle_samples: 8,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum RunError {
+    /// `low_rssi` is not lower than `high_rssi`, or `min` is higher than `max`.
+    InvalidConfig,
+    Disconnected,
+    Raw(RawError),
+}
+
+impl From<DisconnectedError> for RunError {
+    fn from(_err: DisconnectedError) -> Self {
+        Self::Disconnected
+    }
+}
+
+impl From<RssiError> for RunError {
+    fn from(err: RssiError) -> Self {
+        match err {
+            RssiError::Disconnected => Self::Disconnected,
+            RssiError::Raw(err) => Self::Raw(err),
+        }
+    }
+}
+
+impl From<SetTxPowerError> for RunError {
+    fn from(err: SetTxPowerError) -> Self {
+        match err {
+            SetTxPowerError::Disconnected => Self::Disconnected,
+            SetTxPowerError::Raw(err) => Self::Raw(err),
+        }
+    }
+}
+
+fn level_index(tx_power: TxPower) -> usize {
+    unwrap!(LEVELS.iter().position(|&level| level == tx_power))
+}
+
+/// Control the transmit power of `conn` until it is disconnected or setting the power fails.
+///
+/// The RSSI seen by the local device is used as an estimate of the link quality in both directions.
+/// This starts RSSI measurement with the default [`RssiConfig`] and receives RSSI changes through
+/// [`Connection::events`], so other tasks can listen for events on this connection meanwhile. If RSSI
+/// measurement is already running, its configuration is left as it is.
+///
+/// Returns [`RunError::InvalidConfig`] right away if `config` is invalid.
+pub async fn run(conn: &Connection, config: &Config) -> RunError {
+    let min = level_index(config.min);
+    let max = level_index(config.max);
+    if config.low_rssi >= config.high_rssi || min > max {
+        warn!("tx_power: invalid config");
+        return RunError::InvalidConfig;
+    }
+    let mut level = level_index(config.initial).clamp(min, max);
+
+    let mut events = conn.events();
+    if let Err(err) = conn.set_tx_power(LEVELS[level]) {
+        return err.into();
+    }
+    match conn.start_rssi_with_config(&RssiConfig::default()) {
+        Ok(()) | Err(RssiError::Raw(RawError::InvalidState)) => {}
+        Err(err) => return err.into(),
+    }
+
+    let mut skip = config.settle_samples;
+    loop {
+        match events.next().await {
+            Ok(ConnectionEvent::RssiChanged { .. }) => {}
+            Ok(_) => continue,
+            Err(err) => return err.into(),
+        }
+
+        if skip > 0 {
+            skip -= 1;
+            continue;
+        }
+
+        let Some(rssi) = conn.rssi() else { continue };
+        let next = if rssi < config.low_rssi && level < max {
+            level + 1
+        } else if rssi > config.high_rssi && level > min {
+            level - 1
+        } else {
+            continue;
+        };
+
+        debug!("tx_power: rssi {:?}, setting tx power {:?}", rssi, LEVELS[next]);
+        if let Err(err) = conn.set_tx_power(LEVELS[next]) {
+            return err.into();
+        }
+        level = next;
+        skip = config.settle_samples;
+    }
+}
@@ -1,5 +1,9 @@
-use core::mem;
 use core::num::NonZeroU16;
+use core::str::FromStr;
+use core::{fmt, mem};
+
+#[cfg(feature = "rand-core")]
+use rand_core::RngCore;
 
 use crate::{raw, RawError};
 
@@ -32,19 +36,23 @@ impl Uuid {
     // Note that `uuid` needs to be in little-endian format, i.e. opposite to what you would
     // normally write UUIDs.
     pub fn new_128(uuid: &[u8; 16]) -> Self {
-        let mut uuid_type: u8 = 0;
-        let ret = unsafe { raw::sd_ble_uuid_vs_add(uuid.as_ptr() as _, &mut uuid_type as _) };
-        match RawError::convert(ret) {
-            Ok(()) => {}
+        match Self::try_new_128(uuid) {
+            Ok(uuid) => uuid,
             Err(e) => panic!("sd_ble_uuid_vs_add err {:?}", e),
         }
+    }
 
-        Self {
+    fn try_new_128(uuid: &[u8; 16]) -> Result<Self, RawError> {
+        let mut uuid_type: u8 = 0;
+        let ret = unsafe { raw::sd_ble_uuid_vs_add(uuid.as_ptr() as _, &mut uuid_type as _) };
+        RawError::convert(ret)?;
+
+        Ok(Self {
             inner: raw::ble_uuid_t {
                 type_: uuid_type,
                 uuid: ((uuid[13] as u16) << 8) | (uuid[12] as u16),
             },
-        }
+        })
     }
 
     pub fn as_raw_ptr(&self) -> *const raw::ble_uuid_t {
@@ -54,6 +62,123 @@ impl Uuid {
     pub fn into_raw(self) -> raw::ble_uuid_t {
         self.inner
     }
+
+    /// Get the full 128-bit UUID in little-endian format.
+    ///
+    /// For vendor-specific UUIDs, the base UUID is looked up in the SoftDevice.
+    pub fn to_bytes(&self) -> Option<[u8; 16]> {
+        let mut bytes = [0; 16];
+        let mut len: u8 = 0;
+        let ret = unsafe { raw::sd_ble_uuid_encode(&self.inner, &mut len, bytes.as_mut_ptr()) };
+        match RawError::convert(ret) {
+            Ok(()) if len == 2 => {
+                let mut full = BLUETOOTH_BASE_UUID;
+                full[12..14].copy_from_slice(&bytes[..2]);
+                Some(full)
+            }
+            Ok(()) if len == 16 => Some(bytes),
+            _ => None,
+        }
+    }
+
+    fn is_16(&self) -> bool {
+        self.inner.type_ == raw::BLE_UUID_TYPE_BLE as u8
+    }
+}
+
+/// Bluetooth Base UUID `00000000-0000-1000-8000-00805f9b34fb` in little-endian format.
+const BLUETOOTH_BASE_UUID: [u8; 16] = [
+    0xfb, 0x34, 0x9b, 0x5f, 0x80, 0x00, 0x00, 0x80, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+];
+
+/// Formats 16-bit UUIDs in their short form, e.g. `180d`, and 128-bit UUIDs in the usual
+/// `6e400001-b5a3-f393-e0a9-e50e24dcca9e` form.
+impl fmt::Display for Uuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_16() {
+            return write!(f, "{:04x}", self.inner.uuid);
+        }
+        match self.to_bytes() {
+            Some(b) => write!(
+                f,
+                "{:02x}{:02x}{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}",
+                b[15], b[14], b[13], b[12], b[11], b[10], b[9], b[8], b[7], b[6], b[5], b[4], b[3], b[2], b[1], b[0]
+            ),
+            None => write!(f, "unknown-{}:{:04x}", self.inner.type_, self.inner.uuid),
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Uuid {
+    fn format(&self, fmt: defmt::Formatter) {
+        if self.is_16() {
+            return defmt::write!(fmt, "{=u16:04x}", self.inner.uuid);
+        }
+        match self.to_bytes() {
+            Some(b) => defmt::write!(
+                fmt,
+                "{=u8:02x}{=u8:02x}{=u8:02x}{=u8:02x}-{=u8:02x}{=u8:02x}-{=u8:02x}{=u8:02x}-{=u8:02x}{=u8:02x}-{=u8:02x}{=u8:02x}{=u8:02x}{=u8:02x}{=u8:02x}{=u8:02x}",
+                b[15], b[14], b[13], b[12], b[11], b[10], b[9], b[8], b[7], b[6], b[5], b[4], b[3], b[2], b[1], b[0]
+            ),
+            None => defmt::write!(fmt, "unknown-{=u8}:{=u16:04x}", self.inner.type_, self.inner.uuid),
+        }
+    }
+}
+
+/// Error for [`Uuid::from_str`]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ParseUuidError {
+    Invalid,
+    /// Registering the vendor-specific base UUID with the SoftDevice failed.
+    Raw(RawError),
+}
+
+impl From<RawError> for ParseUuidError {
+    fn from(err: RawError) -> Self {
+        Self::Raw(err)
+    }
+}
+
+/// Parses 16-bit UUIDs (`180d` or `0x180d`) and 128-bit UUIDs.
+///
+/// 128-bit UUIDs derived from the Bluetooth Base UUID are turned into 16-bit UUIDs. Other 128-bit UUIDs
+/// are registered with the SoftDevice like in [`Uuid::new_128`].
+impl FromStr for Uuid {
+    type Err = ParseUuidError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let short = s.strip_prefix("0x").unwrap_or(s);
+        if short.len() == 4 {
+            return parse_hex_u16(short).map(Self::new_16).ok_or(ParseUuidError::Invalid);
+        }
+
+        let s = s.as_bytes();
+        if s.len() != 36 || [8, 13, 18, 23].iter().any(|&i| s[i] != b'-') {
+            return Err(ParseUuidError::Invalid);
+        }
+
+        let mut bytes = [0; 16];
+        let mut digits = s.iter().filter(|&&c| c != b'-');
+        for b in bytes.iter_mut().rev() {
+            let hi = digits.next().and_then(|&c| (c as char).to_digit(16));
+            let lo = digits.next().and_then(|&c| (c as char).to_digit(16));
+            match (hi, lo) {
+                (Some(hi), Some(lo)) => *b = (hi << 4 | lo) as u8,
+                _ => return Err(ParseUuidError::Invalid),
+            }
+        }
+        if digits.next().is_some() {
+            return Err(ParseUuidError::Invalid);
+        }
+
+        if bytes[..12] == BLUETOOTH_BASE_UUID[..12] && bytes[14..] == [0, 0] {
+            return Ok(Self::new_16(u16::from_le_bytes([bytes[12], bytes[13]])));
+        }
+
+        Ok(Self::try_new_128(&bytes)?)
+    }
 }
 
 impl Eq for Uuid {}
@@ -133,6 +258,197 @@ impl SecurityMode {
     }
 }
 
+/// GAP Appearance, as exposed in the GAP service and advertising data.
+///
+/// Appearances are compared by their raw value, so [`Appearance::Other`] holding the value of a named
+/// variant is equal to that variant.
+#[derive(Debug, Default, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Appearance {
+    #[default]
+    Unknown,
+    GenericPhone,
+    GenericComputer,
+    GenericWatch,
+    WatchSportsWatch,
+    GenericClock,
+    GenericDisplay,
+    GenericRemoteControl,
+    GenericEyeGlasses,
+    GenericTag,
+    GenericKeyring,
+    GenericMediaPlayer,
+    GenericBarcodeScanner,
+    GenericThermometer,
+    ThermometerEar,
+    GenericHeartRateSensor,
+    HeartRateSensorHeartRateBelt,
+    GenericBloodPressure,
+    BloodPressureArm,
+    BloodPressureWrist,
+    GenericHid,
+    HidKeyboard,
+    HidMouse,
+    HidJoystick,
+    HidGamepad,
+    HidDigitizerTablet,
+    HidCardReader,
+    HidDigitalPen,
+    HidBarcode,
+    GenericGlucoseMeter,
+    GenericRunningWalkingSensor,
+    RunningWalkingSensorInShoe,
+    RunningWalkingSensorOnShoe,
+    RunningWalkingSensorOnHip,
+    GenericCycling,
+    CyclingCyclingComputer,
+    CyclingSpeedSensor,
+    CyclingCadenceSensor,
+    CyclingPowerSensor,
+    CyclingSpeedCadenceSensor,
+    GenericPulseOximeter,
+    PulseOximeterFingertip,
+    PulseOximeterWristWorn,
+    GenericWeightScale,
+    GenericOutdoorSportsActivity,
+    OutdoorSportsActivityLocationDisplay,
+    OutdoorSportsActivityLocationAndNavigationDisplay,
+    OutdoorSportsActivityLocationPod,
+    OutdoorSportsActivityLocationAndNavigationPod,
+    /// A value not covered by the variants above. [`Appearance::from_raw`] never returns this for the
+    /// value of a named variant.
+    Other(u16),
+}
+
+impl PartialEq for Appearance {
+    fn eq(&self, other: &Self) -> bool {
+        self.into_raw() == other.into_raw()
+    }
+}
+
+impl Eq for Appearance {}
+
+impl Appearance {
+    pub const fn from_raw(raw: u16) -> Self {
+        match raw as u32 {
+            raw::BLE_APPEARANCE_UNKNOWN => Self::Unknown,
+            raw::BLE_APPEARANCE_GENERIC_PHONE => Self::GenericPhone,
+            raw::BLE_APPEARANCE_GENERIC_COMPUTER => Self::GenericComputer,
+            raw::BLE_APPEARANCE_GENERIC_WATCH => Self::GenericWatch,
+            raw::BLE_APPEARANCE_WATCH_SPORTS_WATCH => Self::WatchSportsWatch,
+            raw::BLE_APPEARANCE_GENERIC_CLOCK => Self::GenericClock,
+            raw::BLE_APPEARANCE_GENERIC_DISPLAY => Self::GenericDisplay,
+            raw::BLE_APPEARANCE_GENERIC_REMOTE_CONTROL => Self::GenericRemoteControl,
+            raw::BLE_APPEARANCE_GENERIC_EYE_GLASSES => Self::GenericEyeGlasses,
+            raw::BLE_APPEARANCE_GENERIC_TAG => Self::GenericTag,
+            raw::BLE_APPEARANCE_GENERIC_KEYRING => Self::GenericKeyring,
+            raw::BLE_APPEARANCE_GENERIC_MEDIA_PLAYER => Self::GenericMediaPlayer,
+            raw::BLE_APPEARANCE_GENERIC_BARCODE_SCANNER => Self::GenericBarcodeScanner,
+            raw::BLE_APPEARANCE_GENERIC_THERMOMETER => Self::GenericThermometer,
+            raw::BLE_APPEARANCE_THERMOMETER_EAR => Self::ThermometerEar,
+            raw::BLE_APPEARANCE_GENERIC_HEART_RATE_SENSOR => Self::GenericHeartRateSensor,
+            raw::BLE_APPEARANCE_HEART_RATE_SENSOR_HEART_RATE_BELT => Self::HeartRateSensorHeartRateBelt,
+            raw::BLE_APPEARANCE_GENERIC_BLOOD_PRESSURE => Self::GenericBloodPressure,
+            raw::BLE_APPEARANCE_BLOOD_PRESSURE_ARM => Self::BloodPressureArm,
+            raw::BLE_APPEARANCE_BLOOD_PRESSURE_WRIST => Self::BloodPressureWrist,
+            raw::BLE_APPEARANCE_GENERIC_HID => Self::GenericHid,
+            raw::BLE_APPEARANCE_HID_KEYBOARD => Self::HidKeyboard,
+            raw::BLE_APPEARANCE_HID_MOUSE => Self::HidMouse,
+            raw::BLE_APPEARANCE_HID_JOYSTICK => Self::HidJoystick,
+            raw::BLE_APPEARANCE_HID_GAMEPAD => Self::HidGamepad,
+            raw::BLE_APPEARANCE_HID_DIGITIZERSUBTYPE => Self::HidDigitizerTablet,
+            raw::BLE_APPEARANCE_HID_CARD_READER => Self::HidCardReader,
+            raw::BLE_APPEARANCE_HID_DIGITAL_PEN => Self::HidDigitalPen,
+            raw::BLE_APPEARANCE_HID_BARCODE => Self::HidBarcode,
+            raw::BLE_APPEARANCE_GENERIC_GLUCOSE_METER => Self::GenericGlucoseMeter,
+            raw::BLE_APPEARANCE_GENERIC_RUNNING_WALKING_SENSOR => Self::GenericRunningWalkingSensor,
+            raw::BLE_APPEARANCE_RUNNING_WALKING_SENSOR_IN_SHOE => Self::RunningWalkingSensorInShoe,
+            raw::BLE_APPEARANCE_RUNNING_WALKING_SENSOR_ON_SHOE => Self::RunningWalkingSensorOnShoe,
+            raw::BLE_APPEARANCE_RUNNING_WALKING_SENSOR_ON_HIP => Self::RunningWalkingSensorOnHip,
+            raw::BLE_APPEARANCE_GENERIC_CYCLING => Self::GenericCycling,
+            raw::BLE_APPEARANCE_CYCLING_CYCLING_COMPUTER => Self::CyclingCyclingComputer,
+            raw::BLE_APPEARANCE_CYCLING_SPEED_SENSOR => Self::CyclingSpeedSensor,
+            raw::BLE_APPEARANCE_CYCLING_CADENCE_SENSOR => Self::CyclingCadenceSensor,
+            raw::BLE_APPEARANCE_CYCLING_POWER_SENSOR => Self::CyclingPowerSensor,
+            raw::BLE_APPEARANCE_CYCLING_SPEED_CADENCE_SENSOR => Self::CyclingSpeedCadenceSensor,
+            raw::BLE_APPEARANCE_GENERIC_PULSE_OXIMETER => Self::GenericPulseOximeter,
+            raw::BLE_APPEARANCE_PULSE_OXIMETER_FINGERTIP => Self::PulseOximeterFingertip,
+            raw::BLE_APPEARANCE_PULSE_OXIMETER_WRIST_WORN => Self::PulseOximeterWristWorn,
+            raw::BLE_APPEARANCE_GENERIC_WEIGHT_SCALE => Self::GenericWeightScale,
+            raw::BLE_APPEARANCE_GENERIC_OUTDOOR_SPORTS_ACT => Self::GenericOutdoorSportsActivity,
+            raw::BLE_APPEARANCE_OUTDOOR_SPORTS_ACT_LOC_DISP => Self::OutdoorSportsActivityLocationDisplay,
+            raw::BLE_APPEARANCE_OUTDOOR_SPORTS_ACT_LOC_AND_NAV_DISP => {
+                Self::OutdoorSportsActivityLocationAndNavigationDisplay
+            }
+            raw::BLE_APPEARANCE_OUTDOOR_SPORTS_ACT_LOC_POD => Self::OutdoorSportsActivityLocationPod,
+            raw::BLE_APPEARANCE_OUTDOOR_SPORTS_ACT_LOC_AND_NAV_POD => {
+                Self::OutdoorSportsActivityLocationAndNavigationPod
+            }
+            _ => Self::Other(raw),
+        }
+    }
+
+    pub const fn into_raw(self) -> u16 {
+        let raw = match self {
+            Self::Unknown => raw::BLE_APPEARANCE_UNKNOWN,
+            Self::GenericPhone => raw::BLE_APPEARANCE_GENERIC_PHONE,
+            Self::GenericComputer => raw::BLE_APPEARANCE_GENERIC_COMPUTER,
+            Self::GenericWatch => raw::BLE_APPEARANCE_GENERIC_WATCH,
+            Self::WatchSportsWatch => raw::BLE_APPEARANCE_WATCH_SPORTS_WATCH,
+            Self::GenericClock => raw::BLE_APPEARANCE_GENERIC_CLOCK,
+            Self::GenericDisplay => raw::BLE_APPEARANCE_GENERIC_DISPLAY,
+            Self::GenericRemoteControl => raw::BLE_APPEARANCE_GENERIC_REMOTE_CONTROL,
+            Self::GenericEyeGlasses => raw::BLE_APPEARANCE_GENERIC_EYE_GLASSES,
+            Self::GenericTag => raw::BLE_APPEARANCE_GENERIC_TAG,
+            Self::GenericKeyring => raw::BLE_APPEARANCE_GENERIC_KEYRING,
+            Self::GenericMediaPlayer => raw::BLE_APPEARANCE_GENERIC_MEDIA_PLAYER,
+            Self::GenericBarcodeScanner => raw::BLE_APPEARANCE_GENERIC_BARCODE_SCANNER,
+            Self::GenericThermometer => raw::BLE_APPEARANCE_GENERIC_THERMOMETER,
+            Self::ThermometerEar => raw::BLE_APPEARANCE_THERMOMETER_EAR,
+            Self::GenericHeartRateSensor => raw::BLE_APPEARANCE_GENERIC_HEART_RATE_SENSOR,
+            Self::HeartRateSensorHeartRateBelt => raw::BLE_APPEARANCE_HEART_RATE_SENSOR_HEART_RATE_BELT,
+            Self::GenericBloodPressure => raw::BLE_APPEARANCE_GENERIC_BLOOD_PRESSURE,
+            Self::BloodPressureArm => raw::BLE_APPEARANCE_BLOOD_PRESSURE_ARM,
+            Self::BloodPressureWrist => raw::BLE_APPEARANCE_BLOOD_PRESSURE_WRIST,
+            Self::GenericHid => raw::BLE_APPEARANCE_GENERIC_HID,
+            Self::HidKeyboard => raw::BLE_APPEARANCE_HID_KEYBOARD,
+            Self::HidMouse => raw::BLE_APPEARANCE_HID_MOUSE,
+            Self::HidJoystick => raw::BLE_APPEARANCE_HID_JOYSTICK,
+            Self::HidGamepad => raw::BLE_APPEARANCE_HID_GAMEPAD,
+            Self::HidDigitizerTablet => raw::BLE_APPEARANCE_HID_DIGITIZERSUBTYPE,
+            Self::HidCardReader => raw::BLE_APPEARANCE_HID_CARD_READER,
+            Self::HidDigitalPen => raw::BLE_APPEARANCE_HID_DIGITAL_PEN,
+            Self::HidBarcode => raw::BLE_APPEARANCE_HID_BARCODE,
+            Self::GenericGlucoseMeter => raw::BLE_APPEARANCE_GENERIC_GLUCOSE_METER,
+            Self::GenericRunningWalkingSensor => raw::BLE_APPEARANCE_GENERIC_RUNNING_WALKING_SENSOR,
+            Self::RunningWalkingSensorInShoe => raw::BLE_APPEARANCE_RUNNING_WALKING_SENSOR_IN_SHOE,
+            Self::RunningWalkingSensorOnShoe => raw::BLE_APPEARANCE_RUNNING_WALKING_SENSOR_ON_SHOE,
+            Self::RunningWalkingSensorOnHip => raw::BLE_APPEARANCE_RUNNING_WALKING_SENSOR_ON_HIP,
+            Self::GenericCycling => raw::BLE_APPEARANCE_GENERIC_CYCLING,
+            Self::CyclingCyclingComputer => raw::BLE_APPEARANCE_CYCLING_CYCLING_COMPUTER,
+            Self::CyclingSpeedSensor => raw::BLE_APPEARANCE_CYCLING_SPEED_SENSOR,
+            Self::CyclingCadenceSensor => raw::BLE_APPEARANCE_CYCLING_CADENCE_SENSOR,
+            Self::CyclingPowerSensor => raw::BLE_APPEARANCE_CYCLING_POWER_SENSOR,
+            Self::CyclingSpeedCadenceSensor => raw::BLE_APPEARANCE_CYCLING_SPEED_CADENCE_SENSOR,
+            Self::GenericPulseOximeter => raw::BLE_APPEARANCE_GENERIC_PULSE_OXIMETER,
+            Self::PulseOximeterFingertip => raw::BLE_APPEARANCE_PULSE_OXIMETER_FINGERTIP,
+            Self::PulseOximeterWristWorn => raw::BLE_APPEARANCE_PULSE_OXIMETER_WRIST_WORN,
+            Self::GenericWeightScale => raw::BLE_APPEARANCE_GENERIC_WEIGHT_SCALE,
+            Self::GenericOutdoorSportsActivity => raw::BLE_APPEARANCE_GENERIC_OUTDOOR_SPORTS_ACT,
+            Self::OutdoorSportsActivityLocationDisplay => raw::BLE_APPEARANCE_OUTDOOR_SPORTS_ACT_LOC_DISP,
+            Self::OutdoorSportsActivityLocationAndNavigationDisplay => {
+                raw::BLE_APPEARANCE_OUTDOOR_SPORTS_ACT_LOC_AND_NAV_DISP
+            }
+            Self::OutdoorSportsActivityLocationPod => raw::BLE_APPEARANCE_OUTDOOR_SPORTS_ACT_LOC_POD,
+            Self::OutdoorSportsActivityLocationAndNavigationPod => {
+                raw::BLE_APPEARANCE_OUTDOOR_SPORTS_ACT_LOC_AND_NAV_POD
+            }
+            Self::Other(raw) => return raw,
+        };
+        raw as u16
+    }
+}
+
 #[repr(u8)]
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
@@ -222,21 +538,170 @@ impl Address {
         // Safety: `Self` has the same layout as `raw::ble_gap_addr_t` and all bit patterns are valid
         unsafe { mem::transmute(raw) }
     }
+
+    /// Generate a resolvable private address from `irk`.
+    ///
+    /// The hash is computed with the SoftDevice's AES-ECB, so the SoftDevice must be enabled.
+    #[cfg(feature = "rand-core")]
+    pub fn new_resolvable(irk: IdentityResolutionKey, rng: &mut impl RngCore) -> Self {
+        Self::new_resolvable_with(irk, rng, sd_aes128)
+    }
+
+    #[cfg(feature = "rand-core")]
+    fn new_resolvable_with(
+        irk: IdentityResolutionKey,
+        rng: &mut impl RngCore,
+        aes128: impl FnOnce(&[u8; 16], &[u8; 16]) -> [u8; 16],
+    ) -> Self {
+        let mut bytes = [0; 6];
+        loop {
+            rng.fill_bytes(&mut bytes[3..]);
+            bytes[5] = (bytes[5] & 0x3f) | 0x40;
+            // The random part must contain at least one 0 and one 1 bit.
+            if bytes[3..] != [0x00, 0x00, 0x40] && bytes[3..] != [0xff, 0xff, 0x7f] {
+                break;
+            }
+        }
+        let hash = ah(aes128, &irk, unwrap!(bytes[3..].try_into()));
+        bytes[..3].copy_from_slice(&hash);
+        Self::new(AddressType::RandomPrivateResolvable, bytes)
+    }
+
+    /// Generate a static random address.
+    #[cfg(feature = "rand-core")]
+    pub fn new_static_random(rng: &mut impl RngCore) -> Self {
+        let mut bytes = [0; 6];
+        loop {
+            rng.fill_bytes(&mut bytes);
+            bytes[5] |= 0xc0;
+            // The random part must contain at least one 0 and one 1 bit.
+            if bytes != [0x00, 0x00, 0x00, 0x00, 0x00, 0xc0] && bytes != [0xff; 6] {
+                break;
+            }
+        }
+        Self::new(AddressType::RandomStatic, bytes)
+    }
+}
+
+impl AddressType {
+    const fn suffix(self) -> &'static str {
+        match self {
+            Self::Public => "public",
+            Self::RandomStatic => "static",
+            Self::RandomPrivateResolvable => "rpa",
+            Self::RandomPrivateNonResolvable => "nrpa",
+            Self::Anonymous => "anonymous",
+        }
+    }
+
+    fn from_suffix(suffix: &str) -> Option<Self> {
+        [
+            Self::Public,
+            Self::RandomStatic,
+            Self::RandomPrivateResolvable,
+            Self::RandomPrivateNonResolvable,
+            Self::Anonymous,
+        ]
+        .into_iter()
+        .find(|t| t.suffix() == suffix)
+    }
+}
+
+/// Formats the address most significant byte first, followed by the address type,
+/// e.g. `C0:11:22:33:44:55/static`.
+///
+/// Peer identity addresses resolved by the SoftDevice are marked with `(resolved)`.
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let b = self.bytes;
+        write!(
+            f,
+            "{:02X}:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}/{}",
+            b[5],
+            b[4],
+            b[3],
+            b[2],
+            b[1],
+            b[0],
+            self.address_type().suffix()
+        )?;
+        if self.is_resolved_peer_id() {
+            f.write_str("(resolved)")?;
+        }
+        Ok(())
+    }
 }
 
 #[cfg(feature = "defmt")]
 impl defmt::Format for Address {
     fn format(&self, fmt: defmt::Formatter) {
+        let b = self.bytes;
+        defmt::write!(
+            fmt,
+            "{=u8:02X}:{=u8:02X}:{=u8:02X}:{=u8:02X}:{=u8:02X}:{=u8:02X}/{=str}",
+            b[5],
+            b[4],
+            b[3],
+            b[2],
+            b[1],
+            b[0],
+            self.address_type().suffix()
+        );
         if self.is_resolved_peer_id() {
-            defmt::write!(fmt, "{:?}(resolved):{=[u8]:x}", self.address_type(), self.bytes())
-        } else {
-            defmt::write!(fmt, "{:?}:{=[u8]:x}", self.address_type(), self.bytes())
+            defmt::write!(fmt, "(resolved)");
+        }
+    }
+}
+
+/// Parses the format produced by [`Display`](fmt::Display). The address type suffix is optional and
+/// defaults to public.
+impl FromStr for Address {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (s, resolved) = match s.strip_suffix("(resolved)") {
+            Some(s) => (s, true),
+            None => (s, false),
+        };
+        let (s, address_type) = match s.split_once('/') {
+            Some((s, suffix)) => (s, AddressType::from_suffix(suffix).ok_or(ParseError)?),
+            None => (s, AddressType::Public),
+        };
+
+        let mut bytes = [0; 6];
+        let mut parts = s.split(':');
+        for b in bytes.iter_mut().rev() {
+            let part = parts.next().ok_or(ParseError)?;
+            if part.len() != 2 {
+                return Err(ParseError);
+            }
+            *b = u8::from_str_radix(part, 16).map_err(|_| ParseError)?;
         }
+        if parts.next().is_some() {
+            return Err(ParseError);
+        }
+
+        let mut addr = Self::new(address_type, bytes);
+        addr.flags |= u8::from(resolved);
+        Ok(addr)
     }
 }
 
+/// Error for the [`FromStr`] implementations of [`Address`], [`HciStatus`], [`GattStatus`] and [`GattError`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-#[derive(Eq, PartialEq, Copy, Clone)]
+pub struct ParseError;
+
+fn parse_hex_u16(s: &str) -> Option<u16> {
+    let s = s.strip_prefix("0x").unwrap_or(s);
+    if s.is_empty() || s.len() > 4 || s.starts_with('+') {
+        return None;
+    }
+    u16::from_str_radix(s, 16).ok()
+}
+
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
 #[repr(i8)]
 pub enum TxPower {
     Minus40dBm = -40,
@@ -261,7 +726,7 @@ pub enum TxPower {
 }
 
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
-#[derive(Eq, PartialEq, Copy, Clone)]
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
 #[repr(u8)]
 pub enum Phy {
     /// 1Mbps phy
@@ -273,6 +738,18 @@ pub enum Phy {
     Coded = 4,
 }
 
+impl Phy {
+    pub fn try_from_raw(raw: u8) -> Option<Self> {
+        match raw as u32 {
+            raw::BLE_GAP_PHY_1MBPS => Some(Self::M1),
+            raw::BLE_GAP_PHY_2MBPS => Some(Self::M2),
+            #[cfg(feature = "s140")]
+            raw::BLE_GAP_PHY_CODED => Some(Self::Coded),
+            _ => None,
+        }
+    }
+}
+
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[derive(Eq, PartialEq, Copy, Clone)]
 #[repr(u8)]
@@ -352,6 +829,10 @@ pub struct IdentityResolutionKey {
 }
 
 impl IdentityResolutionKey {
+    pub const fn new(irk: [u8; 16]) -> Self {
+        Self { irk }
+    }
+
     pub fn from_raw(raw: raw::ble_gap_irk_t) -> Self {
         Self { irk: raw.irk }
     }
@@ -375,10 +856,14 @@ pub struct IdentityKey {
 
 impl IdentityKey {
     pub fn is_match(&self, addr: Address) -> bool {
+        self.is_match_with(addr, sd_aes128)
+    }
+
+    fn is_match_with(&self, addr: Address, aes128: impl FnOnce(&[u8; 16], &[u8; 16]) -> [u8; 16]) -> bool {
         match addr.address_type() {
             AddressType::Public | AddressType::RandomStatic => self.addr == addr,
             AddressType::RandomPrivateResolvable => {
-                let local_hash = random_address_hash(self.irk, addr.bytes()[3..].try_into().unwrap());
+                let local_hash = ah(aes128, &self.irk, addr.bytes()[3..].try_into().unwrap());
                 addr.bytes()[..3] == local_hash
             }
             AddressType::RandomPrivateNonResolvable | AddressType::Anonymous => false,
@@ -405,25 +890,51 @@ impl IdentityKey {
     }
 }
 
-fn random_address_hash(key: IdentityResolutionKey, r: [u8; 3]) -> [u8; 3] {
-    let mut cleartext = [0; 16];
-    cleartext[13..].copy_from_slice(&r);
-    cleartext[13..].reverse(); // big-endian to little-endian
+/// Find the key in `keys` that matches `addr`, see [`IdentityKey::is_match`].
+///
+/// Returns the index of the first matching key.
+pub fn resolve(addr: Address, keys: &[IdentityKey]) -> Option<usize> {
+    resolve_with(addr, keys, sd_aes128)
+}
+
+fn resolve_with(
+    addr: Address,
+    keys: &[IdentityKey],
+    aes128: impl Fn(&[u8; 16], &[u8; 16]) -> [u8; 16],
+) -> Option<usize> {
+    keys.iter().position(|key| key.is_match_with(addr, &aes128))
+}
+
+/// The random address hash function `ah` of the Core spec, Vol 3 Part H, 2.2.2.
+///
+/// `aes128(key, plaintext)` is the security function `e`, with all blocks most significant octet first.
+/// `irk`, `r` and the result are least significant octet first, as in [`Address`].
+fn ah(aes128: impl FnOnce(&[u8; 16], &[u8; 16]) -> [u8; 16], irk: &IdentityResolutionKey, r: [u8; 3]) -> [u8; 3] {
+    let mut key = irk.irk;
+    key.reverse(); // little-endian to big-endian
+
+    let mut plaintext = [0; 16];
+    plaintext[13..].copy_from_slice(&r);
+    plaintext[13..].reverse(); // little-endian to big-endian
+
+    let ciphertext = aes128(&key, &plaintext);
 
-    let mut ecb_hal_data: raw::nrf_ecb_hal_data_t = raw::nrf_ecb_hal_data_t {
-        key: key.irk,
-        cleartext,
+    let mut res: [u8; 3] = ciphertext[13..].try_into().unwrap();
+    res.reverse(); // big-endian to little-endian
+    res
+}
+
+fn sd_aes128(key: &[u8; 16], plaintext: &[u8; 16]) -> [u8; 16] {
+    let mut ecb_hal_data = raw::nrf_ecb_hal_data_t {
+        key: *key,
+        cleartext: *plaintext,
         ciphertext: [0; 16],
     };
 
-    ecb_hal_data.key.reverse(); // big-endian to little-endian
-
     // Can only return NRF_SUCCESS
     let _ = unsafe { raw::sd_ecb_block_encrypt(&mut ecb_hal_data) };
 
-    let mut res: [u8; 3] = ecb_hal_data.ciphertext[13..].try_into().unwrap();
-    res.reverse(); // little-endian to big-endian
-    res
+    ecb_hal_data.ciphertext
 }
 
 #[derive(PartialEq, Eq, Clone, Copy)]
@@ -463,6 +974,20 @@ impl core::fmt::Debug for GattError {
     }
 }
 
+impl core::fmt::Display for GattError {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.to_status(), fmt)
+    }
+}
+
+impl FromStr for GattError {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<GattStatus>()?.to_result().err().ok_or(ParseError)
+    }
+}
+
 impl From<GattError> for u16 {
     fn from(value: GattError) -> Self {
         value.0.get()
@@ -537,7 +1062,7 @@ macro_rules! error_codes {
             }
         }
 
-        impl core::fmt::Debug for GattStatus {
+        impl core::fmt::Display for GattStatus {
             fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
                 if self.is_app_error() {
                     core::write!(fmt, "Application Error: 0x{:02x}", self.0 as u8)
@@ -553,6 +1078,34 @@ macro_rules! error_codes {
             }
         }
 
+        impl core::fmt::Debug for GattStatus {
+            fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Display::fmt(self, fmt)
+            }
+        }
+
+        /// Parses the format produced by [`Display`](core::fmt::Display).
+        impl FromStr for GattStatus {
+            type Err = ParseError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    "Success" => Ok(Self::SUCCESS),
+                    $(
+                    $phrase => Ok(Self::$konst),
+                    )+
+                    _ => {
+                        if let Some(code) = s.strip_prefix("Application Error: 0x") {
+                            let code = parse_hex_u16(code).filter(|&c| c <= u8::MAX as u16).ok_or(ParseError)?;
+                            let status = Self(raw::BLE_GATT_STATUS_ATTERR_APP_BEGIN as u16 + code);
+                            return if status.is_app_error() { Ok(status) } else { Err(ParseError) };
+                        }
+                        let code = s.strip_prefix("Unknown GATT status: ").ok_or(ParseError)?;
+                        parse_hex_u16(code).map(Self).ok_or(ParseError)
+                    }
+                }
+            }
+        }
 
         impl GattStatus {
         $(
@@ -659,7 +1212,7 @@ macro_rules! hci_status_codes {
             }
         }
 
-        impl core::fmt::Debug for HciStatus {
+        impl core::fmt::Display for HciStatus {
             fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
                 match *self {
                     $(
@@ -669,6 +1222,30 @@ macro_rules! hci_status_codes {
                 }
             }
         }
+
+        impl core::fmt::Debug for HciStatus {
+            fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Display::fmt(self, fmt)
+            }
+        }
+
+        /// Parses the format produced by [`Display`](core::fmt::Display).
+        impl FromStr for HciStatus {
+            type Err = ParseError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $(
+                    $phrase => Ok(Self::$konst),
+                    )+
+                    _ => {
+                        let code = s.strip_prefix("Unknown HCI status: ").ok_or(ParseError)?;
+                        let code = parse_hex_u16(code).filter(|&c| c <= u8::MAX as u16).ok_or(ParseError)?;
+                        Ok(Self(code as u8))
+                    }
+                }
+            }
+        }
     }
 }
 
@@ -730,3 +1307,145 @@ hci_status_codes! {
     /// Connection Failed to be Established
     (CONN_FAILED_TO_BE_ESTABLISHED, raw::BLE_HCI_CONN_FAILED_TO_BE_ESTABLISHED, "Connection Failed to be Established");
 }
+
+#[cfg(test)]
+mod tests {
+    use aes::cipher::{BlockEncrypt, KeyInit};
+    use aes::Aes128;
+
+    use super::*;
+
+    // Core spec Vol 3 Part H, D.7: ah random address hash function. Values are most significant octet first.
+    const IRK: [u8; 16] = [
+        0xec, 0x02, 0x34, 0xa3, 0x57, 0xc8, 0xad, 0x05, 0x34, 0x10, 0x10, 0xa6, 0x0a, 0x39, 0x7d, 0x9b,
+    ];
+    const M: [u8; 16] = [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x70, 0x81, 0x94,
+    ];
+    const AES_128: [u8; 16] = [
+        0x15, 0x9d, 0x5f, 0xb7, 0x2e, 0xbe, 0x23, 0x11, 0xa4, 0x8c, 0x1b, 0xdc, 0xc4, 0x0d, 0xfb, 0xaa,
+    ];
+
+    // The security function `e`, in place of the SoftDevice's AES-ECB.
+    fn aes128(key: &[u8; 16], plaintext: &[u8; 16]) -> [u8; 16] {
+        let mut block = (*plaintext).into();
+        Aes128::new(key.into()).encrypt_block(&mut block);
+        block.into()
+    }
+
+    fn irk() -> IdentityResolutionKey {
+        let mut irk = IRK;
+        irk.reverse();
+        IdentityResolutionKey::new(irk)
+    }
+
+    // prand 0x708194 and hash 0x0dfbaa, least significant octet first.
+    const RPA: [u8; 6] = [0xaa, 0xfb, 0x0d, 0x94, 0x81, 0x70];
+
+    #[cfg(feature = "rand-core")]
+    struct FixedRng(&'static [u8]);
+
+    #[cfg(feature = "rand-core")]
+    impl RngCore for FixedRng {
+        fn next_u32(&mut self) -> u32 {
+            rand_core::impls::next_u32_via_fill(self)
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            rand_core::impls::next_u64_via_fill(self)
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            let (bytes, rest) = self.0.split_at(dest.len());
+            dest.copy_from_slice(bytes);
+            self.0 = rest;
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn ah_spec_vector() {
+        assert_eq!(aes128(&IRK, &M), AES_128);
+        assert_eq!(ah(aes128, &irk(), [0x94, 0x81, 0x70]), [0xaa, 0xfb, 0x0d]);
+    }
+
+    #[cfg(feature = "rand-core")]
+    #[test]
+    fn new_resolvable_spec_vector() {
+        // The first prand has no 1 bit in its random part and must be discarded.
+        let mut rng = FixedRng(&[0x00, 0x00, 0x00, 0x94, 0x81, 0xb0]);
+        let addr = Address::new_resolvable_with(irk(), &mut rng, aes128);
+        assert_eq!(addr.address_type(), AddressType::RandomPrivateResolvable);
+        assert_eq!(addr.bytes(), RPA);
+    }
+
+    #[cfg(feature = "rand-core")]
+    #[test]
+    fn new_resolvable_resolves() {
+        let key = IdentityKey {
+            irk: IdentityResolutionKey::new([0x5a; 16]),
+            addr: Address::new(AddressType::RandomStatic, [1, 2, 3, 4, 5, 0xc6]),
+        };
+        let mut rng = FixedRng(&[0x12, 0x34, 0x56]);
+        let addr = Address::new_resolvable_with(key.irk, &mut rng, aes128);
+        assert_eq!(addr.bytes()[3..], [0x12, 0x34, 0x56]);
+        assert_eq!(resolve_with(addr, &[key], aes128), Some(0));
+    }
+
+    #[cfg(feature = "rand-core")]
+    #[test]
+    fn new_static_random() {
+        // The random part must not be all zeros or all ones, so the first two draws are discarded.
+        let mut rng = FixedRng(&[
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0x3f, 0x11, 0x22, 0x33, 0x44, 0x55, 0x26,
+        ]);
+        let addr = Address::new_static_random(&mut rng);
+        assert_eq!(addr.address_type(), AddressType::RandomStatic);
+        assert_eq!(addr.bytes(), [0x11, 0x22, 0x33, 0x44, 0x55, 0xe6]);
+        assert!(!addr.is_resolved_peer_id());
+    }
+
+    #[test]
+    fn resolve_match() {
+        let keys = [
+            IdentityKey::from_addr(Address::new(AddressType::Public, [1, 2, 3, 4, 5, 6])),
+            IdentityKey {
+                irk: irk(),
+                addr: Address::new(AddressType::RandomStatic, [1, 2, 3, 4, 5, 0xc6]),
+            },
+        ];
+        let addr = Address::new(AddressType::RandomPrivateResolvable, RPA);
+        assert_eq!(resolve_with(addr, &keys, aes128), Some(1));
+    }
+
+    #[test]
+    fn resolve_mismatch() {
+        let key = IdentityKey {
+            irk: irk(),
+            addr: Address::new(AddressType::RandomStatic, [1, 2, 3, 4, 5, 0xc6]),
+        };
+
+        let mut bytes = RPA;
+        bytes[0] ^= 0x01;
+        let addr = Address::new(AddressType::RandomPrivateResolvable, bytes);
+        assert_eq!(resolve_with(addr, &[key], aes128), None);
+
+        let mut other = key;
+        other.irk = IdentityResolutionKey::new([0x55; 16]);
+        let addr = Address::new(AddressType::RandomPrivateResolvable, RPA);
+        assert_eq!(resolve_with(addr, &[other], aes128), None);
+    }
+
+    #[test]
+    fn appearance_other_equals_named() {
+        let tag = raw::BLE_APPEARANCE_GENERIC_TAG as u16;
+        assert_eq!(Appearance::from_raw(tag), Appearance::GenericTag);
+        assert_eq!(Appearance::Other(tag), Appearance::GenericTag);
+        assert_ne!(Appearance::Other(tag + 1), Appearance::GenericTag);
+        assert_eq!(Appearance::Other(0xffc0), Appearance::from_raw(0xffc0));
+    }
+}
@@ -42,6 +42,8 @@ pub struct Softdevice {
     pub(crate) att_mtu: u16,
     #[cfg(feature = "ble-l2cap")]
     pub(crate) l2cap_rx_mps: u16,
+    #[cfg(all(feature = "ble-peripheral", feature = "ble-gatt-server"))]
+    pub(crate) periph_role_count: u8,
 }
 
 /// Softdevice configuration.
@@ -276,6 +278,12 @@ impl Softdevice {
             .map(|x| x.rx_mps)
             .unwrap_or(raw::BLE_L2CAP_MPS_MIN as u16);
 
+        #[cfg(all(feature = "ble-peripheral", feature = "ble-gatt-server"))]
+        let periph_role_count = config
+            .gap_role_count
+            .map(|x| x.periph_role_count)
+            .unwrap_or(raw::BLE_GAP_ROLE_COUNT_PERIPH_DEFAULT as u8);
+
         let sd = Softdevice {
             _private: PhantomData,
 
@@ -284,6 +292,9 @@ impl Softdevice {
 
             #[cfg(feature = "ble-l2cap")]
             l2cap_rx_mps,
+
+            #[cfg(all(feature = "ble-peripheral", feature = "ble-gatt-server"))]
+            periph_role_count,
         };
 
         unsafe {
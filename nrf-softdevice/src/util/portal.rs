@@ -1,24 +1,32 @@
-use core::cell::RefCell;
+use core::cell::{Cell, RefCell};
 use core::mem;
 use core::mem::MaybeUninit;
 use core::ptr::NonNull;
 
-use embassy_sync::blocking_mutex::raw::{CriticalSectionRawMutex, ThreadModeRawMutex};
+use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
+#[cfg(all(target_arch = "arm", not(feature = "usable-from-interrupts")))]
+use embassy_sync::blocking_mutex::raw::ThreadModeRawMutex;
 use embassy_sync::blocking_mutex::Mutex;
 use embassy_sync::signal::Signal;
 
 use crate::util::OnDrop;
 
+// Thread mode only exists on Cortex-M, so host builds (unit tests) use a critical section.
+#[cfg(all(target_arch = "arm", not(feature = "usable-from-interrupts")))]
+type PortalRawMutex = ThreadModeRawMutex;
+#[cfg(any(not(target_arch = "arm"), feature = "usable-from-interrupts"))]
+type PortalRawMutex = CriticalSectionRawMutex;
+
 /// Utility to call a closure across tasks.
 pub struct Portal<T> {
-    #[cfg(feature = "usable-from-interrupts")]
-    state: Mutex<CriticalSectionRawMutex, RefCell<State<T>>>,
-    #[cfg(not(feature = "usable-from-interrupts"))]
-    state: Mutex<ThreadModeRawMutex, RefCell<State<T>>>,
+    state: Mutex<PortalRawMutex, RefCell<State<T>>>,
+    handler: Mutex<PortalRawMutex, Cell<Option<Handler<T>>>>,
 }
 
 struct State<T>(Option<NonNull<dyn FnMut(T, &mut State<T>)>>);
 
+type Handler<T> = (unsafe fn(*const (), T), *const ());
+
 unsafe impl<T> Send for Portal<T> {}
 
 unsafe impl<T> Sync for Portal<T> {}
@@ -26,6 +34,7 @@ unsafe impl<T> Sync for Portal<T> {}
 impl<T> Portal<T> {
     const INIT: Self = Portal {
         state: Mutex::new(RefCell::new(State(None))),
+        handler: Mutex::new(Cell::new(None)),
     };
     pub const fn new() -> Self {
         Self::INIT
@@ -46,12 +55,41 @@ impl<T> Portal<T> {
                 // Safety: This is transmuted from a FnMut, and therefore valid
                 unsafe { (*ptr.as_ptr())(val, &mut *state) };
                 true
+            } else if let Some((func, ctx)) = self.handler.lock(Cell::get) {
+                // Safety: `register_handler` requires `ctx` to stay valid while registered
+                unsafe { func(ctx, val) };
+                true
             } else {
                 false
             }
         })
     }
 
+    /// Register a handler that is called with `ctx` by [Portal::call()] whenever no closure is waiting
+    /// on the portal, until [Portal::unregister_handler()] is called.
+    ///
+    /// # Safety
+    ///
+    /// `ctx` must stay valid for `func` until the handler is unregistered.
+    ///
+    /// Returns `false` without registering anything when a handler is already registered on this portal.
+    #[allow(unused)]
+    pub unsafe fn register_handler(&self, func: unsafe fn(*const (), T), ctx: *const ()) -> bool {
+        self.handler.lock(|handler| {
+            if handler.get().is_some() {
+                return false;
+            }
+            handler.set(Some((func, ctx)));
+            true
+        })
+    }
+
+    /// Unregister the handler set with [Portal::register_handler()].
+    #[allow(unused)]
+    pub fn unregister_handler(&self) {
+        self.handler.lock(|handler| handler.set(None));
+    }
+
     /// Wait until the portal is called once using the [Portal::call()] function.
     ///
     /// The closure will be called with the parameter provided to [Portal::call()].
@@ -70,6 +108,7 @@ impl<T> Portal<T> {
     /// lock the application for its run duration. So, the caller is responsible for creating
     /// closures with short enough execution times to not massively disrupt the control flow of any
     /// application, especially when this is used from a library
+    #[allow(unused)]
     pub async fn wait_once<'a, R, F>(&'a self, mut func: F) -> R
     where
         F: FnMut(T) -> R + 'a,
@@ -175,3 +214,31 @@ impl<T> Portal<T> {
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    unsafe fn record(ctx: *const (), val: u32) {
+        (*ctx.cast::<Cell<u32>>()).set(val);
+    }
+
+    #[test]
+    fn second_handler_is_rejected() {
+        let portal = Portal::<u32>::new();
+        let first = Cell::new(0);
+        let second = Cell::new(0);
+
+        assert!(unsafe { portal.register_handler(record, &first as *const _ as *const ()) });
+        assert!(!unsafe { portal.register_handler(record, &second as *const _ as *const ()) });
+        assert!(portal.call(1));
+        assert_eq!((first.get(), second.get()), (1, 0));
+
+        portal.unregister_handler();
+        assert!(!portal.call(2));
+        assert!(unsafe { portal.register_handler(record, &second as *const _ as *const ()) });
+        assert!(portal.call(3));
+        assert_eq!((first.get(), second.get()), (1, 3));
+        portal.unregister_handler();
+    }
+}